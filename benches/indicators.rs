@@ -2,13 +2,23 @@ use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
 use chrono::{TimeZone, Utc};
 use rand::Rng;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange, WeightedMovingAverage,
+    AcceleratorOscillator, AccumulationDistribution, Alligator, ArnaudLegouxMovingAverage, Aroon,
+    AveragePrice, AverageTrueRange, AwesomeOscillator, BalanceOfPower, BollingerBands,
+    ChaikinMoneyFlow, ChaikinOscillator, ChandeMomentumOscillator, ChandelierExit,
+    CloseLocationValue, CommodityChannelIndex, ConnorsRsi, CoppockCurve, DetrendedPriceOscillator,
+    DirectionalMovementIndex, DisparityIndex, DonchianChannel, EaseOfMovement, EfficiencyRatio,
+    ElderRay, ExponentialMovingAverage, FastStochastic, ForceIndex, Fractal, HeikinAshi,
+    HighLowRange, KeltnerChannel, KnowSureThing, LinearRegressionMovingAverage, MassIndex, Maximum,
+    McGinleyDynamic, MeanAbsoluteDeviation, MedianPrice, Minimum, Momentum, MoneyFlowIndex,
+    MovingAverageConvergenceDivergence, NegativeVolumeIndex, OnBalanceVolume, ParabolicSAR,
+    PercentagePriceOscillator, PercentageVolumeOscillator, PivotPoints, PositiveVolumeIndex,
+    PriceVolumeTrend, Qstick, RateOfChange, RelativeStrengthIndex, RollingMedian, RollingSum,
+    SchaffTrendCycle, SimpleMovingAverage, SlowStochastic, SmoothedMovingAverage,
+    StandardDeviation, StandardErrorBands, Stochastic, StochasticRsi, SuperTrend, T3MovingAverage,
+    Trix, TrueRange, TypicalPrice, UlcerIndex, UltimateOscillator, Vidya,
+    VolumeWeightedMovingAverage, VortexIndicator, VwapBands, WeightedClose, WeightedMovingAverage,
 };
-use ta::{lit, Candle, Next};
+use ta::{int, lit, Candle, Next};
 
 const ITEMS_COUNT: usize = 5_000;
 
@@ -22,7 +32,7 @@ fn rand_data_item() -> Candle {
     let volume = rng.gen_range(lit!(0.0)..=lit!(10_000.0));
 
     Candle::builder()
-        .time(Utc.timestamp_opt(0, 0).single().unwrap_or_else(Utc::now))
+        .time(Utc.timestamp_opt(0, 0).unwrap())
         .open(open)
         .high(high)
         .low(low)
@@ -32,6 +42,65 @@ fn rand_data_item() -> Candle {
         .unwrap()
 }
 
+fn trend_data_item(price: ta::Num) -> Candle {
+    Candle::builder()
+        .time(Utc.timestamp_opt(0, 0).unwrap())
+        .open(price)
+        .high(price)
+        .low(price)
+        .close(price)
+        .volume(lit!(1000.0))
+        .build()
+        .unwrap()
+}
+
+// rolling extremes over a long window, a trend against the extreme makes it leave the
+// window at every bar, the worst case when the window has to be scanned again
+fn bench_period_500<I: for<'a> Next<&'a Candle>>(bench: &mut Bencher, mut indicator: I, step: i64) {
+    let items: Vec<Candle> = (0..ITEMS_COUNT as i64)
+        .map(|i| trend_data_item(lit!(10_000.0) + int!(i * step)))
+        .collect();
+
+    bench.iter(|| {
+        for item in items.iter() {
+            black_box(indicator.next(item));
+        }
+    })
+}
+
+fn maximum_period_500(bench: &mut Bencher) {
+    bench_period_500(bench, Maximum::new(500).unwrap(), -1);
+}
+
+fn minimum_period_500(bench: &mut Bencher) {
+    bench_period_500(bench, Minimum::new(500).unwrap(), 1);
+}
+
+// HighLowRange keeps both extremes in one buffer, compare it with a separate Maximum and
+// Minimum over the same candles
+fn high_low_range_period_500(bench: &mut Bencher) {
+    let items: Vec<Candle> = (0..ITEMS_COUNT).map(|_| rand_data_item()).collect();
+    let mut range = HighLowRange::new(500).unwrap();
+
+    bench.iter(|| {
+        for item in items.iter() {
+            black_box(range.next(item));
+        }
+    })
+}
+
+fn maximum_minimum_period_500(bench: &mut Bencher) {
+    let items: Vec<Candle> = (0..ITEMS_COUNT).map(|_| rand_data_item()).collect();
+    let mut max = Maximum::new(500).unwrap();
+    let mut min = Minimum::new(500).unwrap();
+
+    bench.iter(|| {
+        for item in items.iter() {
+            black_box((max.next(item), min.next(item)));
+        }
+    })
+}
+
 macro_rules! bench_indicators {
     ($($indicator:ident), *) => {
         $(
@@ -49,7 +118,6 @@ macro_rules! bench_indicators {
         )*
 
         benchmark_group!(benches, $($indicator,)*);
-        benchmark_main!(benches);
     }
 }
 
@@ -59,6 +127,7 @@ bench_indicators!(
     MeanAbsoluteDeviation,
     BollingerBands,
     ChandelierExit,
+    DirectionalMovementIndex,
     EfficiencyRatio,
     FastStochastic,
     KeltnerChannel,
@@ -75,5 +144,68 @@ bench_indicators!(
     SlowStochastic,
     StandardDeviation,
     TrueRange,
-    WeightedMovingAverage
+    WeightedMovingAverage,
+    ParabolicSAR,
+    Momentum,
+    ChandeMomentumOscillator,
+    Trix,
+    UltimateOscillator,
+    Aroon,
+    DonchianChannel,
+    VwapBands,
+    SuperTrend,
+    CloseLocationValue,
+    AccumulationDistribution,
+    ChaikinMoneyFlow,
+    UlcerIndex,
+    ChaikinOscillator,
+    PriceVolumeTrend,
+    ForceIndex,
+    EaseOfMovement,
+    NegativeVolumeIndex,
+    PositiveVolumeIndex,
+    VortexIndicator,
+    AwesomeOscillator,
+    DetrendedPriceOscillator,
+    CoppockCurve,
+    StochasticRsi,
+    BalanceOfPower,
+    Qstick,
+    MedianPrice,
+    WeightedClose,
+    ConnorsRsi,
+    ArnaudLegouxMovingAverage,
+    McGinleyDynamic,
+    T3MovingAverage,
+    Vidya,
+    LinearRegressionMovingAverage,
+    StandardErrorBands,
+    PercentageVolumeOscillator,
+    MassIndex,
+    KnowSureThing,
+    ElderRay,
+    Fractal,
+    DisparityIndex,
+    SchaffTrendCycle,
+    SmoothedMovingAverage,
+    Alligator,
+    PivotPoints,
+    HighLowRange,
+    TypicalPrice,
+    AveragePrice,
+    HeikinAshi,
+    Stochastic,
+    RollingMedian,
+    RollingSum,
+    VolumeWeightedMovingAverage,
+    AcceleratorOscillator
+);
+
+benchmark_group!(
+    long_periods,
+    maximum_period_500,
+    minimum_period_500,
+    high_low_range_period_500,
+    maximum_minimum_period_500
 );
+benchmark_main!(benches, long_periods);
@@ -1,31 +1,31 @@
 use ta::indicators::TrueRange;
 use ta::{Close, High, Low, Next};
 
-
 // You can create your own data items.
 // You may want it for different purposes, e.g.:
 // - you data source don't have volume or other fields.
-// - you want to skip validation to avoid performance penalty.
+// - you want to skip validation to avoid performance penalty (`CandleBuilder::build_unchecked`
+//   skips it for `Candle` too).
 struct Item {
-    high: rust_decimal::Decimal,
-    low: rust_decimal::Decimal,
-    close: rust_decimal::Decimal,
+    high: ta::Num,
+    low: ta::Num,
+    close: ta::Num,
 }
 
 impl Low for Item {
-    fn low(&self) -> rust_decimal::Decimal {
+    fn low(&self) -> ta::Num {
         self.low
     }
 }
 
 impl High for Item {
-    fn high(&self) -> rust_decimal::Decimal {
+    fn high(&self) -> ta::Num {
         self.high
     }
 }
 
 impl Close for Item {
-    fn close(&self) -> rust_decimal::Decimal {
+    fn close(&self) -> ta::Num {
         self.close
     }
 }
@@ -35,8 +35,14 @@ fn main() {
     let mut reader = csv::Reader::from_path("./examples/data/AMZN.csv").unwrap();
 
     for record in reader.deserialize() {
-        let (date, _open, high, low, close, _volume): (String, rust_decimal::Decimal, rust_decimal::Decimal, rust_decimal::Decimal, rust_decimal::Decimal, rust_decimal::Decimal) =
-            record.unwrap();
+        let (date, _open, high, low, close, _volume): (
+            String,
+            ta::Num,
+            ta::Num,
+            ta::Num,
+            ta::Num,
+            ta::Num,
+        ) = record.unwrap();
         let item = Item { high, low, close };
         let val = tr.next(&item);
         println!("{date}: {tr} = {val:2.2}");
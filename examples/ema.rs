@@ -7,8 +7,14 @@ fn main() {
     let mut reader = csv::Reader::from_path("./examples/data/AMZN.csv").unwrap();
 
     for record in reader.deserialize() {
-        let (date, open, high, low, close, volume): (String, rust_decimal::Decimal, rust_decimal::Decimal, rust_decimal::Decimal, rust_decimal::Decimal, rust_decimal::Decimal) =
-            record.unwrap();
+        let (date, open, high, low, close, volume): (
+            String,
+            ta::Num,
+            ta::Num,
+            ta::Num,
+            ta::Num,
+            ta::Num,
+        ) = record.unwrap();
         let dt = Candle::builder()
             .open(open)
             .high(high)
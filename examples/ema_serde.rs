@@ -7,8 +7,7 @@ fn main() {
     let mut reader = csv::Reader::from_path("./examples/data/AMZN.csv").unwrap();
 
     for record in reader.deserialize() {
-        // Deserialize DataItem but ignore the `date` field.
-        // You may have to create your own struct if you want to keep track of the date.
+        // The `date` column is read into the datetime of the candle.
         let dt: Candle = record.unwrap();
         let ema_val = ema.next(&dt);
         println!("{} = {:2.2}", ema, ema_val);
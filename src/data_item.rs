@@ -1,20 +1,33 @@
-use chrono::{DateTime, Utc};
 use crate::errors::{Result, TaError};
 use crate::{lit, Close, High, Low, Open, Volume};
+use chrono::{DateTime, Datelike, NaiveTime, TimeZone, Utc, Weekday};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Data item is used as an input for indicators.
+///
+/// With the `serde` feature, the datetime is serialized as an RFC 3339 string, or as
+/// milliseconds since the Unix epoch with the `serde-epoch-millis` feature, see
+/// [`serde_datetime`](crate::serde_datetime). It is also read from a `date` field.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct Candle {
+    #[cfg_attr(feature = "serde", serde(alias = "date"))]
+    #[cfg_attr(
+        all(feature = "serde", not(feature = "serde-epoch-millis")),
+        serde(with = "crate::serde_datetime::rfc3339")
+    )]
+    #[cfg_attr(
+        feature = "serde-epoch-millis",
+        serde(with = "crate::serde_datetime::epoch_millis")
+    )]
     datetime: DateTime<Utc>,
-    open: rust_decimal::Decimal,
-    high: rust_decimal::Decimal,
-    low: rust_decimal::Decimal,
-    close: rust_decimal::Decimal,
-    volume: rust_decimal::Decimal,
+    open: crate::Num,
+    high: crate::Num,
+    low: crate::Num,
+    close: crate::Num,
+    volume: crate::Num,
 }
 
 impl Candle {
@@ -23,37 +36,115 @@ impl Candle {
         CandleBuilder::new()
     }
 
+    /// Builds a validated candle from `f64` values, which is shorter than building
+    /// [`Num`](crate::Num)s e.g. for tests or data that is read as floats.
+    ///
+    /// With the default decimal backend, the values keep their binary representation, so
+    /// `0.1` becomes `0.1000000000000000055511151231...` rather than the decimal `0.1`. Use
+    /// the [builder](Self::builder) with exact decimals when that matters.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err(TaError::DataItemInvalid)` if a value is NaN or infinite, or if the
+    /// candle is invalid, see [`CandleBuilder::build`]
+    pub fn from_f64(
+        time: DateTime<Utc>,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> Result<Candle> {
+        let num = |value| crate::num::from_f64(value).ok_or(TaError::DataItemInvalid);
+
+        Candle::builder()
+            .time(time)
+            .open(num(open)?)
+            .high(num(high)?)
+            .low(num(low)?)
+            .close(num(close)?)
+            .volume(num(volume)?)
+            .build()
+    }
+
     pub fn datetime(&self) -> DateTime<Utc> {
         self.datetime
     }
+
+    /// Returns the trading session the candle belongs to.
+    ///
+    /// The candle's datetime is converted into `tz` and compared against the regular session
+    /// window `[open, close)`. On weekdays anything before `open` is labeled as
+    /// [`Session::PreMarket`] and anything from `close` onward as [`Session::AfterHours`].
+    /// Saturdays and Sundays are always [`Session::Closed`].
+    ///
+    /// `tz` can be any `chrono` time zone, e.g. `FixedOffset` or `chrono_tz::Tz`.
+    pub fn session<Tz: TimeZone>(&self, tz: &Tz, open: NaiveTime, close: NaiveTime) -> Session {
+        let local = self.datetime.with_timezone(tz);
+
+        if matches!(local.weekday(), Weekday::Sat | Weekday::Sun) {
+            return Session::Closed;
+        }
+
+        let time = local.time();
+        if time < open {
+            Session::PreMarket
+        } else if time < close {
+            Session::Regular
+        } else {
+            Session::AfterHours
+        }
+    }
+}
+
+/// Parses an RFC 3339 datetime, converted to UTC, or a `YYYY-MM-DD` date, taken as midnight
+/// UTC.
+#[cfg(any(feature = "serde", feature = "csv"))]
+pub(crate) fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+}
+
+/// Trading session of a [`Candle`], see [`Candle::session`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Session {
+    PreMarket,
+    Regular,
+    AfterHours,
+    Closed,
 }
 
 impl Open for Candle {
-    fn open(&self) -> rust_decimal::Decimal {
+    fn open(&self) -> crate::Num {
         self.open
     }
 }
 
 impl High for Candle {
-    fn high(&self) -> rust_decimal::Decimal {
+    fn high(&self) -> crate::Num {
         self.high
     }
 }
 
 impl Low for Candle {
-    fn low(&self) -> rust_decimal::Decimal {
+    fn low(&self) -> crate::Num {
         self.low
     }
 }
 
 impl Close for Candle {
-    fn close(&self) -> rust_decimal::Decimal {
+    fn close(&self) -> crate::Num {
         self.close
     }
 }
 
 impl Volume for Candle {
-    fn volume(&self) -> rust_decimal::Decimal {
+    fn volume(&self) -> crate::Num {
         self.volume
     }
 }
@@ -61,11 +152,11 @@ impl Volume for Candle {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CandleBuilder {
     time: Option<DateTime<Utc>>,
-    open: Option<rust_decimal::Decimal>,
-    high: Option<rust_decimal::Decimal>,
-    low: Option<rust_decimal::Decimal>,
-    close: Option<rust_decimal::Decimal>,
-    volume: Option<rust_decimal::Decimal>,
+    open: Option<crate::Num>,
+    high: Option<crate::Num>,
+    low: Option<crate::Num>,
+    close: Option<crate::Num>,
+    volume: Option<crate::Num>,
 }
 
 impl CandleBuilder {
@@ -73,84 +164,100 @@ impl CandleBuilder {
         Self::default()
     }
 
+    /// Sets the time of the candle. It defaults to the Unix epoch, for series whose
+    /// timestamps don't matter to the indicators.
     pub fn time(mut self, time: DateTime<Utc>) -> Self {
         self.time = Some(time);
         self
     }
 
-    pub fn open(mut self, val: rust_decimal::Decimal) -> Self {
+    pub fn open(mut self, val: crate::Num) -> Self {
         self.open = Some(val);
         self
     }
 
-    pub fn high(mut self, val: rust_decimal::Decimal) -> Self {
+    pub fn high(mut self, val: crate::Num) -> Self {
         self.high = Some(val);
         self
     }
 
-    pub fn low(mut self, val: rust_decimal::Decimal) -> Self {
+    pub fn low(mut self, val: crate::Num) -> Self {
         self.low = Some(val);
         self
     }
 
-    pub fn close(mut self, val: rust_decimal::Decimal) -> Self {
+    pub fn close(mut self, val: crate::Num) -> Self {
         self.close = Some(val);
         self
     }
 
-    pub fn volume(mut self, val: rust_decimal::Decimal) -> Self {
+    pub fn volume(mut self, val: crate::Num) -> Self {
         self.volume = Some(val);
         self
     }
 
     pub fn build(self) -> Result<Candle> {
-        if let (Some(time), Some(open), Some(high), Some(low), Some(close), Some(volume)) =
-            (self.time, self.open, self.high, self.low, self.close, self.volume)
+        let item = self.complete().ok_or(TaError::DataItemIncomplete)?;
+
+        // validate
+        if item.low <= item.open
+            && item.low <= item.close
+            && item.low <= item.high
+            && item.high >= item.open
+            && item.high >= item.close
+            && item.volume >= lit!(0.0)
         {
-            // validate
-            if low <= open
-                && low <= close
-                && low <= high
-                && high >= open
-                && high >= close
-                && volume >= lit!(0.0)
-            {
-                let item = Candle {
-                    datetime: time,
-                    open,
-                    high,
-                    low,
-                    close,
-                    volume,
-                };
-                Ok(item)
-            } else {
-                Err(TaError::DataItemInvalid)
-            }
+            Ok(item)
         } else {
-            Err(TaError::DataItemIncomplete)
+            Err(TaError::DataItemInvalid)
         }
     }
+
+    /// Builds the candle without validating it.
+    ///
+    /// Unlike [`build`](Self::build) it doesn't check that _low_ and _high_ enclose _open_
+    /// and _close_ and that _volume_ isn't negative. It saves the checks for data that is
+    /// already sanitized, e.g. read back from a store that only holds built candles. Feeding
+    /// an invalid candle into an indicator doesn't return an error, but meaningless values,
+    /// e.g. a negative true range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field other than the time is not set.
+    pub fn build_unchecked(self) -> Candle {
+        self.complete().expect("Candle is incomplete")
+    }
+
+    fn complete(self) -> Option<Candle> {
+        Some(Candle {
+            datetime: self.time.unwrap_or_default(),
+            open: self.open?,
+            high: self.high?,
+            low: self.low?,
+            close: self.close?,
+            volume: self.volume?,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::TimeZone;
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_builder() {
         fn assert_valid(
             (open, high, low, close, volume): (
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
+                crate::Num,
+                crate::Num,
+                crate::Num,
+                crate::Num,
+                crate::Num,
             ),
         ) {
             let result = Candle::builder()
-                .time(Utc.timestamp_opt(0, 0).single().unwrap_or_else(Utc::now))
+                .time(Utc.timestamp_opt(0, 0).unwrap())
                 .open(open)
                 .high(high)
                 .low(low)
@@ -163,15 +270,15 @@ mod tests {
 
         fn assert_invalid(
             (open, high, low, close, volume): (
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
-                rust_decimal::Decimal,
+                crate::Num,
+                crate::Num,
+                crate::Num,
+                crate::Num,
+                crate::Num,
             ),
         ) {
             let result = Candle::builder()
-                .time(Utc.timestamp_opt(0, 0).single().unwrap_or_else(Utc::now))
+                .time(Utc.timestamp_opt(0, 0).unwrap())
                 .open(open)
                 .high(high)
                 .low(low)
@@ -207,4 +314,178 @@ mod tests {
             assert_invalid(record)
         }
     }
+
+    #[test]
+    fn test_build_without_time() {
+        let candle = Candle::builder()
+            .open(lit!(20.0))
+            .high(lit!(25.0))
+            .low(lit!(15.0))
+            .close(lit!(21.0))
+            .volume(lit!(7500.0))
+            .build()
+            .unwrap();
+        assert_eq!(candle.datetime(), Utc.timestamp_opt(0, 0).unwrap());
+
+        let result = Candle::builder()
+            .time(Utc.timestamp_opt(0, 0).unwrap())
+            .open(lit!(20.0))
+            .high(lit!(25.0))
+            .low(lit!(15.0))
+            .close(lit!(21.0))
+            .build();
+        assert_eq!(result, Err(TaError::DataItemIncomplete));
+    }
+
+    #[test]
+    fn test_build_unchecked() {
+        // high is below low
+        let builder = Candle::builder()
+            .time(Utc.timestamp_opt(0, 0).unwrap())
+            .open(lit!(20.0))
+            .high(lit!(15.0))
+            .low(lit!(25.0))
+            .close(lit!(21.0))
+            .volume(lit!(7500.0));
+
+        assert_eq!(builder.clone().build(), Err(TaError::DataItemInvalid));
+
+        let candle = builder.build_unchecked();
+        assert_eq!(candle.high(), lit!(15.0));
+        assert_eq!(candle.low(), lit!(25.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_build_unchecked_incomplete() {
+        Candle::builder().open(lit!(1.0)).build_unchecked();
+    }
+
+    #[test]
+    fn test_session() {
+        use chrono::FixedOffset;
+
+        // US equities, EST: regular session 9:30 - 16:00
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let open = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+        let close = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+
+        let session = |day, hour, min| {
+            Candle::builder()
+                .time(Utc.with_ymd_and_hms(2024, 1, day, hour, min, 0).unwrap())
+                .open(lit!(1.0))
+                .high(lit!(1.0))
+                .low(lit!(1.0))
+                .close(lit!(1.0))
+                .volume(lit!(1.0))
+                .build()
+                .unwrap()
+                .session(&tz, open, close)
+        };
+
+        // 2024-01-10 is a Wednesday
+        assert_eq!(session(10, 12, 0), Session::PreMarket);
+        assert_eq!(session(10, 14, 29), Session::PreMarket);
+        assert_eq!(session(10, 14, 30), Session::Regular);
+        assert_eq!(session(10, 20, 59), Session::Regular);
+        assert_eq!(session(10, 21, 0), Session::AfterHours);
+        assert_eq!(session(10, 23, 30), Session::AfterHours);
+
+        // Saturday
+        assert_eq!(session(13, 15, 0), Session::Closed);
+
+        // Late Friday evening in New York is already Saturday in UTC
+        assert_eq!(session(13, 1, 0), Session::AfterHours);
+    }
+
+    #[test]
+    fn test_from_f64() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 10, 15, 0, 0).unwrap();
+        let candle = Candle::from_f64(time, 10.5, 12.25, 9.75, 11.0, 1500.0).unwrap();
+        let built = Candle::builder()
+            .time(time)
+            .open(lit!(10.5))
+            .high(lit!(12.25))
+            .low(lit!(9.75))
+            .close(lit!(11.0))
+            .volume(lit!(1500.0))
+            .build()
+            .unwrap();
+        assert_eq!(candle, built);
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Candle::from_f64(time, 10.5, 12.25, 9.75, value, 1500.0),
+                Err(TaError::DataItemInvalid)
+            );
+            assert_eq!(
+                Candle::from_f64(time, 10.5, 12.25, 9.75, 11.0, value),
+                Err(TaError::DataItemInvalid)
+            );
+        }
+
+        // the same validation as the builder
+        assert_eq!(
+            Candle::from_f64(time, 10.5, 10.0, 9.75, 11.0, 1500.0),
+            Err(TaError::DataItemInvalid)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    fn candle() -> Candle {
+        Candle::builder()
+            .time(Utc.with_ymd_and_hms(2017, 1, 3, 0, 0, 0).unwrap())
+            .open(lit!(757.5))
+            .high(lit!(758.75))
+            .low(lit!(747.5))
+            .close(lit!(753.5))
+            .volume(lit!(3521100.0))
+            .build()
+            .unwrap()
+    }
+
+    #[cfg(all(feature = "serde", not(feature = "serde-epoch-millis")))]
+    #[test]
+    fn test_serde_rfc3339() {
+        let candle = candle();
+        let json = serde_json::to_string(&candle).unwrap();
+        assert!(
+            json.contains(r#""datetime":"2017-01-03T00:00:00Z""#),
+            "{}",
+            json
+        );
+        assert_eq!(serde_json::from_str::<Candle>(&json).unwrap(), candle);
+
+        let bytes = bincode::serialize(&candle).unwrap();
+        assert_eq!(bincode::deserialize::<Candle>(&bytes).unwrap(), candle);
+    }
+
+    #[cfg(all(feature = "serde", not(feature = "serde-epoch-millis")))]
+    #[test]
+    fn test_serde_csv() {
+        // the layout of examples/data/AMZN.csv
+        let data = "date,open,high,low,close,volume\n2017-01-03,757.5,758.75,747.5,753.5,3521100\n";
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let candles: Vec<Candle> = reader.deserialize().map(|record| record.unwrap()).collect();
+
+        assert_eq!(candles, vec![candle()]);
+    }
+
+    #[cfg(feature = "serde-epoch-millis")]
+    #[test]
+    fn test_serde_epoch_millis() {
+        let candle = candle();
+        let json = serde_json::to_string(&candle).unwrap();
+        assert!(json.contains(r#""datetime":1483401600000"#), "{}", json);
+        assert_eq!(serde_json::from_str::<Candle>(&json).unwrap(), candle);
+
+        let bytes = bincode::serialize(&candle).unwrap();
+        assert_eq!(bincode::deserialize::<Candle>(&bytes).unwrap(), candle);
+
+        let data =
+            "date,open,high,low,close,volume\n1483401600000,757.5,758.75,747.5,753.5,3521100\n";
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let candles: Vec<Candle> = reader.deserialize().map(|record| record.unwrap()).collect();
+        assert_eq!(candles, vec![candle]);
+    }
 }
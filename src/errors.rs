@@ -1,29 +1,116 @@
+use core::fmt::{Display, Formatter};
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt::{Display, Formatter};
 
-pub type Result<T> = std::result::Result<T, TaError>;
+pub type Result<T> = core::result::Result<T, TaError>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TaError {
     InvalidParameter,
+    InvalidPeriod { got: usize },
+    InvalidMultiplier,
     DataItemIncomplete,
     DataItemInvalid,
+    InsufficientData { have: usize, need: usize },
+    InvalidRecord { line: u64 },
+}
+
+impl TaError {
+    /// Returns a short description of the kind of error, without the context that
+    /// [`Display`] adds.
+    #[must_use]
+    pub fn message(&self) -> &'static str {
+        match *self {
+            TaError::InvalidParameter => "invalid parameter",
+            TaError::InvalidPeriod { .. } => "invalid period",
+            TaError::InvalidMultiplier => "invalid multiplier",
+            TaError::DataItemIncomplete => "data item is incomplete",
+            TaError::DataItemInvalid => "data item is invalid",
+            TaError::InsufficientData { .. } => "insufficient data",
+            TaError::InvalidRecord { .. } => "invalid record",
+        }
+    }
 }
 
 impl Display for TaError {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         match *self {
-            TaError::InvalidParameter => write!(f, "invalid parameter"),
-            TaError::DataItemIncomplete => write!(f, "data item is incomplete"),
-            TaError::DataItemInvalid => write!(f, "data item is invalid"),
+            TaError::InvalidPeriod { got } => {
+                write!(
+                    f,
+                    "{}: got {}, expected an integer greater than 0",
+                    self.message(),
+                    got
+                )
+            }
+            TaError::InsufficientData { have, need } => {
+                write!(f, "{}: have {}, need {}", self.message(), have, need)
+            }
+            TaError::InvalidRecord { line } => write!(f, "{}: line {}", self.message(), line),
+            _ => write!(f, "{}", self.message()),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for TaError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            TaError::DataItemInvalid | TaError::DataItemIncomplete | TaError::InvalidParameter => None,
+            TaError::DataItemInvalid
+            | TaError::DataItemIncomplete
+            | TaError::InvalidParameter
+            | TaError::InvalidPeriod { .. }
+            | TaError::InvalidMultiplier
+            | TaError::InsufficientData { .. }
+            | TaError::InvalidRecord { .. } => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let cases = [
+            (TaError::InvalidParameter, "invalid parameter"),
+            (
+                TaError::InvalidPeriod { got: 0 },
+                "invalid period: got 0, expected an integer greater than 0",
+            ),
+            (TaError::InvalidMultiplier, "invalid multiplier"),
+            (TaError::DataItemIncomplete, "data item is incomplete"),
+            (TaError::DataItemInvalid, "data item is invalid"),
+            (
+                TaError::InsufficientData { have: 1, need: 3 },
+                "insufficient data: have 1, need 3",
+            ),
+            (TaError::InvalidRecord { line: 4 }, "invalid record: line 4"),
+        ];
+        for (error, display) in cases {
+            assert_eq!(format!("{}", error), display);
+            assert!(display.starts_with(error.message()));
+        }
+    }
+
+    #[test]
+    fn test_constructors() {
+        use crate::indicators::*;
+        use crate::lit;
+
+        let zero_period = Err(TaError::InvalidPeriod { got: 0 });
+        assert_eq!(ExponentialMovingAverage::new(0).map(drop), zero_period);
+        assert_eq!(StandardDeviation::new(0).map(drop), zero_period);
+        assert_eq!(BollingerBands::new(0, lit!(2.0)).map(drop), zero_period);
+        assert_eq!(KeltnerChannel::new(0, lit!(2.0)).map(drop), zero_period);
+        assert_eq!(
+            MovingAverageConvergenceDivergence::new(12, 0, 9).map(drop),
+            zero_period
+        );
+        assert_eq!(
+            SuperTrend::new(10, lit!(0.0)).map(drop),
+            Err(TaError::InvalidMultiplier)
+        );
+    }
+}
@@ -1,22 +1,218 @@
-use rust_decimal::Decimal;
-pub const INFINITY: Decimal = Decimal::MAX;
-pub const NEG_INFINITY: Decimal = Decimal::MIN;
+use alloc::boxed::Box;
+use alloc::vec;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "f64-backend"))]
 #[macro_export]
 macro_rules! lit {
-        ($e:expr) => {
-            ::rust_decimal::Decimal::from_str_exact(stringify!($e)).unwrap()
-        };
-    }
+    ($e:expr) => {
+        ::rust_decimal::Decimal::from_str_exact(stringify!($e)).unwrap()
+    };
+}
+
+#[cfg(feature = "f64-backend")]
+#[macro_export]
+macro_rules! lit {
+    ($e:expr) => {
+        ($e as f64)
+    };
+}
 
+#[cfg(not(feature = "f64-backend"))]
 #[macro_export]
 macro_rules! int {
-        ($e:expr) => {
-            ::rust_decimal::Decimal::new($e.try_into().unwrap(), 0)
-        };
-    }
+    ($e:expr) => {
+        ::rust_decimal::Decimal::new($e.try_into().unwrap(), 0)
+    };
+}
+
+#[cfg(feature = "f64-backend")]
+#[macro_export]
+macro_rules! int {
+    ($e:expr) => {
+        ($e as f64)
+    };
+}
+
+/// Chains indicators into a [`Pipe`](crate::stream::Pipe), each one fed with the outputs of
+/// the previous one.
+///
+/// The output type of every indicator must be an input type of the next one. The inputs of
+/// the pipe go into the first indicator and can be of any type it accepts, e.g. bars.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{RateOfChange, SimpleMovingAverage};
+/// use ta::{lit, pipe, Next};
+///
+/// // rate of change of the 2 period SMA
+/// let mut sma_roc = pipe!(
+///     SimpleMovingAverage::new(2).unwrap(),
+///     RateOfChange::new(1).unwrap()
+/// );
+///
+/// sma_roc.next(lit!(8.0));
+/// sma_roc.next(lit!(12.0)); // SMA 10
+/// assert_eq!(sma_roc.next(lit!(16.0)), lit!(40.0)); // SMA 14
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::stream::Pipe::new($first, $second)
+    };
+    ($first:expr, $second:expr, $($rest:expr),+ $(,)?) => {
+        $crate::pipe!($crate::stream::Pipe::new($first, $second), $($rest),+)
+    };
+}
 
 /// Returns the largest of 3 given numbers.
-pub fn max3(a: rust_decimal::Decimal, b: rust_decimal::Decimal, c: rust_decimal::Decimal) -> rust_decimal::Decimal {
+pub fn max3(a: crate::Num, b: crate::Num, c: crate::Num) -> crate::Num {
     a.max(b).max(c)
 }
+
+/// Fixed size window over the latest values of a series.
+///
+/// Holds up to `capacity` values, pushing a value into a full window evicts the oldest one.
+/// Lookbacks during warmup are clamped to the oldest value seen so far, so an indicator
+/// looking _n_ bars back uses its first input until _n + 1_ values were pushed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RingWindow {
+    index: usize,
+    count: usize,
+    deque: Box<[crate::Num]>,
+}
+
+impl RingWindow {
+    /// Creates an empty window, `capacity` must be greater than 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "RingWindow capacity must be greater than 0");
+        Self {
+            index: 0,
+            count: 0,
+            deque: vec![lit!(0.0); capacity].into_boxed_slice(),
+        }
+    }
+
+    /// Pushes `value` as the newest value, returns the evicted value if the window was full.
+    pub fn push(&mut self, value: crate::Num) -> Option<crate::Num> {
+        let evicted = if self.count == self.deque.len() {
+            Some(self.deque[self.index])
+        } else {
+            self.count += 1;
+            None
+        };
+        self.deque[self.index] = value;
+
+        self.index = if self.index + 1 < self.deque.len() {
+            self.index + 1
+        } else {
+            0
+        };
+
+        evicted
+    }
+
+    /// Returns the value `n` pushes ago, 0 being the newest value.
+    ///
+    /// While fewer than `n + 1` values were pushed the oldest value is returned. Returns
+    /// `None` if the window is empty.
+    pub fn lookback(&self, n: usize) -> Option<crate::Num> {
+        if self.count == 0 {
+            return None;
+        }
+        let n = n.min(self.count - 1);
+        let capacity = self.deque.len();
+        Some(self.deque[(self.index + 2 * capacity - 1 - n) % capacity])
+    }
+
+    /// Returns `true` once `capacity` values were pushed.
+    pub fn is_full(&self) -> bool {
+        self.count == self.deque.len()
+    }
+
+    /// Iterates over the values from the oldest to the newest one.
+    pub fn iter(&self) -> impl Iterator<Item = crate::Num> + '_ {
+        let capacity = self.deque.len();
+        let oldest = self.index + capacity - self.count;
+        (0..self.count).map(move |i| self.deque[(oldest + i) % capacity])
+    }
+
+    /// Empties the window.
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for value in self.deque.iter_mut() {
+            *value = lit!(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(window: &RingWindow) -> Vec<crate::Num> {
+        window.iter().collect()
+    }
+
+    #[test]
+    fn test_ring_window_push() {
+        let mut window = RingWindow::new(3);
+        assert!(values(&window).is_empty());
+
+        assert_eq!(window.push(lit!(1.0)), None);
+        assert_eq!(window.push(lit!(2.0)), None);
+        assert_eq!(values(&window), vec![lit!(1.0), lit!(2.0)]);
+
+        // exactly `capacity` pushes fill the window without evicting
+        assert_eq!(window.push(lit!(3.0)), None);
+        assert_eq!(values(&window), vec![lit!(1.0), lit!(2.0), lit!(3.0)]);
+
+        // wraparound
+        assert_eq!(window.push(lit!(4.0)), Some(lit!(1.0)));
+        assert_eq!(window.push(lit!(5.0)), Some(lit!(2.0)));
+        assert_eq!(values(&window), vec![lit!(3.0), lit!(4.0), lit!(5.0)]);
+    }
+
+    #[test]
+    fn test_ring_window_lookback() {
+        let mut window = RingWindow::new(3);
+        assert_eq!(window.lookback(0), None);
+
+        // during warmup the lookback is clamped to the oldest value
+        window.push(lit!(1.0));
+        assert_eq!(window.lookback(0), Some(lit!(1.0)));
+        assert_eq!(window.lookback(2), Some(lit!(1.0)));
+
+        window.push(lit!(2.0));
+        assert_eq!(window.lookback(0), Some(lit!(2.0)));
+        assert_eq!(window.lookback(1), Some(lit!(1.0)));
+        assert_eq!(window.lookback(2), Some(lit!(1.0)));
+
+        window.push(lit!(3.0));
+        assert_eq!(window.lookback(2), Some(lit!(1.0)));
+
+        window.push(lit!(4.0));
+        assert_eq!(window.lookback(0), Some(lit!(4.0)));
+        assert_eq!(window.lookback(1), Some(lit!(3.0)));
+        assert_eq!(window.lookback(2), Some(lit!(2.0)));
+        assert_eq!(window.lookback(5), Some(lit!(2.0)));
+    }
+
+    #[test]
+    fn test_ring_window_clear() {
+        let mut window = RingWindow::new(2);
+        window.push(lit!(1.0));
+        window.push(lit!(2.0));
+        window.push(lit!(3.0));
+
+        window.clear();
+        assert_eq!(window.lookback(0), None);
+        assert_eq!(window.push(lit!(4.0)), None);
+        assert_eq!(values(&window), vec![lit!(4.0)]);
+    }
+}
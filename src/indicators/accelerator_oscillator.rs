@@ -0,0 +1,203 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AwesomeOscillator, SimpleMovingAverage as Sma};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accelerator Oscillator (AC).
+///
+/// Developed by Bill Williams, the Accelerator Oscillator measures the acceleration of the
+/// market momentum: how far the [Awesome Oscillator](struct.AwesomeOscillator.html) is from
+/// its own simple moving average. The momentum changes direction before the price does, and
+/// the AC changes direction before the momentum does, so it crosses zero ahead of the AO.
+///
+/// # Formula
+///
+/// AC = AO - SMA(AO, _period_)
+///
+/// Where:
+///
+/// * _AO_ - [Awesome Oscillator](struct.AwesomeOscillator.html) with the default periods of 5
+///   and 34
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _period_ - period of the SMA of the AO (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AcceleratorOscillator;
+/// use ta::{int, lit, Candle, Next};
+///
+/// let mut ac = AcceleratorOscillator::new(5).unwrap();
+/// let bar = |low| {
+///     Candle::builder()
+///         .open(low)
+///         .high(low + lit!(2.0))
+///         .low(low)
+///         .close(low + lit!(2.0))
+///         .volume(lit!(100.0))
+///         .build()
+///         .unwrap()
+/// };
+///
+/// // no momentum yet
+/// assert_eq!(ac.next(&bar(lit!(10.0))), lit!(0.0));
+///
+/// // a rally gains momentum
+/// let mut value = lit!(0.0);
+/// for low in [11, 13, 16, 20, 25] {
+///     value = ac.next(&bar(int!(low)));
+/// }
+/// assert!(value > lit!(0.0));
+/// ```
+///
+/// # Links
+///
+/// * [Accelerator Oscillator, TradingView](https://www.tradingview.com/support/solutions/43000501837-accelerator-oscillator-ac/)
+///
+#[doc(alias = "AC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AcceleratorOscillator {
+    ao: AwesomeOscillator,
+    sma: Sma,
+}
+
+impl AcceleratorOscillator {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ao: AwesomeOscillator::default(),
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for AcceleratorOscillator {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: High + Low> Next<&T> for AcceleratorOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ao = self.ao.next(input);
+        ao - self.sma.next(ao)
+    }
+}
+
+impl Reset for AcceleratorOscillator {
+    fn reset(&mut self) {
+        self.ao.reset();
+        self.sma.reset();
+    }
+}
+
+impl Default for AcceleratorOscillator {
+    fn default() -> Self {
+        Self::new(5).unwrap()
+    }
+}
+
+impl fmt::Display for AcceleratorOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AC({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::{int, lit};
+
+    fn sine_bar(i: usize) -> Bar {
+        // median price oscillating around 100 with a cycle of 60 bars
+        let median = 100.0 + 10.0 * (i as f64 * core::f64::consts::TAU / 60.0).sin();
+        let median = crate::num::from_f64(median).unwrap();
+        Bar::new().high(median + lit!(1.0)).low(median - lit!(1.0))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(AcceleratorOscillator::new(0).is_err());
+        assert!(AcceleratorOscillator::new(5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ac = AcceleratorOscillator::new(5).unwrap();
+        let mut ao = AwesomeOscillator::default();
+        let mut sma = Sma::new(5).unwrap();
+
+        for i in 0..50 {
+            let bar = Bar::new().high(int!(20 + i % 7)).low(int!(10 + i % 3));
+            let expected = ao.next(&bar);
+            assert_eq!(ac.next(&bar), expected - sma.next(expected));
+        }
+    }
+
+    #[test]
+    fn test_leads_awesome_oscillator() {
+        let mut ac = AcceleratorOscillator::default();
+        let mut ao = AwesomeOscillator::default();
+
+        let mut prev = None;
+        let mut last_ac_cross = 0;
+        let mut ao_crosses = 0;
+        for i in 0..240 {
+            let bar = sine_bar(i);
+            let (ac, ao) = (ac.next(&bar), ao.next(&bar));
+
+            if let Some((prev_ac, prev_ao)) = prev {
+                if (prev_ac < lit!(0.0)) != (ac < lit!(0.0)) {
+                    last_ac_cross = i;
+                }
+                // skip the warmup of the slow SMA of the AO
+                if i > 40 && (prev_ao < lit!(0.0)) != (ao < lit!(0.0)) {
+                    ao_crosses += 1;
+                    // the AC has already crossed to the same side
+                    assert_eq!(ac < lit!(0.0), ao < lit!(0.0));
+                    assert!(last_ac_cross < i, "AO crossed zero at {}", i);
+                }
+            }
+            prev = Some((ac, ao));
+        }
+        assert!(ao_crosses >= 6);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ac = AcceleratorOscillator::default();
+        let mut fresh = AcceleratorOscillator::default();
+
+        for i in 0..20 {
+            ac.next(&sine_bar(i));
+        }
+        ac.reset();
+
+        for i in 20..30 {
+            assert_eq!(ac.next(&sine_bar(i)), fresh.next(&sine_bar(i)));
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        AcceleratorOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AcceleratorOscillator::default();
+        assert_eq!(format!("{}", indicator), "AC(5)");
+    }
+}
@@ -0,0 +1,143 @@
+use core::fmt;
+
+use crate::indicators::CloseLocationValue;
+use crate::{lit, Close, High, Low, Next, Reset, ResetTo, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accumulation/Distribution Line (ADL).
+///
+/// Developed by Marc Chaikin, the ADL is a cumulative volume based indicator. Each period
+/// adds the volume weighted by where the close is located within the range of the bar,
+/// so closes near the high accumulate and closes near the low distribute.
+///
+/// # Formula
+///
+/// ADL<sub>t</sub> = ADL<sub>t-1</sub> + CLV * volume
+///
+/// Where:
+///
+/// * _CLV_ - [close location value](struct.CloseLocationValue.html), the money flow
+///   multiplier `((close - low) - (high - close)) / (high - low)`. It is 0 if high equals low.
+///
+/// # Links
+///
+/// * [Accumulation/distribution index, Wikipedia](https://en.wikipedia.org/wiki/Accumulation/distribution_index)
+///
+#[doc(alias = "ADL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccumulationDistribution {
+    clv: CloseLocationValue,
+    adl: crate::Num,
+}
+
+impl AccumulationDistribution {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            clv: CloseLocationValue::new(),
+            adl: lit!(0.0),
+        }
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for AccumulationDistribution {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.adl += self.clv.next(input) * input.volume();
+        self.adl
+    }
+}
+
+impl Reset for AccumulationDistribution {
+    fn reset(&mut self) {
+        self.adl = lit!(0.0);
+    }
+}
+
+impl ResetTo for AccumulationDistribution {
+    fn reset_to(&mut self, seed: crate::Num) {
+        self.adl = seed;
+    }
+}
+
+impl Default for AccumulationDistribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for AccumulationDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADL")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut adl = AccumulationDistribution::new();
+
+        // clv = 1
+        let bar1 = Bar::new().high(12).low(8).close(12).volume(1000);
+        // clv = 0.5
+        let bar2 = Bar::new().high(12).low(8).close(11).volume(2000);
+        // clv = -1
+        let bar3 = Bar::new().high(11).low(9).close(9).volume(500);
+
+        assert_eq!(adl.next(&bar1), lit!(1000.0));
+        assert_eq!(adl.next(&bar2), lit!(2000.0));
+        assert_eq!(adl.next(&bar3), lit!(1500.0));
+    }
+
+    #[test]
+    fn test_next_no_range() {
+        let mut adl = AccumulationDistribution::new();
+
+        adl.next(&Bar::new().high(12).low(8).close(12).volume(1000));
+
+        // high equals low, the multiplier is 0
+        let bar = Bar::new().high(10).low(10).close(10).volume(5000);
+        assert_eq!(adl.next(&bar), lit!(1000.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut adl = AccumulationDistribution::new();
+        let bar = Bar::new().high(12).low(8).close(12).volume(1000);
+
+        adl.next(&bar);
+        adl.next(&bar);
+
+        adl.reset();
+        assert_eq!(adl.next(&bar), lit!(1000.0));
+    }
+
+    #[test]
+    fn test_reset_to() {
+        let mut adl = AccumulationDistribution::new();
+        let bar = Bar::new().high(12).low(8).close(12).volume(1000);
+
+        adl.next(&bar);
+
+        adl.reset_to(lit!(100.0));
+        assert_eq!(adl.next(&bar), lit!(1100.0));
+    }
+
+    #[test]
+    fn test_default() {
+        AccumulationDistribution::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AccumulationDistribution::new();
+        assert_eq!(format!("{}", indicator), "ADL");
+    }
+}
@@ -0,0 +1,245 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::helpers::RingWindow;
+use crate::indicators::{MedianPrice, SmoothedMovingAverage as Smma};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Alligator.
+///
+/// Developed by Bill Williams, the Alligator is made of three
+/// [smoothed moving averages](struct.SmoothedMovingAverage.html) of the
+/// [median price](struct.MedianPrice.html), each shifted into the future by a number of
+/// bars. When the lines are intertwined the market is ranging ("the alligator sleeps"),
+/// when they spread apart in order the market is trending.
+///
+/// # Formula
+///
+/// * _jaw_ - SMMA(median price, _jaw_period_), shifted by _jaw_shift_ bars
+/// * _teeth_ - SMMA(median price, _teeth_period_), shifted by _teeth_shift_ bars
+/// * _lips_ - SMMA(median price, _lips_period_), shifted by _lips_shift_ bars
+///
+/// On a chart each line is drawn _shift_ bars to the right of the bar it was computed on.
+/// An indicator can't output values for future bars, so the output of a bar holds the
+/// values that are drawn on it: each line lags by its _shift_ bars. The shifted values are
+/// buffered internally. Until _shift_ bars are known the line repeats its first value.
+///
+/// # Parameters
+///
+/// * _jaw_period_, _jaw_shift_ - integers, the period greater than 0. Default is 13 and 8.
+/// * _teeth_period_, _teeth_shift_ - integers, the period greater than 0. Default is 8
+///   and 5.
+/// * _lips_period_, _lips_shift_ - integers, the period greater than 0. Default is 5 and 3.
+///
+/// # Links
+///
+/// * [Alligator, Investopedia](https://www.investopedia.com/articles/trading/072115/exploring-williams-alligator-indicator.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Alligator {
+    median: MedianPrice,
+    jaw: AlligatorLine,
+    teeth: AlligatorLine,
+    lips: AlligatorLine,
+}
+
+/// A smoothed moving average and the buffer of its values, shifted by `shift` bars.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct AlligatorLine {
+    smma: Smma,
+    shift: usize,
+    window: RingWindow,
+}
+
+impl AlligatorLine {
+    fn new(period: usize, shift: usize) -> Result<Self> {
+        Ok(Self {
+            smma: Smma::new(period)?,
+            shift,
+            window: RingWindow::new(shift + 1),
+        })
+    }
+
+    fn next(&mut self, input: crate::Num) -> crate::Num {
+        self.window.push(self.smma.next(input));
+        self.window.lookback(self.shift).unwrap()
+    }
+
+    fn reset(&mut self) {
+        self.smma.reset();
+        self.window.clear();
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlligatorOutput {
+    pub jaw: crate::Num,
+    pub teeth: crate::Num,
+    pub lips: crate::Num,
+}
+
+impl From<AlligatorOutput> for (crate::Num, crate::Num, crate::Num) {
+    fn from(ao: AlligatorOutput) -> Self {
+        (ao.jaw, ao.teeth, ao.lips)
+    }
+}
+
+impl Alligator {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(
+        jaw_period: usize,
+        jaw_shift: usize,
+        teeth_period: usize,
+        teeth_shift: usize,
+        lips_period: usize,
+        lips_shift: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            median: MedianPrice::new(),
+            jaw: AlligatorLine::new(jaw_period, jaw_shift)?,
+            teeth: AlligatorLine::new(teeth_period, teeth_shift)?,
+            lips: AlligatorLine::new(lips_period, lips_shift)?,
+        })
+    }
+}
+
+impl<T: High + Low> Next<&T> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let median = self.median.next(input);
+
+        AlligatorOutput {
+            jaw: self.jaw.next(median),
+            teeth: self.teeth.next(median),
+            lips: self.lips.next(median),
+        }
+    }
+}
+
+impl Reset for Alligator {
+    fn reset(&mut self) {
+        self.jaw.reset();
+        self.teeth.reset();
+        self.lips.reset();
+    }
+}
+
+impl Default for Alligator {
+    fn default() -> Self {
+        Self::new(13, 8, 8, 5, 5, 3).unwrap()
+    }
+}
+
+impl fmt::Display for Alligator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALLIGATOR({},{},{},{},{},{})",
+            self.jaw.smma.period(),
+            self.jaw.shift,
+            self.teeth.smma.period(),
+            self.teeth.shift,
+            self.lips.smma.period(),
+            self.lips.shift
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+    use crate::Num;
+
+    // a bar with the given median price
+    fn bar(median: i32) -> Bar {
+        Bar::new().high(median + 1).low(median - 1)
+    }
+
+    fn lines(out: AlligatorOutput) -> (Num, Num, Num) {
+        out.into()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Alligator::new(0, 8, 8, 5, 5, 3).is_err());
+        assert!(Alligator::new(13, 8, 0, 5, 5, 3).is_err());
+        assert!(Alligator::new(13, 8, 8, 5, 0, 3).is_err());
+        assert!(Alligator::new(1, 0, 1, 0, 1, 0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        // jaw: 10, 11, 12, 13.333, teeth: 10, 11, 12.5, 14.25
+        assert_eq!(
+            lines(alligator.next(&bar(10))),
+            (lit!(10.0), lit!(10.0), lit!(10.0))
+        );
+        assert_eq!(
+            lines(alligator.next(&bar(12))),
+            (lit!(10.0), lit!(10.0), lit!(12.0))
+        );
+        assert_eq!(
+            lines(alligator.next(&bar(14))),
+            (lit!(10.0), lit!(11.0), lit!(14.0))
+        );
+        assert_eq!(
+            lines(alligator.next(&bar(16))),
+            (lit!(11.0), lit!(12.5), lit!(16.0))
+        );
+        assert_eq!(
+            lines(alligator.next(&bar(18))),
+            (lit!(12.0), lit!(14.25), lit!(18.0))
+        );
+    }
+
+    #[test]
+    fn test_next_uptrend() {
+        let mut alligator = Alligator::default();
+
+        // the alligator opens its mouth, the faster lines move above the slower ones
+        for median in 0..40 {
+            let out = alligator.next(&bar(100 + 2 * median));
+            if median >= 10 {
+                assert!(out.lips > out.teeth);
+                assert!(out.teeth > out.jaw);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alligator = Alligator::new(3, 2, 2, 1, 1, 0).unwrap();
+
+        alligator.next(&bar(10));
+        alligator.next(&bar(12));
+
+        alligator.reset();
+        assert_eq!(
+            lines(alligator.next(&bar(20))),
+            (lit!(20.0), lit!(20.0), lit!(20.0))
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Alligator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Alligator::default();
+        assert_eq!(format!("{}", indicator), "ALLIGATOR(13,8,8,5,5,3)");
+    }
+}
@@ -0,0 +1,242 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, num, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Arnaud Legoux moving average (ALMA).
+///
+/// Developed by Arnaud Legoux and Dimitrios Kouzis-Loukas, ALMA weights the window with a
+/// Gaussian curve. Moving the peak of the curve towards the newest value reduces the lag,
+/// while the width of the curve controls the smoothness.
+///
+/// # Formula
+///
+/// ALMA = sum(w<sub>i</sub> * price<sub>i</sub>) / sum(w<sub>i</sub>)
+///
+/// w<sub>i</sub> = exp(-(i - m)<sup>2</sup> / (2 * s<sup>2</sup>))
+///
+/// Where:
+///
+/// * _i_ - position within the window, 0 being the oldest price
+/// * _m_ = _offset_ * (_window_ - 1)
+/// * _s_ = _window_ / _sigma_
+///
+/// The weights are computed once, when the indicator is created. `Decimal` can't represent
+/// them exactly: `exp` is approximated to the 28 significant digits of `Decimal`, and weights
+/// too small to be represented become 0. Dividing by the sum of the stored weights keeps the
+/// average normalized regardless of these rounding errors.
+///
+/// While fewer than _window_ prices are known, the newest prices are weighted with the
+/// newest weights of the window.
+///
+/// # Parameters
+///
+/// * _window_ - number of periods (integer greater than 0). Default is 9.
+/// * _offset_ - position of the peak of the curve, from 0 (the oldest price) to 1 (the
+///   newest price). Default is 0.85.
+/// * _sigma_ - sharpness of the curve (greater than 0). Default is 6.0.
+///
+/// # Links
+///
+/// * [Arnaud Legoux Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000594683-arnaud-legoux-moving-average/)
+///
+#[doc(alias = "ALMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ArnaudLegouxMovingAverage {
+    window: usize,
+    offset: crate::Num,
+    sigma: crate::Num,
+    weights: Box<[crate::Num]>,
+    index: usize,
+    count: usize,
+    deque: Box<[crate::Num]>,
+}
+
+impl ArnaudLegouxMovingAverage {
+    /// # Errors
+    ///
+    /// Will return `Err` if `window` is 0, `offset` is outside of 0..1 or `sigma` is not
+    /// positive
+    pub fn new(window: usize, offset: crate::Num, sigma: crate::Num) -> Result<Self> {
+        if window == 0 {
+            return Err(TaError::InvalidPeriod { got: 0 });
+        }
+        if offset < lit!(0.0) || offset > lit!(1.0) || sigma <= lit!(0.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let m = offset * int!(window - 1);
+        let s = int!(window) / sigma;
+        let weights = (0..window)
+            .map(|i| {
+                let distance = int!(i) - m;
+                num::exp(-(distance * distance) / (lit!(2.0) * s * s)).unwrap_or(lit!(0.0))
+            })
+            .collect();
+
+        Ok(Self {
+            window,
+            offset,
+            sigma,
+            weights,
+            index: 0,
+            count: 0,
+            deque: vec![lit!(0.0); window].into_boxed_slice(),
+        })
+    }
+
+    #[must_use]
+    pub fn offset(&self) -> crate::Num {
+        self.offset
+    }
+
+    #[must_use]
+    pub fn sigma(&self) -> crate::Num {
+        self.sigma
+    }
+}
+
+impl Period for ArnaudLegouxMovingAverage {
+    fn period(&self) -> usize {
+        self.window
+    }
+}
+
+impl Next<crate::Num> for ArnaudLegouxMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.window {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.window {
+            self.count += 1;
+        }
+
+        // walk the window from the oldest price to the newest one
+        let oldest = if self.count < self.window {
+            0
+        } else {
+            self.index
+        };
+        let first_weight = self.window - self.count;
+        let mut sum = lit!(0.0);
+        let mut sum_weights = lit!(0.0);
+        for i in 0..self.count {
+            let weight = self.weights[first_weight + i];
+            sum += weight * self.deque[(oldest + i) % self.window];
+            sum_weights += weight;
+        }
+
+        if sum_weights == lit!(0.0) {
+            input
+        } else {
+            sum / sum_weights
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for ArnaudLegouxMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ArnaudLegouxMovingAverage {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.window {
+            self.deque[i] = lit!(0.0);
+        }
+    }
+}
+
+impl Default for ArnaudLegouxMovingAverage {
+    fn default() -> Self {
+        Self::new(9, lit!(0.85), lit!(6.0)).unwrap()
+    }
+}
+
+impl fmt::Display for ArnaudLegouxMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ALMA({}, {}, {})", self.window, self.offset, self.sigma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ArnaudLegouxMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(ArnaudLegouxMovingAverage::new(0, lit!(0.85), lit!(6.0)).is_err());
+        assert!(ArnaudLegouxMovingAverage::new(9, lit!(-0.1), lit!(6.0)).is_err());
+        assert!(ArnaudLegouxMovingAverage::new(9, lit!(1.1), lit!(6.0)).is_err());
+        assert!(ArnaudLegouxMovingAverage::new(9, lit!(0.85), lit!(0.0)).is_err());
+        assert!(ArnaudLegouxMovingAverage::new(1, lit!(0.0), lit!(6.0)).is_ok());
+        assert!(ArnaudLegouxMovingAverage::new(9, lit!(1.0), lit!(6.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next_centered() {
+        let mut alma = ArnaudLegouxMovingAverage::new(3, lit!(0.5), lit!(6.0)).unwrap();
+
+        assert_eq!(alma.next(lit!(1.0)), lit!(1.0));
+        assert_eq!(round(alma.next(lit!(2.0))), lit!(1.119));
+        // symmetric weights around the middle price
+        assert_eq!(round(alma.next(lit!(3.0))), lit!(2.0));
+        assert_eq!(round(alma.next(lit!(4.0))), lit!(3.0));
+        assert_eq!(round(alma.next(&Bar::new().close(10))), lit!(4.533));
+    }
+
+    #[test]
+    fn test_next_offset() {
+        let mut alma = ArnaudLegouxMovingAverage::new(4, lit!(0.85), lit!(6.0)).unwrap();
+
+        assert_eq!(alma.next(lit!(1.0)), lit!(1.0));
+        assert_eq!(round(alma.next(lit!(2.0))), lit!(1.528));
+        assert_eq!(round(alma.next(lit!(3.0))), lit!(2.463));
+        assert_eq!(round(alma.next(lit!(4.0))), lit!(3.462));
+        assert_eq!(round(alma.next(lit!(10.0))), lit!(6.989));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alma = ArnaudLegouxMovingAverage::new(3, lit!(0.5), lit!(6.0)).unwrap();
+
+        alma.next(lit!(5.0));
+        alma.next(lit!(7.0));
+
+        alma.reset();
+        assert_eq!(alma.next(lit!(1.0)), lit!(1.0));
+        assert_eq!(round(alma.next(lit!(2.0))), lit!(1.119));
+    }
+
+    #[test]
+    fn test_default() {
+        ArnaudLegouxMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ArnaudLegouxMovingAverage::default();
+        #[cfg(not(feature = "f64-backend"))]
+        assert_eq!(format!("{}", indicator), "ALMA(9, 0.85, 6.0)");
+        #[cfg(feature = "f64-backend")]
+        assert_eq!(format!("{}", indicator), "ALMA(9, 0.85, 6)");
+    }
+}
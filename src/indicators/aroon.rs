@@ -0,0 +1,227 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon indicator and Aroon Oscillator.
+///
+/// Developed by Tushar Chande, the Aroon indicator measures how many periods have passed
+/// since the highest high and the lowest low within the time frame. A strong uptrend
+/// keeps Aroon Up close to 100, a strong downtrend keeps Aroon Down close to 100.
+///
+/// # Formula
+///
+/// Aroon Up = 100 * (_period_ - periods since the highest high) / _period_
+///
+/// Aroon Down = 100 * (_period_ - periods since the lowest low) / _period_
+///
+/// Aroon Oscillator = Aroon Up - Aroon Down
+///
+/// The extremes are searched among the last _period_ + 1 bars, including the current one.
+/// If the extreme occurs more than once, the most recent occurrence is used.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 25.
+///
+/// # Links
+///
+/// * [Aroon, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+///
+#[doc(alias = "AROON")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    period: usize,
+    index: usize,
+    count: usize,
+    highs: Box<[crate::Num]>,
+    lows: Box<[crate::Num]>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput {
+    pub aroon_up: crate::Num,
+    pub aroon_down: crate::Num,
+    pub oscillator: crate::Num,
+}
+
+impl From<AroonOutput> for (crate::Num, crate::Num, crate::Num) {
+    fn from(ao: AroonOutput) -> Self {
+        (ao.aroon_up, ao.aroon_down, ao.oscillator)
+    }
+}
+
+impl Aroon {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                highs: vec![lit!(0.0); period + 1].into_boxed_slice(),
+                lows: vec![lit!(0.0); period + 1].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for Aroon {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low> Next<&T> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let length = self.period + 1;
+
+        self.highs[self.index] = input.high();
+        self.lows[self.index] = input.low();
+        if self.count < length {
+            self.count += 1;
+        }
+
+        // walk back from the current bar, so the most recent extreme wins on ties
+        let mut since_high = 0;
+        let mut since_low = 0;
+        for ago in 1..self.count {
+            let i = (self.index + length - ago) % length;
+            if self.highs[i] > self.highs[(self.index + length - since_high) % length] {
+                since_high = ago;
+            }
+            if self.lows[i] < self.lows[(self.index + length - since_low) % length] {
+                since_low = ago;
+            }
+        }
+
+        self.index = if self.index + 1 < length {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let period = int!(self.period);
+        let aroon_up = lit!(100.0) * (period - int!(since_high)) / period;
+        let aroon_down = lit!(100.0) * (period - int!(since_low)) / period;
+
+        AroonOutput {
+            aroon_up,
+            aroon_down,
+            oscillator: aroon_up - aroon_down,
+        }
+    }
+}
+
+impl Reset for Aroon {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..=self.period {
+            self.highs[i] = lit!(0.0);
+            self.lows[i] = lit!(0.0);
+        }
+    }
+}
+
+impl Default for Aroon {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl fmt::Display for Aroon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AROON({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Aroon::new(0).is_err());
+        assert!(Aroon::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        let out = aroon.next(&Bar::new().high(10).low(8));
+        assert_eq!(out.aroon_up, lit!(100.0));
+        assert_eq!(out.aroon_down, lit!(100.0));
+        assert_eq!(out.oscillator, lit!(0.0));
+
+        // lowest low is the current bar, highest high 1 bar ago
+        let out = aroon.next(&Bar::new().high(9).low(7));
+        assert_eq!(out.aroon_up, lit!(75.0));
+        assert_eq!(out.aroon_down, lit!(100.0));
+        assert_eq!(out.oscillator, lit!(-25.0));
+
+        let out = aroon.next(&Bar::new().high(9).low(8));
+        assert_eq!(out.aroon_up, lit!(50.0));
+        assert_eq!(out.aroon_down, lit!(75.0));
+        assert_eq!(out.oscillator, lit!(-25.0));
+
+        // a fresh high resets aroon up
+        let out = aroon.next(&Bar::new().high(11).low(9));
+        assert_eq!(out.aroon_up, lit!(100.0));
+        assert_eq!(out.aroon_down, lit!(50.0));
+        assert_eq!(out.oscillator, lit!(50.0));
+    }
+
+    #[test]
+    fn test_next_window() {
+        let mut aroon = Aroon::new(2).unwrap();
+
+        aroon.next(&Bar::new().high(20).low(1));
+        aroon.next(&Bar::new().high(10).low(5));
+        let out = aroon.next(&Bar::new().high(10).low(5));
+        assert_eq!(out.aroon_up, lit!(0.0));
+        assert_eq!(out.aroon_down, lit!(0.0));
+
+        // the first bar leaves the window, the most recent equal extremes are used
+        let out = aroon.next(&Bar::new().high(9).low(6));
+        assert_eq!(out.aroon_up, lit!(50.0));
+        assert_eq!(out.aroon_down, lit!(50.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        aroon.next(&Bar::new().high(10).low(8));
+        aroon.next(&Bar::new().high(9).low(7));
+
+        aroon.reset();
+        let out = aroon.next(&Bar::new().high(9).low(7));
+        assert_eq!(out.aroon_up, lit!(100.0));
+        assert_eq!(out.aroon_down, lit!(100.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Aroon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Aroon::new(25).unwrap();
+        assert_eq!(format!("{}", indicator), "AROON(25)");
+    }
+}
@@ -0,0 +1,114 @@
+use core::fmt;
+
+use crate::{lit, Close, High, Low, Next, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average Price (OHLC4).
+///
+/// The average of the open, the high, the low and the close of the bar. It is stateless, so
+/// it can be used to turn a stream of bars into a stream of prices for another indicator.
+///
+/// # Formula
+///
+/// OHLC4 = (open + high + low + close) / 4
+///
+#[doc(alias = "OHLC4")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AveragePrice {}
+
+impl AveragePrice {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for AveragePrice {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        (input.open() + input.high() + input.low() + input.close()) / lit!(4.0)
+    }
+}
+
+impl Reset for AveragePrice {
+    fn reset(&mut self) {}
+}
+
+impl Default for AveragePrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for AveragePrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OHLC4")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut ap = AveragePrice::new();
+
+        let bar = Bar::new().open(10).high(12).low(8).close(10);
+        assert_eq!(ap.next(&bar), lit!(10.0));
+        let bar = Bar::new().open(9).high(13).low(8).close(12);
+        assert_eq!(ap.next(&bar), lit!(10.5));
+        let bar = Bar::new().open(lit!(1.5)).high(3).low(1).close(2);
+        assert_eq!(ap.next(&bar), lit!(1.875));
+    }
+
+    #[test]
+    fn test_uses_open() {
+        let mut ap = AveragePrice::new();
+
+        // only the open differs
+        let low_open = Bar::new().open(8).high(12).low(8).close(10);
+        let high_open = Bar::new().open(12).high(12).low(8).close(10);
+        assert_eq!(ap.next(&low_open), lit!(9.5));
+        assert_eq!(ap.next(&high_open), lit!(10.5));
+    }
+
+    #[test]
+    fn test_into_ema() {
+        let mut ap = AveragePrice::new();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+
+        let bar1 = Bar::new().open(10).high(12).low(8).close(10);
+        let bar2 = Bar::new().open(11).high(13).low(9).close(11);
+        assert_eq!(ema.next(ap.next(&bar1)), lit!(10.0));
+        assert_eq!(ema.next(ap.next(&bar2)), lit!(10.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ap = AveragePrice::new();
+
+        ap.next(&Bar::new().open(9).high(13).low(8).close(12));
+        ap.reset();
+        assert_eq!(
+            ap.next(&Bar::new().open(10).high(12).low(8).close(10)),
+            lit!(10.0)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        AveragePrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AveragePrice::new();
+        assert_eq!(format!("{}", indicator), "OHLC4");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, TrueRange};
@@ -52,16 +52,16 @@ impl Period for AverageTrueRange {
     }
 }
 
-impl Next<rust_decimal::Decimal> for AverageTrueRange {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for AverageTrueRange {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         self.ema.next(self.true_range.next(input))
     }
 }
 
 impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.ema.next(self.true_range.next(input))
@@ -0,0 +1,152 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{MedianPrice, SimpleMovingAverage as Sma};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Awesome Oscillator (AO).
+///
+/// Developed by Bill Williams, the Awesome Oscillator measures the market momentum as the
+/// difference between a fast and a slow simple moving average of the median price.
+///
+/// # Formula
+///
+/// AO = SMA(median, _fast_period_) - SMA(median, _slow_period_)
+///
+/// Where:
+///
+/// * _median_ - [median price](struct.MedianPrice.html) of the bar, (high + low) / 2
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast SMA (integer greater than 0). Default is 5.
+/// * _slow_period_ - period of the slow SMA (integer greater than 0). Default is 34.
+///
+/// # Links
+///
+/// * [Awesome Oscillator, TradingView](https://www.tradingview.com/support/solutions/43000501826-awesome-oscillator-ao/)
+///
+#[doc(alias = "AO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AwesomeOscillator {
+    median: MedianPrice,
+    fast_sma: Sma,
+    slow_sma: Sma,
+}
+
+impl AwesomeOscillator {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+        Ok(Self {
+            median: MedianPrice::new(),
+            fast_sma: Sma::new(fast_period)?,
+            slow_sma: Sma::new(slow_period)?,
+        })
+    }
+}
+
+impl<T: High + Low> Next<&T> for AwesomeOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let median = self.median.next(input);
+        self.fast_sma.next(median) - self.slow_sma.next(median)
+    }
+}
+
+impl Reset for AwesomeOscillator {
+    fn reset(&mut self) {
+        self.fast_sma.reset();
+        self.slow_sma.reset();
+    }
+}
+
+impl Default for AwesomeOscillator {
+    fn default() -> Self {
+        Self::new(5, 34).unwrap()
+    }
+}
+
+impl fmt::Display for AwesomeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AO({},{})",
+            self.fast_sma.period(),
+            self.slow_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(AwesomeOscillator::new(0, 34).is_err());
+        assert!(AwesomeOscillator::new(5, 0).is_err());
+        assert!(AwesomeOscillator::new(5, 34).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+
+        // median = 10
+        assert_eq!(ao.next(&Bar::new().high(11).low(9)), lit!(0.0));
+        // median = 13, 11.5 - 11.5
+        assert_eq!(ao.next(&Bar::new().high(14).low(12)), lit!(0.0));
+        // median = 16, 14.5 - 13
+        assert_eq!(ao.next(&Bar::new().high(17).low(15)), lit!(1.5));
+        // median = 10, 13 - 13
+        assert_eq!(ao.next(&Bar::new().high(11).low(9)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_convergence() {
+        let mut ao = AwesomeOscillator::new(2, 4).unwrap();
+
+        for _ in 0..4 {
+            ao.next(&Bar::new().high(11).low(9));
+        }
+
+        // a jump of the price is followed by the fast SMA first
+        assert_eq!(ao.next(&Bar::new().high(21).low(19)), lit!(2.5));
+        assert_eq!(ao.next(&Bar::new().high(21).low(19)), lit!(5.0));
+        assert_eq!(ao.next(&Bar::new().high(21).low(19)), lit!(2.5));
+        // both SMAs have caught up with the new price
+        assert_eq!(ao.next(&Bar::new().high(21).low(19)), lit!(0.0));
+        assert_eq!(ao.next(&Bar::new().high(21).low(19)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+
+        ao.next(&Bar::new().high(11).low(9));
+        ao.next(&Bar::new().high(14).low(12));
+
+        ao.reset();
+        assert_eq!(ao.next(&Bar::new().high(17).low(15)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        AwesomeOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AwesomeOscillator::default();
+        assert_eq!(format!("{}", indicator), "AO(5,34)");
+    }
+}
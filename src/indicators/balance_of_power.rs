@@ -0,0 +1,155 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{lit, Close, High, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Balance of Power (BOP).
+///
+/// Developed by Igor Livshin, BOP measures the strength of the buyers against the sellers
+/// by relating the change from the open to the close with the range of the bar. It returns
+/// output in the range of -1..1.
+///
+/// # Formula
+///
+/// BOP = SMA((close - open) / (high - low), _period_)
+///
+/// Where:
+///
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// A bar with high equal to low contributes 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14. A period of 1
+///   gives the raw, unsmoothed BOP of each bar.
+///
+/// # Links
+///
+/// * [Balance of Power, TradingView](https://www.tradingview.com/support/solutions/43000589100-balance-of-power-bop/)
+///
+#[doc(alias = "BOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BalanceOfPower {
+    sma: Sma,
+}
+
+impl BalanceOfPower {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for BalanceOfPower {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for BalanceOfPower {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let range = input.high() - input.low();
+        let bop = if range == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            (input.close() - input.open()) / range
+        };
+
+        self.sma.next(bop)
+    }
+}
+
+impl Reset for BalanceOfPower {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for BalanceOfPower {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for BalanceOfPower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BOP({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BalanceOfPower::new(0).is_err());
+        assert!(BalanceOfPower::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_raw() {
+        let mut bop = BalanceOfPower::new(1).unwrap();
+
+        let bar1 = Bar::new().open(9).high(12).low(8).close(11);
+        let bar2 = Bar::new().open(12).high(12).low(8).close(8);
+        let bar3 = Bar::new().open(10).high(12).low(8).close(10);
+
+        assert_eq!(bop.next(&bar1), lit!(0.5));
+        assert_eq!(bop.next(&bar2), lit!(-1.0));
+        assert_eq!(bop.next(&bar3), lit!(0.0));
+    }
+
+    #[test]
+    fn test_next_smoothed() {
+        let mut bop = BalanceOfPower::new(2).unwrap();
+
+        let bar1 = Bar::new().open(9).high(12).low(8).close(11);
+        let bar2 = Bar::new().open(12).high(12).low(8).close(8);
+
+        assert_eq!(bop.next(&bar1), lit!(0.5));
+        assert_eq!(bop.next(&bar2), lit!(-0.25));
+    }
+
+    #[test]
+    fn test_next_no_range() {
+        let mut bop = BalanceOfPower::new(1).unwrap();
+
+        let bar = Bar::new().open(10).high(10).low(10).close(10);
+        assert_eq!(bop.next(&bar), lit!(0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bop = BalanceOfPower::new(2).unwrap();
+
+        bop.next(&Bar::new().open(9).high(12).low(8).close(11));
+
+        bop.reset();
+        let bar = Bar::new().open(12).high(12).low(8).close(8);
+        assert_eq!(bop.next(&bar), lit!(-1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        BalanceOfPower::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = BalanceOfPower::default();
+        assert_eq!(format!("{}", indicator), "BOP(14)");
+    }
+}
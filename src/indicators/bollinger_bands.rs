@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::StandardDeviation as Sd;
-use crate::{lit, Close, Next, Period, Reset};
+use crate::{lit, Bands, Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +21,23 @@ use serde::{Deserialize, Serialize};
 ///  * _BB<sub>Upper Band</sub>_ = SMA + SD of observation * multipler (usually 2.0)
 ///  * _BB<sub>Lower Band</sub>_ = SMA - SD of observation * multipler (usually 2.0)
 ///
+/// The output also carries two values derived from the bands:
+///
+///  * _Bandwidth_ = (upper - lower) / middle
+///  * _%B_ = (price - lower) / (upper - lower)
+///
+/// When the bands collapse (upper equals lower) both are 0. Bandwidth is also 0 when the
+/// middle band is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _multiplier_ - number of standard deviations between the average and the bands.
+///   Default is 2.0.
+/// * _ddof_ - delta degrees of freedom of the standard deviation, see
+///   [StandardDeviation](struct.StandardDeviation.html). Default is 0 (population), use
+///   [`new_with_ddof`](Self::new_with_ddof) with 1 for the sample standard deviation.
+///
 /// # Links
 ///
 /// * [Bollinger Bands, Wikipedia](https://en.wikipedia.org/wiki/Bollinger_Bands)
@@ -30,33 +47,68 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct BollingerBands {
     period: usize,
-    multiplier: rust_decimal::Decimal,
+    multiplier: crate::Num,
     sd: Sd,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct BollingerBandsOutput {
-    pub average: rust_decimal::Decimal,
-    pub upper: rust_decimal::Decimal,
-    pub lower: rust_decimal::Decimal,
+    pub average: crate::Num,
+    pub upper: crate::Num,
+    pub lower: crate::Num,
+    pub bandwidth: crate::Num,
+    pub percent_b: crate::Num,
+}
+
+impl Bands for BollingerBandsOutput {
+    fn upper(&self) -> crate::Num {
+        self.upper
+    }
+
+    fn average(&self) -> crate::Num {
+        self.average
+    }
+
+    fn lower(&self) -> crate::Num {
+        self.lower
+    }
 }
 
 impl BollingerBands {
     /// # Errors
     ///
-    /// Will return `Err` if period or multiplier is 0
-    pub fn new(period: usize, multiplier: rust_decimal::Decimal) -> Result<Self> {
+    /// Will return `Err(TaError::InvalidPeriod { .. })` if `period` is 0 and
+    /// `Err(TaError::InvalidMultiplier)` if `multiplier` is not positive
+    pub fn new(period: usize, multiplier: crate::Num) -> Result<Self> {
+        Self::new_with_ddof(period, multiplier, 0)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err(TaError::InvalidPeriod { .. })` if `period` is 0 and
+    /// `Err(TaError::InvalidMultiplier)` if `multiplier` is not positive
+    pub fn new_with_ddof(period: usize, multiplier: crate::Num, ddof: usize) -> Result<Self> {
+        if multiplier <= lit!(0.0) {
+            return Err(TaError::InvalidMultiplier);
+        }
+
         Ok(Self {
             period,
             multiplier,
-            sd: Sd::new(period)?,
+            sd: Sd::new_with_ddof(period, ddof)?,
         })
     }
 
     #[must_use]
-    pub fn multiplier(&self) -> rust_decimal::Decimal {
+    pub fn multiplier(&self) -> crate::Num {
         self.multiplier
     }
+
+    #[must_use]
+    pub fn ddof(&self) -> usize {
+        self.sd.ddof()
+    }
 }
 
 impl Period for BollingerBands {
@@ -65,17 +117,29 @@ impl Period for BollingerBands {
     }
 }
 
-impl Next<rust_decimal::Decimal> for BollingerBands {
+impl Next<crate::Num> for BollingerBands {
     type Output = BollingerBandsOutput;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let sd = self.sd.next(input);
         let mean = self.sd.mean();
+        let upper = mean + sd * self.multiplier;
+        let lower = mean - sd * self.multiplier;
+
+        let (bandwidth, percent_b) = if upper == lower {
+            (lit!(0.0), lit!(0.0))
+        } else if mean == lit!(0.0) {
+            (lit!(0.0), (input - lower) / (upper - lower))
+        } else {
+            ((upper - lower) / mean, (input - lower) / (upper - lower))
+        };
 
         Self::Output {
             average: mean,
-            upper: mean + sd * self.multiplier,
-            lower: mean - sd * self.multiplier,
+            upper,
+            lower,
+            bandwidth,
+            percent_b,
         }
     }
 }
@@ -102,7 +166,10 @@ impl Default for BollingerBands {
 
 impl fmt::Display for BollingerBands {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "BB({}, {})", self.period, self.multiplier)
+        match self.sd.ddof() {
+            0 => write!(f, "BB({}, {})", self.period, self.multiplier),
+            ddof => write!(f, "BB({}, {}, {})", self.period, self.multiplier, ddof),
+        }
     }
 }
 
@@ -115,7 +182,18 @@ mod tests {
 
     #[test]
     fn test_new() {
-        assert!(BollingerBands::new(0, lit!(2.0)).is_err());
+        assert_eq!(
+            BollingerBands::new(0, lit!(2.0)).unwrap_err(),
+            TaError::InvalidPeriod { got: 0 }
+        );
+        assert_eq!(
+            BollingerBands::new(10, lit!(0.0)).unwrap_err(),
+            TaError::InvalidMultiplier
+        );
+        assert_eq!(
+            BollingerBands::new_with_ddof(10, lit!(-2.0), 1).unwrap_err(),
+            TaError::InvalidMultiplier
+        );
         assert!(BollingerBands::new(1, lit!(2.0)).is_ok());
         assert!(BollingerBands::new(2, lit!(2.0)).is_ok());
     }
@@ -143,6 +221,65 @@ mod tests {
         assert_eq!(round(b.lower), lit!(0.5));
         assert_eq!(round(c.lower), lit!(-0.733));
         assert_eq!(round(d.lower), lit!(-0.395));
+
+        assert_eq!(a.bandwidth, lit!(0.0));
+        assert_eq!(round(b.bandwidth), lit!(1.714));
+        assert_eq!(round(c.bandwidth), lit!(2.55));
+        assert_eq!(round(d.bandwidth), lit!(2.193));
+
+        assert_eq!(a.percent_b, lit!(0.0));
+        assert_eq!(round(b.percent_b), lit!(0.75));
+        assert_eq!(round(c.percent_b), lit!(0.255));
+        assert_eq!(round(d.percent_b), lit!(0.742));
+    }
+
+    #[test]
+    fn test_next_collapsed() {
+        let mut bb = BollingerBands::new(3, lit!(2.0)).unwrap();
+
+        for _ in 0..4 {
+            let out = bb.next(lit!(5.0));
+            assert_eq!(out.upper, out.lower);
+            assert_eq!(out.bandwidth, lit!(0.0));
+            assert_eq!(out.percent_b, lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_next_zero_average() {
+        let mut bb = BollingerBands::new(2, lit!(1.0)).unwrap();
+
+        bb.next(lit!(-1.0));
+        let out = bb.next(lit!(1.0));
+        assert_eq!(out.average, lit!(0.0));
+        assert_eq!(out.bandwidth, lit!(0.0));
+        assert_eq!(out.percent_b, lit!(1.0));
+    }
+
+    #[test]
+    fn test_next_with_ddof() {
+        let mut bb = BollingerBands::new_with_ddof(3, lit!(2.0), 1).unwrap();
+
+        let a = bb.next(lit!(2.0));
+        let b = bb.next(lit!(5.0));
+        let c = bb.next(lit!(1.0));
+        let d = bb.next(lit!(6.25));
+
+        assert_eq!(round(a.average), lit!(2.0));
+        assert_eq!(round(b.average), lit!(3.5));
+        assert_eq!(round(c.average), lit!(2.667));
+        assert_eq!(round(d.average), lit!(4.083));
+
+        // the sample deviation is wider: sqrt(4.5), sqrt(4.333), sqrt(7.521)
+        assert_eq!(round(a.upper), lit!(2.0));
+        assert_eq!(round(b.upper), lit!(7.743));
+        assert_eq!(round(c.upper), lit!(6.83));
+        assert_eq!(round(d.upper), lit!(9.568));
+
+        assert_eq!(round(a.lower), lit!(2.0));
+        assert_eq!(round(b.lower), lit!(-0.743));
+        assert_eq!(round(c.lower), lit!(-1.497));
+        assert_eq!(round(d.lower), lit!(-1.401));
     }
 
     #[test]
@@ -172,6 +309,27 @@ mod tests {
         assert_eq!(out.lower, lit!(3.0));
     }
 
+    #[test]
+    fn test_distances() {
+        let out = BollingerBandsOutput {
+            average: lit!(10.0),
+            upper: lit!(12.0),
+            lower: lit!(8.0),
+            bandwidth: lit!(0.4),
+            percent_b: lit!(0.5),
+        };
+
+        let above = out.distances(lit!(13.0));
+        assert_eq!(above.to_upper, lit!(1.0));
+        assert_eq!(above.to_average, lit!(3.0));
+        assert_eq!(above.to_lower, lit!(5.0));
+
+        let below = out.distances(lit!(7.5));
+        assert_eq!(below.to_upper, lit!(-4.5));
+        assert_eq!(below.to_average, lit!(-2.5));
+        assert_eq!(below.to_lower, lit!(-0.5));
+    }
+
     #[test]
     fn test_default() {
         BollingerBands::default();
@@ -181,5 +339,7 @@ mod tests {
     fn test_display() {
         let bb = BollingerBands::new(10, crate::int!(3)).unwrap();
         assert_eq!(format!("{}", bb), "BB(10, 3)");
+        let bb = BollingerBands::new_with_ddof(10, crate::int!(3), 1).unwrap();
+        assert_eq!(format!("{}", bb), "BB(10, 3, 1)");
     }
 }
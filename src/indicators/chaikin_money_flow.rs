@@ -0,0 +1,193 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::CloseLocationValue;
+use crate::{lit, Close, High, Low, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Money Flow (CMF).
+///
+/// Developed by Marc Chaikin, the CMF measures the amount of money flow volume over a
+/// period. It returns output in the range of -1..1: positive values show buying pressure,
+/// negative values show selling pressure.
+///
+/// # Formula
+///
+/// CMF = sum(CLV * volume, _period_) / sum(volume, _period_)
+///
+/// Where:
+///
+/// * _CLV_ - [close location value](struct.CloseLocationValue.html)
+/// * _sum(x, n)_ - sum of _x_ over the last _n_ periods
+///
+/// If the total volume of the period is 0, CMF is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Links
+///
+/// * [Chaikin Money Flow, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_money_flow_cmf)
+///
+#[doc(alias = "CMF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChaikinMoneyFlow {
+    period: usize,
+    index: usize,
+    count: usize,
+    clv: CloseLocationValue,
+    sum_money_flow: crate::Num,
+    sum_volume: crate::Num,
+    deque: Box<[(crate::Num, crate::Num)]>,
+}
+
+impl ChaikinMoneyFlow {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                clv: CloseLocationValue::new(),
+                sum_money_flow: lit!(0.0),
+                sum_volume: lit!(0.0),
+                deque: vec![(lit!(0.0), lit!(0.0)); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for ChaikinMoneyFlow {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinMoneyFlow {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let volume = input.volume();
+        let money_flow = self.clv.next(input) * volume;
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let (old_money_flow, old_volume) = self.deque[self.index];
+            self.sum_money_flow -= old_money_flow;
+            self.sum_volume -= old_volume;
+        }
+
+        self.deque[self.index] = (money_flow, volume);
+        self.sum_money_flow += money_flow;
+        self.sum_volume += volume;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.sum_volume == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            self.sum_money_flow / self.sum_volume
+        }
+    }
+}
+
+impl Reset for ChaikinMoneyFlow {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_money_flow = lit!(0.0);
+        self.sum_volume = lit!(0.0);
+        for i in 0..self.period {
+            self.deque[i] = (lit!(0.0), lit!(0.0));
+        }
+    }
+}
+
+impl Default for ChaikinMoneyFlow {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ChaikinMoneyFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinMoneyFlow::new(0).is_err());
+        assert!(ChaikinMoneyFlow::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+
+        // clv = 1
+        let bar1 = Bar::new().high(12).low(8).close(12).volume(1000);
+        // clv = -1
+        let bar2 = Bar::new().high(12).low(8).close(8).volume(3000);
+        // clv = 0.5
+        let bar3 = Bar::new().high(12).low(8).close(11).volume(1000);
+
+        assert_eq!(cmf.next(&bar1), lit!(1.0));
+        // (1000 - 3000) / 4000
+        assert_eq!(cmf.next(&bar2), lit!(-0.5));
+        // bar1 leaves the window: (-3000 + 500) / 4000
+        assert_eq!(cmf.next(&bar3), lit!(-0.625));
+        // bar2 leaves the window: (500 + 500) / 2000
+        assert_eq!(cmf.next(&bar3), lit!(0.5));
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+
+        let bar = Bar::new().high(12).low(8).close(12).volume(0);
+        assert_eq!(cmf.next(&bar), lit!(0.0));
+        assert_eq!(cmf.next(&bar), lit!(0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+
+        cmf.next(&Bar::new().high(12).low(8).close(8).volume(3000));
+
+        cmf.reset();
+        let bar = Bar::new().high(12).low(8).close(12).volume(1000);
+        assert_eq!(cmf.next(&bar), lit!(1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinMoneyFlow::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ChaikinMoneyFlow::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "CMF(20)");
+    }
+}
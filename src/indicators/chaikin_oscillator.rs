@@ -0,0 +1,146 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{AccumulationDistribution, ExponentialMovingAverage as Ema};
+use crate::{Close, High, Low, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Oscillator (CHO).
+///
+/// Developed by Marc Chaikin, the oscillator applies the idea of MACD to the
+/// [Accumulation/Distribution Line](struct.AccumulationDistribution.html): it is the
+/// difference between a fast and a slow EMA of the ADL, and measures the momentum of the
+/// accumulation or distribution.
+///
+/// # Formula
+///
+/// CHO = EMA(ADL, _fast_period_) - EMA(ADL, _slow_period_)
+///
+/// Where:
+///
+/// * _ADL_ - [Accumulation/Distribution Line](struct.AccumulationDistribution.html)
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 3.
+/// * _slow_period_ - period of the slow EMA (integer greater than 0). Default is 10.
+///
+/// # Links
+///
+/// * [Chaikin Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_oscillator)
+///
+#[doc(alias = "CHO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChaikinOscillator {
+    adl: AccumulationDistribution,
+    fast_ema: Ema,
+    slow_ema: Ema,
+}
+
+impl ChaikinOscillator {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+        Ok(Self {
+            adl: AccumulationDistribution::new(),
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+        })
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let adl = self.adl.next(input);
+        self.fast_ema.next(adl) - self.slow_ema.next(adl)
+    }
+}
+
+impl Reset for ChaikinOscillator {
+    fn reset(&mut self) {
+        self.adl.reset();
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+    }
+}
+
+impl Default for ChaikinOscillator {
+    fn default() -> Self {
+        Self::new(3, 10).unwrap()
+    }
+}
+
+impl fmt::Display for ChaikinOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CHO({},{})",
+            self.fast_ema.period(),
+            self.slow_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinOscillator::new(0, 10).is_err());
+        assert!(ChaikinOscillator::new(3, 0).is_err());
+        assert!(ChaikinOscillator::new(3, 10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cho = ChaikinOscillator::new(2, 3).unwrap();
+
+        // adl = 1000
+        let bar1 = Bar::new().high(12).low(8).close(12).volume(1000);
+        // adl = 2000
+        let bar2 = Bar::new().high(12).low(8).close(11).volume(2000);
+        // adl = 1500
+        let bar3 = Bar::new().high(11).low(9).close(9).volume(500);
+
+        assert_eq!(cho.next(&bar1), lit!(0.0));
+        // 1666.667 - 1500
+        assert_eq!(round(cho.next(&bar2)), lit!(166.667));
+        // 1555.556 - 1500
+        assert_eq!(round(cho.next(&bar3)), lit!(55.556));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cho = ChaikinOscillator::new(2, 3).unwrap();
+
+        let bar1 = Bar::new().high(12).low(8).close(12).volume(1000);
+        let bar2 = Bar::new().high(12).low(8).close(11).volume(2000);
+
+        cho.next(&bar1);
+        cho.next(&bar2);
+
+        cho.reset();
+        assert_eq!(cho.next(&bar1), lit!(0.0));
+        assert_eq!(round(cho.next(&bar2)), lit!(166.667));
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ChaikinOscillator::default();
+        assert_eq!(format!("{}", indicator), "CHO(3,10)");
+    }
+}
@@ -0,0 +1,222 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chande Momentum Oscillator (CMO).
+///
+/// Developed by Tushar Chande, the CMO is a momentum oscillator that relates the sum of
+/// recent gains to the sum of recent losses. The oscillator returns output in the range of
+/// -100..100.
+///
+/// # Formula
+///
+/// CMO = 100 * (S<sub>U</sub> - S<sub>D</sub>) / (S<sub>U</sub> + S<sub>D</sub>)
+///
+/// Where:
+///
+/// * S<sub>U</sub> - sum of the price increases over the last _period_ changes
+/// * S<sub>D</sub> - sum of the absolute price decreases over the last _period_ changes
+///
+/// If there was no movement at all, CMO is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Links
+///
+/// * [Chande Momentum Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chande_momentum_oscillator)
+///
+#[doc(alias = "CMO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChandeMomentumOscillator {
+    period: usize,
+    index: usize,
+    count: usize,
+    prev_val: Option<crate::Num>,
+    sum_up: crate::Num,
+    sum_down: crate::Num,
+    deque: Box<[crate::Num]>,
+}
+
+impl ChandeMomentumOscillator {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                prev_val: None,
+                sum_up: lit!(0.0),
+                sum_down: lit!(0.0),
+                deque: vec![lit!(0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for ChandeMomentumOscillator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for ChandeMomentumOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let prev_val = self.prev_val.replace(input);
+        let change = match prev_val {
+            Some(prev_val) => input - prev_val,
+            None => return lit!(0.0),
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_change = self.deque[self.index];
+            if old_change > lit!(0.0) {
+                self.sum_up -= old_change;
+            } else {
+                self.sum_down += old_change;
+            }
+        }
+
+        self.deque[self.index] = change;
+        if change > lit!(0.0) {
+            self.sum_up += change;
+        } else {
+            self.sum_down -= change;
+        }
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let total = self.sum_up + self.sum_down;
+        if total == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            lit!(100.0) * (self.sum_up - self.sum_down) / total
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for ChandeMomentumOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ChandeMomentumOscillator {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.prev_val = None;
+        self.sum_up = lit!(0.0);
+        self.sum_down = lit!(0.0);
+        for i in 0..self.period {
+            self.deque[i] = lit!(0.0);
+        }
+    }
+}
+
+impl Default for ChandeMomentumOscillator {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for ChandeMomentumOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMO({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Cmo = ChandeMomentumOscillator;
+
+    test_indicator!(Cmo);
+
+    #[test]
+    fn test_new() {
+        assert!(Cmo::new(0).is_err());
+        assert!(Cmo::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cmo = Cmo::new(3).unwrap();
+
+        assert_eq!(cmo.next(lit!(10.0)), lit!(0.0));
+        // up = 1, down = 0
+        assert_eq!(cmo.next(lit!(11.0)), lit!(100.0));
+        // up = 1, down = 0.5
+        assert_eq!(round(cmo.next(lit!(10.5))), lit!(33.333));
+        // up = 2.5, down = 0.5
+        assert_eq!(round(cmo.next(lit!(12.0))), lit!(66.667));
+        // first change (+1) leaves the window: up = 1.5, down = 1.5
+        assert_eq!(cmo.next(lit!(11.0)), lit!(0.0));
+        // -0.5 leaves the window: up = 1.5, down = 3
+        assert_eq!(round(cmo.next(lit!(9.0))), lit!(-33.333));
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut cmo = Cmo::new(3).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(cmo.next(lit!(4.2)), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut cmo = Cmo::new(3).unwrap();
+
+        assert_eq!(cmo.next(&Bar::new().close(10)), lit!(0.0));
+        assert_eq!(cmo.next(&Bar::new().close(11)), lit!(100.0));
+        assert_eq!(round(cmo.next(&Bar::new().close(9))), lit!(-33.333));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmo = Cmo::new(3).unwrap();
+
+        cmo.next(lit!(10.0));
+        cmo.next(lit!(9.0));
+
+        cmo.reset();
+        assert_eq!(cmo.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(cmo.next(lit!(11.0)), lit!(100.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Cmo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Cmo::new(9).unwrap();
+        assert_eq!(format!("{}", indicator), "CMO(9)");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -35,14 +35,14 @@ pub struct ChandelierExit {
     atr: AverageTrueRange,
     min: Minimum,
     max: Maximum,
-    multiplier: rust_decimal::Decimal,
+    multiplier: crate::Num,
 }
 
 impl ChandelierExit {
     /// # Errors
     ///
     /// Will return `Err` if period or multipler is 0
-    pub fn new(period: usize, multiplier: rust_decimal::Decimal) -> Result<Self> {
+    pub fn new(period: usize, multiplier: crate::Num) -> Result<Self> {
         Ok(Self {
             atr: AverageTrueRange::new(period)?,
             min: Minimum::new(period)?,
@@ -52,18 +52,19 @@ impl ChandelierExit {
     }
 
     #[must_use]
-    pub fn multiplier(&self) -> rust_decimal::Decimal {
+    pub fn multiplier(&self) -> crate::Num {
         self.multiplier
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ChandelierExitOutput {
-    pub long: rust_decimal::Decimal,
-    pub short: rust_decimal::Decimal,
+    pub long: crate::Num,
+    pub short: crate::Num,
 }
 
-impl From<ChandelierExitOutput> for (rust_decimal::Decimal,rust_decimal::Decimal) {
+impl From<ChandelierExitOutput> for (crate::Num, crate::Num) {
     fn from(ce: ChandelierExitOutput) -> Self {
         (ce.long, ce.short)
     }
@@ -118,7 +119,7 @@ mod tests {
 
     type Ce = ChandelierExit;
 
-    fn round(nums: (rust_decimal::Decimal,rust_decimal::Decimal)) -> (rust_decimal::Decimal,rust_decimal::Decimal) {
+    fn round(nums: (crate::Num, crate::Num)) -> (crate::Num, crate::Num) {
         let n0 = (nums.0 * lit!(100.0)).round() / lit!(100.0);
         let n1 = (nums.1 * lit!(100.0)).round() / lit!(100.0);
         (n0, n1)
@@ -154,6 +155,27 @@ mod tests {
         assert_eq!(round(ce.next(&bar6).into()), (lit!(2.92), lit!(7.08)));
     }
 
+    #[test]
+    fn test_trending_series() {
+        let mut ce = Ce::new(5, lit!(2.0)).unwrap();
+
+        // uptrend, the long stop trails below the price
+        for close in 10..30 {
+            let bar = Bar::new().high(close + 1).low(close - 1).close(close);
+            let out = ce.next(&bar);
+            assert!(out.long < Close::close(&bar), "{:?}", out);
+        }
+
+        ce.reset();
+
+        // downtrend, the short stop trails above the price
+        for close in (10..30).rev() {
+            let bar = Bar::new().high(close + 1).low(close - 1).close(close);
+            let out = ce.next(&bar);
+            assert!(out.short > Close::close(&bar), "{:?}", out);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut ce = Ce::new(5, lit!(2.0)).unwrap();
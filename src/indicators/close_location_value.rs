@@ -0,0 +1,101 @@
+use core::fmt;
+
+use crate::{lit, Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Close Location Value (CLV).
+///
+/// Places the close within the range of the bar: +1 means the close is at the high,
+/// -1 means it is at the low and 0 means it is in the middle of the range. It is the money
+/// flow multiplier of the Accumulation/Distribution family of indicators.
+///
+/// # Formula
+///
+/// CLV = ((close - low) - (high - close)) / (high - low)
+///
+/// If the bar has no range (high equals low), CLV is 0.
+///
+/// # Links
+///
+/// * [Close Location Value, Wikipedia](https://en.wikipedia.org/wiki/Accumulation/distribution_index)
+///
+#[doc(alias = "CLV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CloseLocationValue {}
+
+impl CloseLocationValue {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for CloseLocationValue {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        let range = high - low;
+
+        if range == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            ((close - low) - (high - close)) / range
+        }
+    }
+}
+
+impl Reset for CloseLocationValue {
+    fn reset(&mut self) {}
+}
+
+impl Default for CloseLocationValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CloseLocationValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CLV")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut clv = CloseLocationValue::new();
+
+        // close at the high
+        assert_eq!(clv.next(&Bar::new().high(12).low(8).close(12)), lit!(1.0));
+        // close at the low
+        assert_eq!(clv.next(&Bar::new().high(12).low(8).close(8)), lit!(-1.0));
+        // close in the middle of the range
+        assert_eq!(clv.next(&Bar::new().high(12).low(8).close(10)), lit!(0.0));
+        assert_eq!(clv.next(&Bar::new().high(12).low(8).close(11)), lit!(0.5));
+    }
+
+    #[test]
+    fn test_next_no_range() {
+        let mut clv = CloseLocationValue::new();
+
+        assert_eq!(clv.next(&Bar::new().high(10).low(10).close(10)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        CloseLocationValue::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = CloseLocationValue::new();
+        assert_eq!(format!("{}", indicator), "CLV");
+    }
+}
@@ -1,11 +1,11 @@
-use std::fmt;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
 use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{lit, Close, High, Low, Next, Period, Reset};
+use crate::{lit, Close, High, Low, Next, Period, Reset, TypicalPriceExt};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -19,6 +19,10 @@ use crate::{lit, Close, High, Low, Next, Period, Reset};
 ///
 /// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) * 0.015)
 ///
+/// Where TP is the [typical price](crate::TypicalPriceExt::typical_price) and MAD the
+/// [mean absolute deviation](struct.MeanAbsoluteDeviation.html) of it. When MAD is 0, e.g.
+/// on the first bar or while the typical price doesn't change, CCI is 0.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default is 20.
@@ -54,12 +58,12 @@ impl Period for CommodityChannelIndex {
 }
 
 impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        let tp = (input.close() + input.high() + input.low()) / lit!(3.0);
+        let tp = input.typical_price();
         let sma = self.sma.next(tp);
-        let mad = self.mad.next(input);
+        let mad = self.mad.next(tp);
 
         if mad == lit!(0.0) {
             return lit!(0.0);
@@ -122,6 +126,54 @@ mod tests {
         assert_eq!(round(cci.next(&bar6)), lit!(-126.126));
     }
 
+    #[test]
+    fn test_zero_mad() {
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+        let bar = Bar::new().high(12).low(8).close(10);
+
+        for _ in 0..5 {
+            assert_eq!(cci.next(&bar), lit!(0.0));
+        }
+
+        // a different close with the same typical price keeps MAD at 0
+        let bar = Bar::new().high(11).low(7).close(12);
+        for _ in 0..3 {
+            assert_eq!(cci.next(&bar), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_mad_of_typical_price() {
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+
+        // the close stays at 10, the typical price is 10, 12 and 8
+        cci.next(&Bar::new().high(12).low(8).close(10));
+        // sma = 11, mad = 1
+        assert_eq!(
+            round(cci.next(&Bar::new().high(18).low(8).close(10))),
+            lit!(66.667)
+        );
+        // sma = 10, mad = 4/3
+        assert_eq!(
+            round(cci.next(&Bar::new().high(10).low(4).close(10))),
+            lit!(-100.0)
+        );
+    }
+
+    #[test]
+    fn test_mad_regression() {
+        let mut cci = CommodityChannelIndex::new(2).unwrap();
+
+        // closes 10 and 13, typical prices 10 and 12
+        cci.next(&Bar::new().high(12).low(8).close(10));
+        let value = round(cci.next(&Bar::new().high(13).low(10).close(13)));
+
+        // sma = 11, MAD of the typical price = 1
+        assert_eq!(value, lit!(66.667));
+        // the previous formula took MAD of the close = 1.5, (12 - 11) / (1.5 * 0.015)
+        assert_ne!(value, lit!(44.444));
+    }
+
     #[test]
     fn test_reset() {
         let mut cci = CommodityChannelIndex::new(5).unwrap();
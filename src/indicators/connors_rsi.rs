@@ -0,0 +1,238 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::RingWindow;
+use crate::indicators::RelativeStrengthIndex as Rsi;
+use crate::{int, lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Connors RSI (CRSI).
+///
+/// Developed by Larry Connors, Connors RSI averages three momentum components: a short
+/// [RSI](struct.RelativeStrengthIndex.html) of the price, an RSI of the up/down streak
+/// length and the percent rank of the latest one-period return. It returns output in the
+/// range of 0..100.
+///
+/// # Formula
+///
+/// CRSI = (RSI(close, _rsi_period_) + RSI(streak, _streak_period_) +
+/// PercentRank(return, _rank_period_)) / 3
+///
+/// Where:
+///
+/// * _streak_ - number of consecutive closes above the previous one, or the negated number
+///   of consecutive closes below the previous one. An unchanged close resets it to 0.
+/// * _return_ - one-period rate of change of the close, in percent
+/// * _PercentRank_ - percentage of the previous _rank_period_ returns that are lower than
+///   the latest return. While fewer returns are known, only the known ones are ranked.
+///
+/// The first bar has no previous close, so its streak is 0 and it has no return.
+///
+/// # Parameters
+///
+/// * _rsi_period_ - period of the price RSI (integer greater than 0). Default is 3.
+/// * _streak_period_ - period of the streak RSI (integer greater than 0). Default is 2.
+/// * _rank_period_ - number of returns the latest one is ranked against (integer greater
+///   than 0). Default is 100.
+///
+/// # Links
+///
+/// * [Connors RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:connorsrsi)
+///
+#[doc(alias = "CRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ConnorsRsi {
+    rsi: Rsi,
+    streak_rsi: Rsi,
+    rank_period: usize,
+    returns: RingWindow,
+    count: usize,
+    streak: crate::Num,
+    prev_close: Option<crate::Num>,
+}
+
+impl ConnorsRsi {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(rsi_period: usize, streak_period: usize, rank_period: usize) -> Result<Self> {
+        match rank_period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                rsi: Rsi::new(rsi_period)?,
+                streak_rsi: Rsi::new(streak_period)?,
+                rank_period,
+                returns: RingWindow::new(rank_period),
+                count: 0,
+                streak: lit!(0.0),
+                prev_close: None,
+            }),
+        }
+    }
+
+    /// Percentage of the known previous returns lower than `current`.
+    fn percent_rank(&self, current: crate::Num) -> crate::Num {
+        if self.count == 0 {
+            return lit!(0.0);
+        }
+
+        let lower = self.returns.iter().filter(|r| *r < current).count();
+        int!(lower) * lit!(100.0) / int!(self.count)
+    }
+}
+
+impl Next<crate::Num> for ConnorsRsi {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let rank = match self.prev_close.replace(input) {
+            Some(prev_close) => {
+                self.streak = if input > prev_close {
+                    self.streak.max(lit!(0.0)) + lit!(1.0)
+                } else if input < prev_close {
+                    self.streak.min(lit!(0.0)) - lit!(1.0)
+                } else {
+                    lit!(0.0)
+                };
+
+                let current = if prev_close == lit!(0.0) {
+                    lit!(0.0)
+                } else {
+                    (input - prev_close) / prev_close * lit!(100.0)
+                };
+                let rank = self.percent_rank(current);
+
+                self.returns.push(current);
+                if self.count < self.rank_period {
+                    self.count += 1;
+                }
+                rank
+            }
+            None => lit!(0.0),
+        };
+
+        let rsi = self.rsi.next(input);
+        let streak_rsi = self.streak_rsi.next(self.streak);
+
+        (rsi + streak_rsi + rank) / lit!(3.0)
+    }
+}
+
+impl<T: Close> Next<&T> for ConnorsRsi {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ConnorsRsi {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.streak_rsi.reset();
+        self.returns.clear();
+        self.count = 0;
+        self.streak = lit!(0.0);
+        self.prev_close = None;
+    }
+}
+
+impl Default for ConnorsRsi {
+    fn default() -> Self {
+        Self::new(3, 2, 100).unwrap()
+    }
+}
+
+impl fmt::Display for ConnorsRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CRSI({},{},{})",
+            self.rsi.period(),
+            self.streak_rsi.period(),
+            self.rank_period
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ConnorsRsi);
+
+    #[test]
+    fn test_new() {
+        assert!(ConnorsRsi::new(0, 2, 100).is_err());
+        assert!(ConnorsRsi::new(3, 0, 100).is_err());
+        assert!(ConnorsRsi::new(3, 2, 0).is_err());
+        assert!(ConnorsRsi::new(3, 2, 100).is_ok());
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut crsi = ConnorsRsi::new(3, 2, 5).unwrap();
+
+        // both RSIs stay at 50, no return is lower than another one
+        for _ in 0..5 {
+            assert_eq!(round(crsi.next(lit!(10.0))), lit!(33.333));
+        }
+    }
+
+    #[test]
+    fn test_next() {
+        let mut crsi = ConnorsRsi::new(3, 2, 3).unwrap();
+        let mut rsi = Rsi::new(3).unwrap();
+        let mut streak_rsi = Rsi::new(2).unwrap();
+
+        // (close, streak, percent rank of the return)
+        let series = [
+            (lit!(10.0), lit!(0.0), lit!(0.0)),
+            // +10%, nothing to rank against
+            (lit!(11.0), lit!(1.0), lit!(0.0)),
+            // +10%, +10% is not lower
+            (lit!(12.1), lit!(2.0), lit!(0.0)),
+            // 0%, both returns are higher
+            (lit!(12.1), lit!(0.0), lit!(0.0)),
+            // -50%, the lowest so far
+            (lit!(6.05), lit!(-1.0), lit!(0.0)),
+            // +100%, the first +10% left the window, the other 3 are lower
+            (lit!(12.1), lit!(1.0), lit!(100.0)),
+            // -50%, none is lower
+            (lit!(6.05), lit!(-1.0), lit!(0.0)),
+            // 0%, the two -50% are lower
+            (lit!(6.05), lit!(0.0), lit!(200.0) / lit!(3.0)),
+        ];
+
+        for (close, streak, rank) in series {
+            let expected = (rsi.next(close) + streak_rsi.next(streak) + rank) / lit!(3.0);
+            assert_eq!(crsi.next(close), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut crsi = ConnorsRsi::new(3, 2, 3).unwrap();
+
+        let first = crsi.next(lit!(10.0));
+        crsi.next(lit!(11.0));
+        crsi.next(lit!(9.0));
+
+        crsi.reset();
+        assert_eq!(crsi.next(lit!(10.0)), first);
+    }
+
+    #[test]
+    fn test_default() {
+        ConnorsRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ConnorsRsi::default();
+        assert_eq!(format!("{}", indicator), "CRSI(3,2,100)");
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, WeightedMovingAverage};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Coppock Curve (COPP).
+///
+/// Developed by Edwin Coppock, the Coppock Curve is a long-term momentum indicator,
+/// originally designed to spot buying opportunities in stock indices on monthly data. A
+/// turn up from below zero is read as a buy signal.
+///
+/// # Formula
+///
+/// COPP = WMA(ROC(_long_roc_period_) + ROC(_short_roc_period_), _wma_period_)
+///
+/// Where:
+///
+/// * _ROC_ - [rate of change](struct.RateOfChange.html)
+/// * _WMA_ - [weighted moving average](struct.WeightedMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _wma_period_ - period of the WMA (integer greater than 0). Default is 10.
+/// * _long_roc_period_ - period of the long ROC (integer greater than 0). Default is 14.
+/// * _short_roc_period_ - period of the short ROC (integer greater than 0). Default is 11.
+///
+/// # Links
+///
+/// * [Coppock curve, Wikipedia](https://en.wikipedia.org/wiki/Coppock_curve)
+///
+#[doc(alias = "COPP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CoppockCurve {
+    wma: WeightedMovingAverage,
+    long_roc: RateOfChange,
+    short_roc: RateOfChange,
+}
+
+impl CoppockCurve {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(wma_period: usize, long_roc_period: usize, short_roc_period: usize) -> Result<Self> {
+        Ok(Self {
+            wma: WeightedMovingAverage::new(wma_period)?,
+            long_roc: RateOfChange::new(long_roc_period)?,
+            short_roc: RateOfChange::new(short_roc_period)?,
+        })
+    }
+}
+
+impl Next<crate::Num> for CoppockCurve {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let roc = self.long_roc.next(input) + self.short_roc.next(input);
+        self.wma.next(roc)
+    }
+}
+
+impl<T: Close> Next<&T> for CoppockCurve {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for CoppockCurve {
+    fn reset(&mut self) {
+        self.wma.reset();
+        self.long_roc.reset();
+        self.short_roc.reset();
+    }
+}
+
+impl Default for CoppockCurve {
+    fn default() -> Self {
+        Self::new(10, 14, 11).unwrap()
+    }
+}
+
+impl fmt::Display for CoppockCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "COPP({},{},{})",
+            self.wma.period(),
+            self.long_roc.period(),
+            self.short_roc.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::{int, lit};
+
+    test_indicator!(CoppockCurve);
+
+    #[test]
+    fn test_new() {
+        assert!(CoppockCurve::new(0, 14, 11).is_err());
+        assert!(CoppockCurve::new(10, 0, 11).is_err());
+        assert!(CoppockCurve::new(10, 14, 0).is_err());
+        assert!(CoppockCurve::new(10, 14, 11).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut copp = CoppockCurve::new(2, 2, 1).unwrap();
+
+        // monthly closes
+        assert_eq!(copp.next(lit!(100.0)), lit!(0.0));
+        // rocs 10 + 10, (0 + 2 * 20) / 3
+        assert_eq!(round(copp.next(lit!(110.0))), lit!(13.333));
+        // rocs 21 + 10, (20 + 2 * 31) / 3
+        assert_eq!(round(copp.next(lit!(121.0))), lit!(27.333));
+        // rocs 0 - 9.091, (31 - 2 * 9.091) / 3
+        assert_eq!(round(copp.next(&Bar::new().close(110))), lit!(4.273));
+    }
+
+    #[test]
+    fn test_next_downtrend() {
+        let mut copp = CoppockCurve::new(3, 4, 2).unwrap();
+
+        copp.next(lit!(100.0));
+        for close in (50..100).rev() {
+            assert!(copp.next(int!(close)) < lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut copp = CoppockCurve::new(2, 2, 1).unwrap();
+
+        copp.next(lit!(100.0));
+        copp.next(lit!(110.0));
+
+        copp.reset();
+        assert_eq!(copp.next(lit!(121.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        CoppockCurve::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = CoppockCurve::default();
+        assert_eq!(format!("{}", indicator), "COPP(10,14,11)");
+    }
+}
@@ -0,0 +1,178 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::RingWindow;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Detrended Price Oscillator (DPO).
+///
+/// DPO removes the trend from the price by comparing a past price with the simple moving
+/// average, which makes cycles shorter than the period easier to see. The past price is
+/// taken from the middle of the moving average window, so that it lines up with the lag of
+/// the moving average.
+///
+/// # Formula
+///
+/// DPO<sub>t</sub> = price<sub>t-d</sub> - SMA(price, _period_)<sub>t</sub>
+///
+/// Where:
+///
+/// * _d_ - displacement, _period_ / 2 + 1 bars, using integer division. For the default
+///   period of 20 the price 11 bars ago is used.
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// During the first _d_ bars the price _d_ bars ago is not known yet and the oldest price
+/// seen so far, i.e. the first input, is used instead.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Links
+///
+/// * [Detrended Price Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:detrended_price_osci)
+///
+#[doc(alias = "DPO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DetrendedPriceOscillator {
+    period: usize,
+    displacement: usize,
+    sma: Sma,
+    window: RingWindow,
+}
+
+impl DetrendedPriceOscillator {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => {
+                let displacement = period / 2 + 1;
+                Ok(Self {
+                    period,
+                    displacement,
+                    sma: Sma::new(period)?,
+                    window: RingWindow::new(displacement + 1),
+                })
+            }
+        }
+    }
+
+    /// Number of bars between the current bar and the price compared with the SMA.
+    #[must_use]
+    pub fn displacement(&self) -> usize {
+        self.displacement
+    }
+}
+
+impl Period for DetrendedPriceOscillator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for DetrendedPriceOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.window.push(input);
+        let displaced = self.window.lookback(self.displacement).unwrap();
+
+        displaced - self.sma.next(input)
+    }
+}
+
+impl<T: Close> Next<&T> for DetrendedPriceOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DetrendedPriceOscillator {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.window.clear();
+    }
+}
+
+impl Default for DetrendedPriceOscillator {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for DetrendedPriceOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DPO({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    test_indicator!(DetrendedPriceOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(DetrendedPriceOscillator::new(0).is_err());
+        assert!(DetrendedPriceOscillator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_displacement() {
+        assert_eq!(DetrendedPriceOscillator::new(1).unwrap().displacement(), 1);
+        assert_eq!(DetrendedPriceOscillator::new(4).unwrap().displacement(), 3);
+        assert_eq!(DetrendedPriceOscillator::new(5).unwrap().displacement(), 3);
+        assert_eq!(DetrendedPriceOscillator::default().displacement(), 11);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dpo = DetrendedPriceOscillator::new(4).unwrap();
+
+        // warmup, the first price stands in for the price 3 bars ago
+        assert_eq!(dpo.next(lit!(1.0)), lit!(0.0));
+        assert_eq!(dpo.next(lit!(2.0)), lit!(-0.5));
+        assert_eq!(dpo.next(lit!(3.0)), lit!(-1.0));
+        // 1 - 2.5
+        assert_eq!(dpo.next(lit!(4.0)), lit!(-1.5));
+        // 2 - 3.5
+        assert_eq!(dpo.next(lit!(5.0)), lit!(-1.5));
+        // 3 - 4.5
+        assert_eq!(dpo.next(&Bar::new().close(6)), lit!(-1.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dpo = DetrendedPriceOscillator::new(4).unwrap();
+
+        dpo.next(lit!(1.0));
+        dpo.next(lit!(2.0));
+
+        dpo.reset();
+        assert_eq!(dpo.next(lit!(5.0)), lit!(0.0));
+        assert_eq!(dpo.next(lit!(7.0)), lit!(-1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        DetrendedPriceOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = DetrendedPriceOscillator::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "DPO(20)");
+    }
+}
@@ -0,0 +1,267 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::TrueRange;
+use crate::{int, lit, Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Directional Movement Index (DMI).
+///
+/// Developed by J. Welles Wilder, the directional movement index consists of two lines:
+/// the positive directional indicator (+DI) and the negative directional indicator (-DI).
+/// +DI measures the strength of upward price movement and -DI the strength of downward
+/// movement. They are the building blocks of the Average Directional Index (ADX).
+///
+/// # Formula
+///
+/// +DM<sub>t</sub> = high<sub>t</sub> - high<sub>t-1</sub>, if it is greater than
+/// low<sub>t-1</sub> - low<sub>t</sub> and greater than 0, otherwise 0
+///
+/// -DM<sub>t</sub> = low<sub>t-1</sub> - low<sub>t</sub>, if it is greater than
+/// high<sub>t</sub> - high<sub>t-1</sub> and greater than 0, otherwise 0
+///
+/// +DI = 100 * Wilder(+DM) / Wilder(TR)
+///
+/// -DI = 100 * Wilder(-DM) / Wilder(TR)
+///
+/// Where:
+///
+/// * _TR_ - [true range](struct.TrueRange.html)
+/// * _Wilder_ - Wilder's smoothing: the values are summed over the first _period_ bars, then
+///   S<sub>t</sub> = S<sub>t-1</sub> - S<sub>t-1</sub> / period + x<sub>t</sub>
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+///
+#[doc(alias = "DMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DirectionalMovementIndex {
+    period: usize,
+    count: usize,
+    true_range: TrueRange,
+    prev_high: Option<crate::Num>,
+    prev_low: Option<crate::Num>,
+    smoothed_tr: crate::Num,
+    smoothed_plus_dm: crate::Num,
+    smoothed_minus_dm: crate::Num,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectionalMovementIndexOutput {
+    pub plus_di: crate::Num,
+    pub minus_di: crate::Num,
+}
+
+impl From<DirectionalMovementIndexOutput> for (crate::Num, crate::Num) {
+    fn from(dmi: DirectionalMovementIndexOutput) -> Self {
+        (dmi.plus_di, dmi.minus_di)
+    }
+}
+
+impl DirectionalMovementIndex {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                true_range: TrueRange::new(),
+                prev_high: None,
+                prev_low: None,
+                smoothed_tr: lit!(0.0),
+                smoothed_plus_dm: lit!(0.0),
+                smoothed_minus_dm: lit!(0.0),
+            }),
+        }
+    }
+
+    fn smooth(&self, prev: crate::Num, input: crate::Num) -> crate::Num {
+        if self.count < self.period {
+            prev + input
+        } else {
+            prev - prev / int!(self.period) + input
+        }
+    }
+}
+
+impl Period for DirectionalMovementIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for DirectionalMovementIndex {
+    type Output = DirectionalMovementIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let tr = self.true_range.next(input);
+
+        let (plus_dm, minus_dm) = match (self.prev_high, self.prev_low) {
+            (Some(prev_high), Some(prev_low)) => {
+                let up_move = input.high() - prev_high;
+                let down_move = prev_low - input.low();
+
+                let plus_dm = if up_move > down_move && up_move > lit!(0.0) {
+                    up_move
+                } else {
+                    lit!(0.0)
+                };
+                let minus_dm = if down_move > up_move && down_move > lit!(0.0) {
+                    down_move
+                } else {
+                    lit!(0.0)
+                };
+                (plus_dm, minus_dm)
+            }
+            _ => (lit!(0.0), lit!(0.0)),
+        };
+
+        self.prev_high = Some(input.high());
+        self.prev_low = Some(input.low());
+
+        self.smoothed_tr = self.smooth(self.smoothed_tr, tr);
+        self.smoothed_plus_dm = self.smooth(self.smoothed_plus_dm, plus_dm);
+        self.smoothed_minus_dm = self.smooth(self.smoothed_minus_dm, minus_dm);
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        if self.smoothed_tr == lit!(0.0) {
+            return Self::Output {
+                plus_di: lit!(0.0),
+                minus_di: lit!(0.0),
+            };
+        }
+
+        Self::Output {
+            plus_di: lit!(100.0) * self.smoothed_plus_dm / self.smoothed_tr,
+            minus_di: lit!(100.0) * self.smoothed_minus_dm / self.smoothed_tr,
+        }
+    }
+}
+
+impl Reset for DirectionalMovementIndex {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.true_range.reset();
+        self.prev_high = None;
+        self.prev_low = None;
+        self.smoothed_tr = lit!(0.0);
+        self.smoothed_plus_dm = lit!(0.0);
+        self.smoothed_minus_dm = lit!(0.0);
+    }
+}
+
+impl Default for DirectionalMovementIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for DirectionalMovementIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DMI({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Dmi = DirectionalMovementIndex;
+
+    #[test]
+    fn test_new() {
+        assert!(Dmi::new(0).is_err());
+        assert!(Dmi::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dmi = Dmi::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let bar2 = Bar::new().high(12).low(9).close(11);
+        let bar3 = Bar::new().high(11).low(7).close(8);
+
+        // TR = 2, no directional movement yet
+        let out = dmi.next(&bar1);
+        assert_eq!(out.plus_di, lit!(0.0));
+        assert_eq!(out.minus_di, lit!(0.0));
+        // TR = 3, +DM = 2
+        let out = dmi.next(&bar2);
+        assert_eq!(out.plus_di, lit!(40.0));
+        assert_eq!(out.minus_di, lit!(0.0));
+        // TR = 4, -DM = 2
+        let out = dmi.next(&bar3);
+        assert_eq!(round(out.plus_di), lit!(22.222));
+        assert_eq!(round(out.minus_di), lit!(22.222));
+    }
+
+    #[test]
+    fn test_rising_series() {
+        let mut dmi = Dmi::new(5).unwrap();
+        let mut out = None;
+        for i in 0..20 {
+            let bar = Bar::new().high(10 + i).low(8 + i).close(9 + i);
+            out = Some(dmi.next(&bar));
+        }
+        let out = out.unwrap();
+        assert!(out.plus_di > out.minus_di);
+        assert_eq!(out.minus_di, lit!(0.0));
+    }
+
+    #[test]
+    fn test_falling_series() {
+        let mut dmi = Dmi::new(5).unwrap();
+        let mut out = None;
+        for i in 0..20 {
+            let bar = Bar::new().high(50 - i).low(48 - i).close(49 - i);
+            out = Some(dmi.next(&bar));
+        }
+        let out = out.unwrap();
+        assert!(out.minus_di > out.plus_di);
+        assert_eq!(out.plus_di, lit!(0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dmi = Dmi::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        let bar2 = Bar::new().high(12).low(9).close(11);
+
+        dmi.next(&bar1);
+        let out = dmi.next(&bar2);
+
+        dmi.reset();
+        let first = dmi.next(&bar1);
+        assert_eq!(first.plus_di, lit!(0.0));
+        assert_eq!(first.minus_di, lit!(0.0));
+        assert_eq!(dmi.next(&bar2), out);
+    }
+
+    #[test]
+    fn test_default() {
+        Dmi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Dmi::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "DMI(14)");
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Disparity Index (DISP).
+///
+/// The Disparity Index measures how far the price is from its moving average, as a
+/// percentage of the moving average. Positive values mean the price is above the average,
+/// negative values that it is below.
+///
+/// # Formula
+///
+/// DISP = 100 * (price - EMA(price, _period_)) / EMA(price, _period_)
+///
+/// Where:
+///
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html)
+///
+/// The EMA is seeded with the first price, so the first value is 0. While the EMA is 0 the
+/// index is 0 as well.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Disparity Index, Investopedia](https://www.investopedia.com/terms/d/disparityindex.asp)
+///
+#[doc(alias = "DISP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DisparityIndex {
+    ema: Ema,
+}
+
+impl DisparityIndex {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(period)?,
+        })
+    }
+}
+
+impl Period for DisparityIndex {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Next<crate::Num> for DisparityIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let ema = self.ema.next(input);
+        if ema == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            lit!(100.0) * (input - ema) / ema
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for DisparityIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DisparityIndex {
+    fn reset(&mut self) {
+        self.ema.reset();
+    }
+}
+
+impl Default for DisparityIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for DisparityIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DISP({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DisparityIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(DisparityIndex::new(0).is_err());
+        assert!(DisparityIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut disp = DisparityIndex::new(3).unwrap();
+
+        assert_eq!(disp.next(lit!(10.0)), lit!(0.0));
+        // ema = 10 + 0.5 * (14 - 10) = 12
+        assert_eq!(round(disp.next(lit!(14.0))), lit!(16.667));
+        // ema = 12 + 0.5 * (9 - 12) = 10.5
+        assert_eq!(round(disp.next(&Bar::new().close(9))), lit!(-14.286));
+    }
+
+    #[test]
+    fn test_next_price_equals_average() {
+        let mut disp = DisparityIndex::new(5).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(disp.next(lit!(25.0)), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_next_zero_average() {
+        let mut disp = DisparityIndex::new(3).unwrap();
+
+        assert_eq!(disp.next(lit!(0.0)), lit!(0.0));
+        // ema = 0.5 * 4 = 2
+        assert_eq!(disp.next(lit!(4.0)), lit!(100.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut disp = DisparityIndex::new(3).unwrap();
+
+        disp.next(lit!(10.0));
+        disp.next(lit!(14.0));
+
+        disp.reset();
+        assert_eq!(disp.next(lit!(20.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        DisparityIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = DisparityIndex::default();
+        assert_eq!(format!("{}", indicator), "DISP(14)");
+    }
+}
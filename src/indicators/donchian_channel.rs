@@ -0,0 +1,208 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{lit, Bands, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Donchian Channel (DC).
+///
+/// Developed by Richard Donchian, the channel is formed by the highest high and the lowest
+/// low of the last _period_ bars. A breakout above the upper or below the lower band is the
+/// classic entry signal of the turtle trading system.
+///
+/// # Formula
+///
+/// DC is composed as:
+///
+///  * _DC<sub>Upper Band</sub>_ - highest high over _period_
+///  * _DC<sub>Lower Band</sub>_ - lowest low over _period_
+///  * _DC<sub>Middle Band</sub>_ = (upper + lower) / 2
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Links
+///
+/// * [Donchian channel, Wikipedia](https://en.wikipedia.org/wiki/Donchian_channel)
+///
+#[doc(alias = "DC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DonchianChannel {
+    max: Maximum,
+    min: Minimum,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonchianChannelOutput {
+    pub upper: crate::Num,
+    pub lower: crate::Num,
+    pub middle: crate::Num,
+}
+
+impl Bands for DonchianChannelOutput {
+    fn upper(&self) -> crate::Num {
+        self.upper
+    }
+
+    fn average(&self) -> crate::Num {
+        self.middle
+    }
+
+    fn lower(&self) -> crate::Num {
+        self.lower
+    }
+}
+
+impl DonchianChannel {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            max: Maximum::new(period)?,
+            min: Minimum::new(period)?,
+        })
+    }
+
+    fn output(upper: crate::Num, lower: crate::Num) -> DonchianChannelOutput {
+        DonchianChannelOutput {
+            upper,
+            lower,
+            middle: (upper + lower) / lit!(2.0),
+        }
+    }
+}
+
+impl Period for DonchianChannel {
+    fn period(&self) -> usize {
+        self.max.period()
+    }
+}
+
+impl Next<crate::Num> for DonchianChannel {
+    type Output = DonchianChannelOutput;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        Self::output(self.max.next(input), self.min.next(input))
+    }
+}
+
+impl<T: High + Low> Next<&T> for DonchianChannel {
+    type Output = DonchianChannelOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        Self::output(self.max.next(input), self.min.next(input))
+    }
+}
+
+impl Reset for DonchianChannel {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+    }
+}
+
+impl Default for DonchianChannel {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for DonchianChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DC({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DonchianChannel);
+
+    #[test]
+    fn test_new() {
+        assert!(DonchianChannel::new(0).is_err());
+        assert!(DonchianChannel::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        let out = dc.next(lit!(2.0));
+        assert_eq!(
+            (out.upper, out.lower, out.middle),
+            (lit!(2.0), lit!(2.0), lit!(2.0))
+        );
+
+        let out = dc.next(lit!(5.0));
+        assert_eq!(
+            (out.upper, out.lower, out.middle),
+            (lit!(5.0), lit!(2.0), lit!(3.5))
+        );
+
+        dc.next(lit!(4.0));
+
+        // 2.0 leaves the window
+        let out = dc.next(lit!(4.5));
+        assert_eq!(
+            (out.upper, out.lower, out.middle),
+            (lit!(5.0), lit!(4.0), lit!(4.5))
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        let out = dc.next(&Bar::new().high(10).low(8));
+        assert_eq!(out.upper - out.lower, lit!(2.0));
+
+        let out = dc.next(&Bar::new().high(10).low(9));
+        assert_eq!(out.upper - out.lower, lit!(2.0));
+
+        // a new high widens the channel
+        let out = dc.next(&Bar::new().high(13).low(9));
+        assert_eq!(
+            (out.upper, out.lower, out.middle),
+            (lit!(13.0), lit!(8.0), lit!(10.5))
+        );
+
+        // a new low widens it further
+        let out = dc.next(&Bar::new().high(12).low(6));
+        assert_eq!(
+            (out.upper, out.lower, out.middle),
+            (lit!(13.0), lit!(6.0), lit!(9.5))
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        dc.next(lit!(2.0));
+        dc.next(lit!(5.0));
+
+        dc.reset();
+        let out = dc.next(lit!(3.0));
+        assert_eq!((out.upper, out.lower), (lit!(3.0), lit!(3.0)));
+    }
+
+    #[test]
+    fn test_default() {
+        DonchianChannel::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = DonchianChannel::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "DC(20)");
+    }
+}
@@ -0,0 +1,176 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{lit, High, Low, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement (EOM).
+///
+/// Developed by Richard Arms, EOM relates the price movement of a bar to its volume.
+/// Large positive values show prices moving up on light volume, large negative values show
+/// prices moving down on light volume.
+///
+/// # Formula
+///
+/// distance = (high + low) / 2 - (high<sub>t-1</sub> + low<sub>t-1</sub>) / 2
+///
+/// box ratio = volume / (high - low)
+///
+/// EOM = SMA(distance / box ratio, _period_)
+///
+/// Where:
+///
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// The first bar has no previous midpoint and moves a distance of 0. A bar with zero volume
+/// or zero range contributes 0. No volume scaling is applied, so the magnitude of the output
+/// depends on the magnitude of the volume.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Ease of movement, Wikipedia](https://en.wikipedia.org/wiki/Ease_of_movement)
+///
+#[doc(alias = "EOM")]
+#[doc(alias = "EMV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EaseOfMovement {
+    sma: Sma,
+    prev_mid: Option<crate::Num>,
+}
+
+impl EaseOfMovement {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+            prev_mid: None,
+        })
+    }
+}
+
+impl Period for EaseOfMovement {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: High + Low + Volume> Next<&T> for EaseOfMovement {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let range = input.high() - input.low();
+        let mid = (input.high() + input.low()) / lit!(2.0);
+        let volume = input.volume();
+
+        let distance = match self.prev_mid.replace(mid) {
+            Some(prev_mid) => mid - prev_mid,
+            None => lit!(0.0),
+        };
+
+        // distance / (volume / range), without dividing by a zero range
+        let emv = if volume == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            distance * range / volume
+        };
+
+        self.sma.next(emv)
+    }
+}
+
+impl Reset for EaseOfMovement {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.prev_mid = None;
+    }
+}
+
+impl Default for EaseOfMovement {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for EaseOfMovement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EOM({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(EaseOfMovement::new(0).is_err());
+        assert!(EaseOfMovement::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut eom = EaseOfMovement::new(2).unwrap();
+
+        // mid = 10
+        let bar1 = Bar::new().high(12).low(8).volume(1000);
+        // mid = 12, distance 2, box ratio 1000 / 4
+        let bar2 = Bar::new().high(14).low(10).volume(1000);
+        // mid = 11, distance -1, box ratio 500 / 2
+        let bar3 = Bar::new().high(12).low(10).volume(500);
+
+        assert_eq!(eom.next(&bar1), lit!(0.0));
+        // (0 + 0.008) / 2
+        assert_eq!(eom.next(&bar2), lit!(0.004));
+        // (0.008 - 0.004) / 2
+        assert_eq!(eom.next(&bar3), lit!(0.002));
+    }
+
+    #[test]
+    fn test_next_zero_volume_or_range() {
+        let mut eom = EaseOfMovement::new(1).unwrap();
+
+        eom.next(&Bar::new().high(12).low(8).volume(1000));
+        // zero volume
+        assert_eq!(eom.next(&Bar::new().high(14).low(10).volume(0)), lit!(0.0));
+        // zero range
+        assert_eq!(
+            eom.next(&Bar::new().high(15).low(15).volume(1000)),
+            lit!(0.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut eom = EaseOfMovement::new(1).unwrap();
+
+        eom.next(&Bar::new().high(12).low(8).volume(1000));
+        eom.next(&Bar::new().high(14).low(10).volume(1000));
+
+        eom.reset();
+        assert_eq!(
+            eom.next(&Bar::new().high(20).low(10).volume(1000)),
+            lit!(0.0)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        EaseOfMovement::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = EaseOfMovement::default();
+        assert_eq!(format!("{}", indicator), "EOM(14)");
+    }
+}
@@ -1,6 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
+use crate::helpers::RingWindow;
+use crate::indicators::Strict;
 use crate::{lit, Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -19,9 +21,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct EfficiencyRatio {
     period: usize,
-    index: usize,
-    count: usize,
-    deque: Box<[rust_decimal::Decimal]>,
+    window: RingWindow,
 }
 
 impl EfficiencyRatio {
@@ -30,15 +30,23 @@ impl EfficiencyRatio {
     /// Will return `Err` if any of the periods is 0
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
-                index: 0,
-                count: 0,
-                deque: vec![lit!(0.0); period].into_boxed_slice(),
+                window: RingWindow::new(period + 1),
             }),
         }
     }
+
+    /// Creates a [strict](struct.Strict.html) version of the indicator, which returns
+    /// `Err(TaError::InsufficientData { .. })` until `period` price changes, i.e. `period + 1` inputs, were seen.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_strict(period: usize) -> Result<Strict<Self>> {
+        Ok(Strict::new(Self::new(period)?, period + 1))
+    }
 }
 
 impl Period for EfficiencyRatio {
@@ -47,33 +55,18 @@ impl Period for EfficiencyRatio {
     }
 }
 
-impl Next<rust_decimal::Decimal> for EfficiencyRatio {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for EfficiencyRatio {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> rust_decimal::Decimal {
-        let first = if self.count >= self.period {
-            self.deque[self.index]
-        } else {
-            self.count += 1;
-            self.deque[0]
-        };
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
+    fn next(&mut self, input: crate::Num) -> crate::Num {
+        self.window.push(input);
+        let first = self.window.lookback(self.period).unwrap();
 
         let mut volatility = lit!(0.0);
         let mut previous = first;
-        for n in &self.deque[self.index..self.count] {
-            volatility += (previous - n).abs();
-            previous = *n;
-        }
-        for n in &self.deque[0..self.index] {
+        for n in self.window.iter() {
             volatility += (previous - n).abs();
-            previous = *n;
+            previous = n;
         }
 
         if volatility == lit!(0.0) {
@@ -85,20 +78,16 @@ impl Next<rust_decimal::Decimal> for EfficiencyRatio {
 }
 
 impl<T: Close> Next<&T> for EfficiencyRatio {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
-    fn next(&mut self, input: &T) -> rust_decimal::Decimal {
+    fn next(&mut self, input: &T) -> crate::Num {
         self.next(input.close())
     }
 }
 
 impl Reset for EfficiencyRatio {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
-        for i in 0..self.period {
-            self.deque[i] = lit!(0.0);
-        }
+        self.window.clear();
     }
 }
 
@@ -141,6 +130,39 @@ mod tests {
         assert_eq!(round(er.next(lit!(6.0))), lit!(1.0));
     }
 
+    #[test]
+    fn test_strict() {
+        assert!(EfficiencyRatio::new_strict(0).is_err());
+
+        // 2 price changes need 3 inputs
+        let mut er = EfficiencyRatio::new_strict(2).unwrap();
+        assert_eq!(
+            er.next(lit!(3.0)),
+            Err(TaError::InsufficientData { have: 1, need: 3 })
+        );
+        assert_eq!(
+            er.next(lit!(5.0)),
+            Err(TaError::InsufficientData { have: 2, need: 3 })
+        );
+        assert_eq!(er.next(lit!(7.0)).map(round), Ok(lit!(1.0)));
+
+        er.reset();
+        assert!(er.next(lit!(3.0)).is_err());
+    }
+
+    #[test]
+    fn test_strict_error_count() {
+        for period in 1..10 {
+            let mut indicator = EfficiencyRatio::new_strict(period).unwrap();
+            let errors = (0..3 * period)
+                .map(|i| indicator.next(crate::int!(10 + i % 4)))
+                .take_while(Result::is_err)
+                .count();
+            // `period` price changes are known from input `period + 1` on
+            assert_eq!(errors, period);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut er = EfficiencyRatio::new(3).unwrap();
@@ -0,0 +1,170 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Elder Ray Index (ELDER).
+///
+/// Developed by Alexander Elder, the Elder Ray Index measures the buying and selling
+/// pressure against an [EMA](struct.ExponentialMovingAverage.html) of the close, which
+/// stands for the consensus of value. Bull power shows how far the buyers could push the
+/// price above the consensus, bear power how far the sellers could push it below.
+///
+/// # Formula
+///
+/// bull power = high - EMA(close, _period_)
+///
+/// bear power = low - EMA(close, _period_)
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 13.
+///
+/// # Links
+///
+/// * [Elder-Ray Index, Investopedia](https://www.investopedia.com/terms/e/elderray.asp)
+///
+#[doc(alias = "ELDER")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ElderRay {
+    ema: Ema,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElderRayOutput {
+    pub bull_power: crate::Num,
+    pub bear_power: crate::Num,
+}
+
+impl From<ElderRayOutput> for (crate::Num, crate::Num) {
+    fn from(eo: ElderRayOutput) -> Self {
+        (eo.bull_power, eo.bear_power)
+    }
+}
+
+impl ElderRay {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(period)?,
+        })
+    }
+}
+
+impl Period for ElderRay {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for ElderRay {
+    type Output = ElderRayOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ema = self.ema.next(input.close());
+
+        ElderRayOutput {
+            bull_power: input.high() - ema,
+            bear_power: input.low() - ema,
+        }
+    }
+}
+
+impl Reset for ElderRay {
+    fn reset(&mut self) {
+        self.ema.reset();
+    }
+}
+
+impl Default for ElderRay {
+    fn default() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl fmt::Display for ElderRay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ELDER({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    fn bar(high: i32, low: i32, close: i32) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    fn powers(out: ElderRayOutput) -> (crate::Num, crate::Num) {
+        out.into()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ElderRay::new(0).is_err());
+        assert!(ElderRay::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut elder = ElderRay::new(3).unwrap();
+
+        assert_eq!(powers(elder.next(&bar(12, 8, 10))), (lit!(2.0), lit!(-2.0)));
+        // ema = 10 + 0.5 * (14 - 10) = 12
+        assert_eq!(
+            powers(elder.next(&bar(15, 11, 14))),
+            (lit!(3.0), lit!(-1.0))
+        );
+        // ema = 12 + 0.5 * (11 - 12) = 11.5
+        assert_eq!(
+            powers(elder.next(&bar(13, 10, 11))),
+            (lit!(1.5), lit!(-1.5))
+        );
+    }
+
+    #[test]
+    fn test_next_uptrend() {
+        let mut elder = ElderRay::default();
+
+        for close in 100..150 {
+            let out = elder.next(&bar(close + 10, close - 10, close));
+            assert!(out.bull_power > lit!(0.0));
+            assert!(out.bear_power < lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut elder = ElderRay::new(3).unwrap();
+
+        elder.next(&bar(12, 8, 10));
+        elder.next(&bar(15, 11, 14));
+
+        elder.reset();
+        assert_eq!(
+            powers(elder.next(&bar(22, 18, 20))),
+            (lit!(2.0), lit!(-2.0))
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        ElderRay::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ElderRay::default();
+        assert_eq!(format!("{}", indicator), "ELDER(13)");
+    }
+}
@@ -1,6 +1,6 @@
-use std::fmt;
 use crate::errors::{Result, TaError};
-use crate::{int, lit, Close, Next, Period, Reset};
+use crate::{int, lit, Close, Current, Next, Period, Reset, ResetTo};
+use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,8 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
+/// * _seed_ - how the first value of the EMA is chosen, see [`EmaSeed`]. Default is
+///   [`EmaSeed::FirstValue`].
 ///
 /// # Links
 ///
@@ -42,9 +44,24 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct ExponentialMovingAverage {
     period: usize,
-    k: rust_decimal::Decimal,
-    current: rust_decimal::Decimal,
+    k: crate::Num,
+    current: crate::Num,
     is_new: bool,
+    seed: EmaSeed,
+    count: usize,
+    sum: crate::Num,
+}
+
+/// Seeding mode of an [`ExponentialMovingAverage`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmaSeed {
+    /// The first input becomes the first EMA.
+    #[default]
+    FirstValue,
+    /// The simple average of the first _period_ inputs becomes the first EMA, like on most
+    /// charting platforms. During this warmup the running simple average is returned.
+    Sma,
 }
 
 impl ExponentialMovingAverage {
@@ -52,16 +69,31 @@ impl ExponentialMovingAverage {
     ///
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_seed(period, EmaSeed::FirstValue)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_seed(period: usize, seed: EmaSeed) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
                 k: lit!(2.0) / int!(period + 1),
-                current: rust_decimal::Decimal::default(),
+                current: crate::Num::default(),
                 is_new: true,
+                seed,
+                count: 0,
+                sum: lit!(0.0),
             }),
         }
     }
+
+    #[must_use]
+    pub fn seed(&self) -> EmaSeed {
+        self.seed
+    }
 }
 
 impl Period for ExponentialMovingAverage {
@@ -70,32 +102,58 @@ impl Period for ExponentialMovingAverage {
     }
 }
 
-impl Next<rust_decimal::Decimal> for ExponentialMovingAverage {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for ExponentialMovingAverage {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
-        if self.is_new {
-            self.is_new = false;
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        if self.seed == EmaSeed::Sma && self.count < self.period {
+            self.count += 1;
+            self.sum += input;
+            self.current = self.sum / int!(self.count);
+        } else if self.is_new {
             self.current = input;
         } else {
             self.current = self.k * input + (lit!(1.0) - self.k) * self.current;
         }
+        self.is_new = false;
         self.current
     }
 }
 
 impl<T: Close> Next<&T> for ExponentialMovingAverage {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
     }
 }
 
+impl Current for ExponentialMovingAverage {
+    type Output = crate::Num;
+
+    fn current(&self) -> Option<crate::Num> {
+        if self.is_new {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
+}
+
 impl Reset for ExponentialMovingAverage {
     fn reset(&mut self) {
-        self.current = rust_decimal::Decimal::default();
+        self.current = crate::Num::default();
         self.is_new = true;
+        self.count = 0;
+        self.sum = lit!(0.0);
+    }
+}
+
+impl ResetTo for ExponentialMovingAverage {
+    fn reset_to(&mut self, seed: crate::Num) {
+        self.current = seed;
+        self.is_new = false;
+        self.count = self.period;
     }
 }
 
@@ -107,7 +165,10 @@ impl Default for ExponentialMovingAverage {
 
 impl fmt::Display for ExponentialMovingAverage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "EMA({})", self.period)
+        match self.seed {
+            EmaSeed::FirstValue => write!(f, "EMA({})", self.period),
+            EmaSeed::Sma => write!(f, "EMA({}, SMA)", self.period),
+        }
     }
 }
 
@@ -140,6 +201,40 @@ mod tests {
         assert_eq!(ema.next(&bar2), lit!(3.5));
     }
 
+    #[test]
+    fn test_next_with_sma_seed() {
+        let mut ema = ExponentialMovingAverage::new_with_seed(3, EmaSeed::Sma).unwrap();
+        assert_eq!(ema.seed(), EmaSeed::Sma);
+
+        // running SMA during the warmup
+        assert_eq!(ema.next(lit!(2.0)), lit!(2.0));
+        assert_eq!(ema.next(lit!(5.0)), lit!(3.5));
+        assert_eq!(round(ema.next(lit!(1.0))), lit!(2.667));
+        // 0.5 * 6.25 + 0.5 * 2.667
+        assert_eq!(round(ema.next(lit!(6.25))), lit!(4.458));
+        assert_eq!(round(ema.next(&Bar::new().close(3))), lit!(3.729));
+    }
+
+    #[test]
+    fn test_seed_modes_converge() {
+        let mut first = ExponentialMovingAverage::new(3).unwrap();
+        let mut sma = ExponentialMovingAverage::new_with_seed(3, EmaSeed::Sma).unwrap();
+
+        // the modes agree on the first value and diverge during the warmup
+        assert_eq!(first.next(lit!(2.0)), sma.next(lit!(2.0)));
+        first.next(lit!(5.0));
+        sma.next(lit!(5.0));
+        assert_ne!(first.next(lit!(1.0)), sma.next(lit!(1.0)));
+        assert_ne!(first.next(lit!(6.25)), sma.next(lit!(6.25)));
+
+        // the difference of the seeds decays with every input
+        for _ in 0..40 {
+            first.next(lit!(10.0));
+            sma.next(lit!(10.0));
+        }
+        assert_eq!(round(first.next(lit!(10.0))), round(sma.next(lit!(10.0))));
+    }
+
     #[test]
     fn test_reset() {
         let mut ema = ExponentialMovingAverage::new(5).unwrap();
@@ -152,6 +247,58 @@ mod tests {
 
         ema.reset();
         assert_eq!(ema.next(lit!(4.0)), lit!(4.0));
+
+        let mut ema = ExponentialMovingAverage::new_with_seed(3, EmaSeed::Sma).unwrap();
+        ema.next(lit!(4.0));
+        ema.next(lit!(10.0));
+        ema.next(lit!(16.0));
+
+        ema.reset();
+        assert_eq!(ema.next(lit!(4.0)), lit!(4.0));
+        assert_eq!(ema.next(lit!(10.0)), lit!(7.0));
+    }
+
+    #[test]
+    fn test_reset_to() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+
+        ema.next(lit!(4.0));
+        ema.next(lit!(10.0));
+
+        ema.reset_to(lit!(100.0));
+        // 0.5 * 50 + 0.5 * 100
+        assert_eq!(ema.next(lit!(50.0)), lit!(75.0));
+        assert_eq!(ema.next(lit!(75.0)), lit!(75.0));
+    }
+
+    #[test]
+    fn test_reset_to_skips_cold_start() {
+        // A cold start would return the first input unchanged
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        ema.reset_to(lit!(10.0));
+        assert_eq!(ema.next(lit!(4.0)), lit!(7.0));
+
+        // A cold start would wait for `period` inputs before averaging
+        let mut ema = ExponentialMovingAverage::new_with_seed(3, EmaSeed::Sma).unwrap();
+        ema.reset_to(lit!(10.0));
+        assert_eq!(ema.next(lit!(4.0)), lit!(7.0));
+        assert_eq!(ema.next(lit!(9.0)), lit!(8.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.current(), None);
+
+        for input in [lit!(2.0), lit!(5.0), lit!(1.0)] {
+            let value = ema.next(input);
+            assert_eq!(ema.current(), Some(value));
+        }
+
+        ema.reset();
+        assert_eq!(ema.current(), None);
+        ema.reset_to(lit!(10.0));
+        assert_eq!(ema.current(), Some(lit!(10.0)));
     }
 
     #[test]
@@ -163,5 +310,7 @@ mod tests {
     fn test_display() {
         let ema = ExponentialMovingAverage::new(7).unwrap();
         assert_eq!(format!("{}", ema), "EMA(7)");
+        let ema = ExponentialMovingAverage::new_with_seed(7, EmaSeed::Sma).unwrap();
+        assert_eq!(format!("{}", ema), "EMA(7, SMA)");
     }
 }
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{Maximum, Minimum};
@@ -54,10 +54,10 @@ impl Period for FastStochastic {
     }
 }
 
-impl Next<rust_decimal::Decimal> for FastStochastic {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for FastStochastic {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let min = self.minimum.next(input);
         let max = self.maximum.next(input);
 
@@ -72,7 +72,7 @@ impl Next<rust_decimal::Decimal> for FastStochastic {
 }
 
 impl<T: High + Low + Close> Next<&T> for FastStochastic {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         let highest = self.maximum.next(input.high());
@@ -0,0 +1,163 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{lit, Close, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Force Index (FI).
+///
+/// Developed by Alexander Elder, the Force Index combines the direction and the extent of
+/// a price change with the volume behind it. Positive values show buying pressure,
+/// negative values show selling pressure.
+///
+/// # Formula
+///
+/// FI = EMA((close - close<sub>t-1</sub>) * volume, _period_)
+///
+/// Where:
+///
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html)
+///
+/// The first bar has no previous close and its raw force is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 13. A period of 1
+///   gives the raw, unsmoothed Force Index.
+///
+/// # Links
+///
+/// * [Force index, Wikipedia](https://en.wikipedia.org/wiki/Force_index)
+///
+#[doc(alias = "FI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ForceIndex {
+    ema: Ema,
+    prev_close: Option<crate::Num>,
+}
+
+impl ForceIndex {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(period)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl Period for ForceIndex {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for ForceIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let force = match self.prev_close.replace(close) {
+            Some(prev_close) => (close - prev_close) * input.volume(),
+            None => lit!(0.0),
+        };
+
+        self.ema.next(force)
+    }
+}
+
+impl Reset for ForceIndex {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for ForceIndex {
+    fn default() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl fmt::Display for ForceIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FI({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ForceIndex::new(0).is_err());
+        assert!(ForceIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_raw() {
+        let mut fi = ForceIndex::new(1).unwrap();
+
+        assert_eq!(fi.next(&Bar::new().close(10).volume(1000)), lit!(0.0));
+        assert_eq!(fi.next(&Bar::new().close(12).volume(1000)), lit!(2000.0));
+        assert_eq!(fi.next(&Bar::new().close(11).volume(500)), lit!(-500.0));
+        assert_eq!(fi.next(&Bar::new().close(11).volume(500)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_next_smoothed() {
+        let mut fi = ForceIndex::new(3).unwrap();
+
+        assert_eq!(fi.next(&Bar::new().close(10).volume(1000)), lit!(0.0));
+        // raw 2000, k = 0.5
+        assert_eq!(fi.next(&Bar::new().close(12).volume(1000)), lit!(1000.0));
+        // raw -500
+        assert_eq!(fi.next(&Bar::new().close(11).volume(500)), lit!(250.0));
+    }
+
+    #[test]
+    fn test_sign_follows_price() {
+        let mut fi = ForceIndex::new(2).unwrap();
+
+        fi.next(&Bar::new().close(10).volume(100));
+        for close in 11..20 {
+            assert!(fi.next(&Bar::new().close(close).volume(100)) > lit!(0.0));
+        }
+
+        let mut fi = ForceIndex::new(2).unwrap();
+
+        fi.next(&Bar::new().close(20).volume(100));
+        for close in (11..20).rev() {
+            assert!(fi.next(&Bar::new().close(close).volume(100)) < lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fi = ForceIndex::new(1).unwrap();
+
+        fi.next(&Bar::new().close(10).volume(1000));
+        fi.next(&Bar::new().close(12).volume(1000));
+
+        fi.reset();
+        assert_eq!(fi.next(&Bar::new().close(20).volume(1000)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        ForceIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ForceIndex::default();
+        assert_eq!(format!("{}", indicator), "FI(13)");
+    }
+}
@@ -0,0 +1,188 @@
+use core::fmt;
+
+use crate::helpers::RingWindow;
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fractal.
+///
+/// Developed by Bill Williams, fractals mark the local extremes of the price. An up
+/// fractal is a bar whose high is above the highs of the two bars before and the two bars
+/// after it, a down fractal is a bar whose low is below the lows of the two bars before and
+/// the two bars after it.
+///
+/// The output describes the middle bar of the last 5 bars, so it is delayed by two bars: a
+/// fractal is only reported once the two bars following it are known. Until 5 bars are
+/// known no fractal is reported.
+///
+/// # Links
+///
+/// * [Fractal, Investopedia](https://www.investopedia.com/terms/f/fractal.asp)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Fractal {
+    highs: RingWindow,
+    lows: RingWindow,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FractalOutput {
+    /// The bar two bars ago is an up fractal.
+    pub up_fractal: bool,
+    /// The bar two bars ago is a down fractal.
+    pub down_fractal: bool,
+}
+
+impl From<FractalOutput> for (bool, bool) {
+    fn from(fo: FractalOutput) -> Self {
+        (fo.up_fractal, fo.down_fractal)
+    }
+}
+
+impl Fractal {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            highs: RingWindow::new(5),
+            lows: RingWindow::new(5),
+        }
+    }
+}
+
+impl<T: High + Low> Next<&T> for Fractal {
+    type Output = FractalOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.highs.push(input.high());
+        self.lows.push(input.low());
+
+        if !self.highs.is_full() {
+            return FractalOutput {
+                up_fractal: false,
+                down_fractal: false,
+            };
+        }
+
+        let middle_high = self.highs.lookback(2).unwrap();
+        let middle_low = self.lows.lookback(2).unwrap();
+        let others = [0, 1, 3, 4];
+
+        FractalOutput {
+            up_fractal: others
+                .iter()
+                .all(|&n| self.highs.lookback(n).unwrap() < middle_high),
+            down_fractal: others
+                .iter()
+                .all(|&n| self.lows.lookback(n).unwrap() > middle_low),
+        }
+    }
+}
+
+impl Reset for Fractal {
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+impl Default for Fractal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Fractal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRACTAL")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: i32, low: i32) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    fn fractals(out: FractalOutput) -> (bool, bool) {
+        out.into()
+    }
+
+    #[test]
+    fn test_next_peak() {
+        let mut fractal = Fractal::new();
+
+        assert_eq!(fractals(fractal.next(&bar(10, 8))), (false, false));
+        assert_eq!(fractals(fractal.next(&bar(11, 9))), (false, false));
+        assert_eq!(fractals(fractal.next(&bar(15, 12))), (false, false));
+        assert_eq!(fractals(fractal.next(&bar(12, 10))), (false, false));
+        // the peak is two bars back
+        assert_eq!(fractals(fractal.next(&bar(11, 9))), (true, false));
+        assert_eq!(fractals(fractal.next(&bar(12, 10))), (false, false));
+    }
+
+    #[test]
+    fn test_next_trough() {
+        let mut fractal = Fractal::new();
+
+        fractal.next(&bar(15, 12));
+        fractal.next(&bar(13, 10));
+        fractal.next(&bar(9, 5));
+        fractal.next(&bar(12, 9));
+        assert_eq!(fractals(fractal.next(&bar(14, 11))), (false, true));
+        assert_eq!(fractals(fractal.next(&bar(15, 12))), (false, false));
+    }
+
+    #[test]
+    fn test_next_both() {
+        let mut fractal = Fractal::new();
+
+        // an outside bar can be both fractals at once
+        fractal.next(&bar(10, 8));
+        fractal.next(&bar(10, 8));
+        fractal.next(&bar(12, 6));
+        fractal.next(&bar(10, 8));
+        assert_eq!(fractals(fractal.next(&bar(10, 8))), (true, true));
+    }
+
+    #[test]
+    fn test_next_equal_highs() {
+        let mut fractal = Fractal::new();
+
+        // the middle high has to be strictly above its neighbours
+        fractal.next(&bar(10, 8));
+        fractal.next(&bar(15, 9));
+        fractal.next(&bar(15, 9));
+        fractal.next(&bar(10, 9));
+        assert_eq!(fractals(fractal.next(&bar(10, 9))), (false, false));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fractal = Fractal::new();
+
+        fractal.next(&bar(10, 8));
+        fractal.next(&bar(11, 9));
+        fractal.next(&bar(15, 12));
+        fractal.next(&bar(12, 10));
+
+        fractal.reset();
+        assert_eq!(fractals(fractal.next(&bar(11, 9))), (false, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Fractal::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Fractal::new();
+        assert_eq!(format!("{}", indicator), "FRACTAL");
+    }
+}
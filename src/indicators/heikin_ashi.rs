@@ -0,0 +1,215 @@
+use core::fmt;
+
+use crate::helpers::max3;
+use crate::{lit, Close, High, Low, Next, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Heikin-Ashi (HA).
+///
+/// Transforms candles into smoothed, synthetic Heikin-Ashi candles. Every HA candle averages
+/// the prices of its bar and carries over the body of the previous HA candle, so trends show
+/// as runs of candles of the same direction. The output implements [`Open`], [`High`],
+/// [`Low`] and [`Close`], so it can be fed into other indicators.
+///
+/// # Formula
+///
+/// * _HA<sub>close</sub>_ = (open + high + low + close) / 4
+/// * _HA<sub>open</sub>_ = (HA<sub>open, t-1</sub> + HA<sub>close, t-1</sub>) / 2
+/// * _HA<sub>high</sub>_ = max(high, HA<sub>open</sub>, HA<sub>close</sub>)
+/// * _HA<sub>low</sub>_ = min(low, HA<sub>open</sub>, HA<sub>close</sub>)
+///
+/// The first HA candle is seeded from its raw bar, with _HA<sub>open</sub>_ = (open + close) / 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{HeikinAshi, SimpleMovingAverage};
+/// use ta::{lit, Candle, Next};
+///
+/// let mut ha = HeikinAshi::new();
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+///
+/// let candle = Candle::builder()
+///     .open(lit!(10.0))
+///     .high(lit!(14.0))
+///     .low(lit!(8.0))
+///     .close(lit!(12.0))
+///     .volume(lit!(100.0))
+///     .build()
+///     .unwrap();
+///
+/// let ha_candle = ha.next(&candle);
+/// assert_eq!(ha_candle.open, lit!(11.0));
+/// assert_eq!(ha_candle.close, lit!(11.0));
+/// assert_eq!(sma.next(&ha_candle), lit!(11.0));
+/// ```
+///
+/// # Links
+///
+/// * [Heikin-Ashi, Investopedia](https://www.investopedia.com/trading/heikin-ashi-better-candlestick/)
+///
+#[doc(alias = "HA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HeikinAshi {
+    // open and close of the previous HA candle
+    previous: Option<(crate::Num, crate::Num)>,
+}
+
+/// Synthetic candle returned by [`HeikinAshi`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeikinAshiOutput {
+    pub open: crate::Num,
+    pub high: crate::Num,
+    pub low: crate::Num,
+    pub close: crate::Num,
+}
+
+impl Open for HeikinAshiOutput {
+    fn open(&self) -> crate::Num {
+        self.open
+    }
+}
+
+impl High for HeikinAshiOutput {
+    fn high(&self) -> crate::Num {
+        self.high
+    }
+}
+
+impl Low for HeikinAshiOutput {
+    fn low(&self) -> crate::Num {
+        self.low
+    }
+}
+
+impl Close for HeikinAshiOutput {
+    fn close(&self) -> crate::Num {
+        self.close
+    }
+}
+
+impl HeikinAshi {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { previous: None }
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for HeikinAshi {
+    type Output = HeikinAshiOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = (input.open() + input.high() + input.low() + input.close()) / lit!(4.0);
+        let open = match self.previous {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / lit!(2.0),
+            None => (input.open() + input.close()) / lit!(2.0),
+        };
+        self.previous = Some((open, close));
+
+        HeikinAshiOutput {
+            open,
+            high: max3(input.high(), open, close),
+            low: input.low().min(open).min(close),
+            close,
+        }
+    }
+}
+
+impl Reset for HeikinAshi {
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl Default for HeikinAshi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for HeikinAshi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HA")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    fn ohlc(out: &HeikinAshiOutput) -> (crate::Num, crate::Num, crate::Num, crate::Num) {
+        (out.open, out.high, out.low, out.close)
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ha = HeikinAshi::new();
+
+        // seeded from the raw bar
+        let out = ha.next(&Bar::new().open(10).high(14).low(8).close(12));
+        assert_eq!(ohlc(&out), (lit!(11.0), lit!(14.0), lit!(8.0), lit!(11.0)));
+
+        // open = (11 + 11) / 2, close = (12 + 16 + 11 + 15) / 4
+        let out = ha.next(&Bar::new().open(12).high(16).low(11).close(15));
+        assert_eq!(ohlc(&out), (lit!(11.0), lit!(16.0), lit!(11.0), lit!(13.5)));
+
+        // open = (11 + 13.5) / 2, close = (15 + 15 + 10 + 12) / 4
+        let out = ha.next(&Bar::new().open(15).high(15).low(10).close(12));
+        assert_eq!(
+            ohlc(&out),
+            (lit!(12.25), lit!(15.0), lit!(10.0), lit!(13.0))
+        );
+    }
+
+    #[test]
+    fn test_extremes() {
+        let mut ha = HeikinAshi::new();
+
+        ha.next(&Bar::new().open(20).high(21).low(19).close(20));
+        // a gap down: the HA open is above the high of the raw bar
+        let out = ha.next(&Bar::new().open(10).high(11).low(9).close(10));
+        assert_eq!(ohlc(&out), (lit!(20.0), lit!(20.0), lit!(9.0), lit!(10.0)));
+
+        // a gap up: the HA open is below the low of the raw bar
+        let out = ha.next(&Bar::new().open(30).high(31).low(29).close(30));
+        assert_eq!(ohlc(&out), (lit!(15.0), lit!(31.0), lit!(15.0), lit!(30.0)));
+    }
+
+    #[test]
+    fn test_into_ema() {
+        let mut ha = HeikinAshi::new();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+
+        let out = ha.next(&Bar::new().open(10).high(14).low(8).close(12));
+        assert_eq!(ema.next(&out), lit!(11.0));
+        let out = ha.next(&Bar::new().open(12).high(16).low(11).close(15));
+        assert_eq!(ema.next(&out), lit!(12.25));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ha = HeikinAshi::new();
+
+        ha.next(&Bar::new().open(20).high(21).low(19).close(20));
+        ha.reset();
+
+        let out = ha.next(&Bar::new().open(10).high(14).low(8).close(12));
+        assert_eq!(out.open, lit!(11.0));
+    }
+
+    #[test]
+    fn test_default() {
+        HeikinAshi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = HeikinAshi::new();
+        assert_eq!(format!("{}", indicator), "HA");
+    }
+}
@@ -0,0 +1,254 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Highest high, lowest low and their difference over a given time frame.
+///
+/// Returns the same extremes as running [`Maximum`](crate::indicators::Maximum) over the
+/// highs and [`Minimum`](crate::indicators::Minimum) over the lows, but keeps a single
+/// monotonic queue per side instead of two windows of inputs. Each call of `next` is
+/// amortized O(1).
+///
+/// # Formula
+///
+///  * _highest_ - highest high over _period_
+///  * _lowest_ - lowest low over _period_
+///  * _range_ = highest - lowest
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HighLowRange;
+/// use ta::{lit, Next};
+///
+/// let mut hlr = HighLowRange::new(2).unwrap();
+///
+/// hlr.next(lit!(3.0));
+/// let out = hlr.next(lit!(5.0));
+/// assert_eq!((out.highest, out.lowest, out.range), (lit!(5.0), lit!(3.0), lit!(2.0)));
+/// ```
+#[doc(alias = "HLR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HighLowRange {
+    period: usize,
+    index: usize,
+    // (value, index) pairs, values decreasing from the front
+    highs: VecDeque<(crate::Num, usize)>,
+    // (value, index) pairs, values increasing from the front
+    lows: VecDeque<(crate::Num, usize)>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HighLowRangeOutput {
+    pub highest: crate::Num,
+    pub lowest: crate::Num,
+    pub range: crate::Num,
+}
+
+impl HighLowRange {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                highs: VecDeque::with_capacity(period),
+                lows: VecDeque::with_capacity(period),
+            }),
+        }
+    }
+
+    fn update(&mut self, high: crate::Num, low: crate::Num) -> HighLowRangeOutput {
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+
+        while matches!(self.highs.back(), Some(&(value, _)) if value <= high) {
+            self.highs.pop_back();
+        }
+        self.highs.push_back((high, index));
+
+        while matches!(self.lows.back(), Some(&(value, _)) if value >= low) {
+            self.lows.pop_back();
+        }
+        self.lows.push_back((low, index));
+
+        // the newest pair is never evicted, so the fronts always exist
+        while matches!(self.highs.front(), Some(&(_, i)) if index.wrapping_sub(i) >= self.period) {
+            self.highs.pop_front();
+        }
+        while matches!(self.lows.front(), Some(&(_, i)) if index.wrapping_sub(i) >= self.period) {
+            self.lows.pop_front();
+        }
+
+        let highest = self.highs[0].0;
+        let lowest = self.lows[0].0;
+        HighLowRangeOutput {
+            highest,
+            lowest,
+            range: highest - lowest,
+        }
+    }
+}
+
+impl Period for HighLowRange {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for HighLowRange {
+    type Output = HighLowRangeOutput;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.update(input, input)
+    }
+}
+
+impl<T: High + Low> Next<&T> for HighLowRange {
+    type Output = HighLowRangeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.update(input.high(), input.low())
+    }
+}
+
+impl Reset for HighLowRange {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+impl Default for HighLowRange {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for HighLowRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HLR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{Maximum, Minimum};
+    use crate::lit;
+    use crate::test_helper::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    test_indicator!(HighLowRange);
+
+    #[test]
+    fn test_new() {
+        assert!(HighLowRange::new(0).is_err());
+        assert!(HighLowRange::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hlr = HighLowRange::new(3).unwrap();
+
+        let out = hlr.next(lit!(4.0));
+        assert_eq!(
+            (out.highest, out.lowest, out.range),
+            (lit!(4.0), lit!(4.0), lit!(0.0))
+        );
+
+        hlr.next(lit!(1.5));
+        let out = hlr.next(lit!(5.0));
+        assert_eq!(
+            (out.highest, out.lowest, out.range),
+            (lit!(5.0), lit!(1.5), lit!(3.5))
+        );
+
+        hlr.next(lit!(3.0));
+        // 1.5 leaves the window
+        let out = hlr.next(lit!(4.0));
+        assert_eq!(
+            (out.highest, out.lowest, out.range),
+            (lit!(5.0), lit!(3.0), lit!(2.0))
+        );
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut hlr = HighLowRange::new(2).unwrap();
+
+        let out = hlr.next(&Bar::new().high(10).low(8));
+        assert_eq!(out.range, lit!(2.0));
+
+        let out = hlr.next(&Bar::new().high(12).low(9));
+        assert_eq!(
+            (out.highest, out.lowest, out.range),
+            (lit!(12.0), lit!(8.0), lit!(4.0))
+        );
+
+        let out = hlr.next(&Bar::new().high(11).low(10));
+        assert_eq!(
+            (out.highest, out.lowest, out.range),
+            (lit!(12.0), lit!(9.0), lit!(3.0))
+        );
+    }
+
+    #[test]
+    fn test_same_as_maximum_and_minimum() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for period in [1, 2, 5, 20] {
+            let mut hlr = HighLowRange::new(period).unwrap();
+            let mut max = Maximum::new(period).unwrap();
+            let mut min = Minimum::new(period).unwrap();
+
+            for _ in 0..500 {
+                // few distinct values to exercise equal extremes
+                let low = rng.gen_range(0..10);
+                let bar = Bar::new().high(low + rng.gen_range(0..3)).low(low);
+
+                let out = hlr.next(&bar);
+                assert_eq!(out.highest, max.next(&bar));
+                assert_eq!(out.lowest, min.next(&bar));
+                assert_eq!(out.range, out.highest - out.lowest);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hlr = HighLowRange::new(10).unwrap();
+        hlr.next(lit!(4.0));
+        hlr.next(lit!(10.0));
+
+        hlr.reset();
+        let out = hlr.next(lit!(6.0));
+        assert_eq!((out.highest, out.lowest), (lit!(6.0), lit!(6.0)));
+    }
+
+    #[test]
+    fn test_default() {
+        HighLowRange::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = HighLowRange::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "HLR(7)");
+    }
+}
@@ -0,0 +1,178 @@
+use core::fmt;
+
+use crate::{lit, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fills missing values of an input stream by linear interpolation.
+///
+/// Consumes `Option<Decimal>`, where `None` marks a missing bar, and feeds the filled values
+/// into the inner indicator.
+///
+/// A missing value is replaced by the midpoint between the last real value and the next real
+/// value. Since the next value is not known yet when a gap is seen, every input is buffered
+/// and the output is emitted with a **one-bar delay**: `next` returns the inner indicator's
+/// output for the *previous* input, or `None` when there is nothing to emit yet.
+///
+/// Boundaries:
+///
+/// * The very first call always returns `None`.
+/// * Missing values before the first real value cannot be interpolated and are dropped
+///   (`None` is returned for them).
+/// * The lookahead is a single bar, so only a gap of one bar is interpolated. In a longer gap
+///   every missing bar except the last one is filled with the last real value.
+/// * The last input stays buffered until the next call, use [`flush`](Self::flush) to emit it
+///   at the end of the stream. A trailing missing value is filled with the last real value.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Interpolate, SimpleMovingAverage};
+/// use ta::{lit, Next};
+///
+/// let mut interpolate = Interpolate::new(SimpleMovingAverage::new(1).unwrap());
+///
+/// assert_eq!(interpolate.next(Some(lit!(1.0))), None);
+/// assert_eq!(interpolate.next(None), Some(lit!(1.0)));
+/// assert_eq!(interpolate.next(Some(lit!(3.0))), Some(lit!(2.0)));
+/// assert_eq!(interpolate.flush(), Some(lit!(3.0)));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Interpolate<I> {
+    inner: I,
+    last_real: Option<crate::Num>,
+    pending: Option<Option<crate::Num>>,
+}
+
+impl<I> Interpolate<I> {
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            last_real: None,
+            pending: None,
+        }
+    }
+
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<I: Next<crate::Num>> Interpolate<I> {
+    /// Emits the buffered input, if any.
+    pub fn flush(&mut self) -> Option<I::Output> {
+        self.emit(None)
+    }
+
+    fn emit(&mut self, lookahead: Option<crate::Num>) -> Option<I::Output> {
+        let value = match self.pending.take()? {
+            Some(value) => {
+                self.last_real = Some(value);
+                value
+            }
+            None => {
+                let prev = self.last_real?;
+                match lookahead {
+                    Some(next) => (prev + next) / lit!(2.0),
+                    None => prev,
+                }
+            }
+        };
+        Some(self.inner.next(value))
+    }
+}
+
+impl<I: Next<crate::Num>> Next<Option<crate::Num>> for Interpolate<I> {
+    type Output = Option<I::Output>;
+
+    fn next(&mut self, input: Option<crate::Num>) -> Self::Output {
+        let output = self.emit(input);
+        self.pending = Some(input);
+        output
+    }
+}
+
+impl<I: Reset> Reset for Interpolate<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.last_real = None;
+        self.pending = None;
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Interpolate<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "INTERPOLATE({})", self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+
+    fn identity() -> Interpolate<SimpleMovingAverage> {
+        Interpolate::new(SimpleMovingAverage::new(1).unwrap())
+    }
+
+    #[test]
+    fn test_next() {
+        let mut interpolate = identity();
+
+        assert_eq!(interpolate.next(Some(lit!(2.0))), None);
+        assert_eq!(interpolate.next(Some(lit!(4.0))), Some(lit!(2.0)));
+        assert_eq!(interpolate.next(None), Some(lit!(4.0)));
+        assert_eq!(interpolate.next(Some(lit!(7.0))), Some(lit!(5.5)));
+        assert_eq!(interpolate.flush(), Some(lit!(7.0)));
+        assert_eq!(interpolate.flush(), None);
+    }
+
+    #[test]
+    fn test_next_feeds_inner() {
+        let mut interpolate = Interpolate::new(SimpleMovingAverage::new(3).unwrap());
+
+        interpolate.next(Some(lit!(1.0)));
+        interpolate.next(None);
+        interpolate.next(Some(lit!(3.0)));
+
+        // SMA of 1, 2 and 3
+        assert_eq!(interpolate.flush(), Some(lit!(2.0)));
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let mut interpolate = identity();
+
+        // nothing to interpolate from at the start of the stream
+        assert_eq!(interpolate.next(None), None);
+        assert_eq!(interpolate.next(Some(lit!(1.0))), None);
+
+        // longer gaps are carried forward until the last missing bar
+        assert_eq!(interpolate.next(None), Some(lit!(1.0)));
+        assert_eq!(interpolate.next(None), Some(lit!(1.0)));
+        assert_eq!(interpolate.next(Some(lit!(5.0))), Some(lit!(3.0)));
+
+        // trailing gap
+        assert_eq!(interpolate.next(None), Some(lit!(5.0)));
+        assert_eq!(interpolate.flush(), Some(lit!(5.0)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut interpolate = identity();
+
+        interpolate.next(Some(lit!(1.0)));
+        interpolate.next(None);
+
+        interpolate.reset();
+        assert_eq!(interpolate.next(None), None);
+        assert_eq!(interpolate.next(Some(lit!(3.0))), None);
+        assert_eq!(interpolate.flush(), Some(lit!(3.0)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", identity()), "INTERPOLATE(SMA(1))");
+    }
+}
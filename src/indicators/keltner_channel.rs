@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
-use crate::errors::Result;
-use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
-use crate::{int, lit, Close, High, Low, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::indicators::{AverageTrueRange, MovingAverage, MovingAverageKind};
+use crate::{int, lit, Bands, Close, High, Low, Next, Period, Reset, TypicalPriceExt};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,9 @@ use serde::{Deserialize, Serialize};
 ///  * _KC<sub>Upper Band</sub>_ = EMA + ATR of observation * multipler (usually 2.0)
 ///  * _KC<sub>Lower Band</sub>_ = EMA - ATR of observation * multipler (usually 2.0)
 ///
+/// The middle band can use another type of moving average, see
+/// [`new_with_ma`](KeltnerChannel::new_with_ma).
+///
 /// # Links
 ///
 /// * [Keltner channel, Wikipedia](https://en.wikipedia.org/wiki/Keltner_channel)
@@ -31,35 +34,74 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct KeltnerChannel {
     period: usize,
-    multiplier: rust_decimal::Decimal,
+    multiplier: crate::Num,
     atr: AverageTrueRange,
-    ema: ExponentialMovingAverage,
+    average: MovingAverage,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeltnerChannelOutput {
-    pub average: rust_decimal::Decimal,
-    pub upper: rust_decimal::Decimal,
-    pub lower: rust_decimal::Decimal,
+    pub average: crate::Num,
+    pub upper: crate::Num,
+    pub lower: crate::Num,
+}
+
+impl Bands for KeltnerChannelOutput {
+    fn upper(&self) -> crate::Num {
+        self.upper
+    }
+
+    fn average(&self) -> crate::Num {
+        self.average
+    }
+
+    fn lower(&self) -> crate::Num {
+        self.lower
+    }
 }
 
 impl KeltnerChannel {
     /// # Errors
     ///
-    /// Will return `Err` if period or multiple is 0
-    pub fn new(period: usize, multiplier: rust_decimal::Decimal) -> Result<Self> {
+    /// Will return `Err(TaError::InvalidPeriod { .. })` if `period` is 0 and
+    /// `Err(TaError::InvalidMultiplier)` if `multiplier` is not positive
+    pub fn new(period: usize, multiplier: crate::Num) -> Result<Self> {
+        Self::new_with_ma(period, multiplier, MovingAverageKind::Exponential)
+    }
+
+    /// Creates a Keltner Channel whose middle band is a moving average of type `kind`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err(TaError::InvalidPeriod { .. })` if `period` is 0 and
+    /// `Err(TaError::InvalidMultiplier)` if `multiplier` is not positive
+    pub fn new_with_ma(
+        period: usize,
+        multiplier: crate::Num,
+        kind: MovingAverageKind,
+    ) -> Result<Self> {
+        if multiplier <= lit!(0.0) {
+            return Err(TaError::InvalidMultiplier);
+        }
+
         Ok(Self {
             period,
             multiplier,
             atr: AverageTrueRange::new(period)?,
-            ema: ExponentialMovingAverage::new(period)?,
+            average: MovingAverage::new(kind, period)?,
         })
     }
 
     #[must_use]
-    pub fn multiplier(&self) -> rust_decimal::Decimal {
+    pub fn multiplier(&self) -> crate::Num {
         self.multiplier
     }
+
+    #[must_use]
+    pub fn ma_kind(&self) -> MovingAverageKind {
+        self.average.kind()
+    }
 }
 
 impl Period for KeltnerChannel {
@@ -68,12 +110,12 @@ impl Period for KeltnerChannel {
     }
 }
 
-impl Next<rust_decimal::Decimal> for KeltnerChannel {
+impl Next<crate::Num> for KeltnerChannel {
     type Output = KeltnerChannelOutput;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let atr = self.atr.next(input);
-        let average = self.ema.next(input);
+        let average = self.average.next(input);
 
         Self::Output {
             average,
@@ -87,9 +129,7 @@ impl<T: Close + High + Low> Next<&T> for KeltnerChannel {
     type Output = KeltnerChannelOutput;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        let typical_price = (input.close() + input.high() + input.low()) / lit!(3.0);
-
-        let average = self.ema.next(typical_price);
+        let average = self.average.next(input.typical_price());
         let atr = self.atr.next(input);
 
         Self::Output {
@@ -103,7 +143,7 @@ impl<T: Close + High + Low> Next<&T> for KeltnerChannel {
 impl Reset for KeltnerChannel {
     fn reset(&mut self) {
         self.atr.reset();
-        self.ema.reset();
+        self.average.reset();
     }
 }
 
@@ -115,7 +155,16 @@ impl Default for KeltnerChannel {
 
 impl fmt::Display for KeltnerChannel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "KC({}, {})", self.period, self.multiplier)
+        match self.average.kind() {
+            MovingAverageKind::Exponential => {
+                write!(f, "KC({}, {})", self.period, self.multiplier)
+            }
+            _ => write!(
+                f,
+                "KC({}, {}, {})",
+                self.period, self.multiplier, self.average
+            ),
+        }
     }
 }
 
@@ -128,7 +177,18 @@ mod tests {
 
     #[test]
     fn test_new() {
-        assert!(KeltnerChannel::new(0, lit!(2.0)).is_err());
+        assert_eq!(
+            KeltnerChannel::new(0, lit!(2.0)).unwrap_err(),
+            TaError::InvalidPeriod { got: 0 }
+        );
+        assert_eq!(
+            KeltnerChannel::new(10, lit!(0.0)).unwrap_err(),
+            TaError::InvalidMultiplier
+        );
+        assert_eq!(
+            KeltnerChannel::new_with_ma(10, lit!(-1.0), MovingAverageKind::Simple).unwrap_err(),
+            TaError::InvalidMultiplier
+        );
         assert!(KeltnerChannel::new(1, lit!(2.0)).is_ok());
         assert!(KeltnerChannel::new(2, lit!(2.0)).is_ok());
     }
@@ -158,6 +218,34 @@ mod tests {
         assert_eq!(round(d.lower), lit!(-3.75));
     }
 
+    #[test]
+    fn test_next_with_ma() {
+        let mut ema_kc =
+            KeltnerChannel::new_with_ma(3, lit!(2.0), MovingAverageKind::Exponential).unwrap();
+        let mut sma_kc =
+            KeltnerChannel::new_with_ma(3, lit!(2.0), MovingAverageKind::Simple).unwrap();
+        let mut kc = KeltnerChannel::new(3, lit!(2.0)).unwrap();
+
+        let inputs = [lit!(2.0), lit!(5.0), lit!(1.0), lit!(6.25)];
+        let sma_averages = [lit!(2.0), lit!(3.5), lit!(2.667), lit!(4.083)];
+        for (input, sma_average) in inputs.into_iter().zip(sma_averages) {
+            let ema_out = ema_kc.next(input);
+            let sma_out = sma_kc.next(input);
+            assert_eq!(ema_out, kc.next(input));
+
+            // same ATR, different middle band
+            assert_eq!(round(sma_out.average), sma_average);
+            assert_eq!(
+                round(sma_out.upper - sma_out.average),
+                round(ema_out.upper - ema_out.average)
+            );
+            assert_eq!(
+                round(sma_out.average - sma_out.lower),
+                round(ema_out.average - ema_out.lower)
+            );
+        }
+    }
+
     #[test]
     fn test_next_with_data_item() {
         let mut kc = KeltnerChannel::new(3, lit!(2.0)).unwrap();
@@ -208,6 +296,24 @@ mod tests {
         assert_eq!(out.upper, lit!(3.0));
     }
 
+    #[test]
+    fn test_distances() {
+        let mut kc = KeltnerChannel::new(3, lit!(2.0)).unwrap();
+        kc.next(lit!(2.0));
+        // average = 3.5, upper = 6.5, lower = 0.5
+        let out = kc.next(lit!(5.0));
+
+        let above = out.distances(lit!(7.0));
+        assert_eq!(above.to_upper, lit!(0.5));
+        assert_eq!(above.to_average, lit!(3.5));
+        assert_eq!(above.to_lower, lit!(6.5));
+
+        let below = out.distances(lit!(0.0));
+        assert_eq!(below.to_upper, lit!(-6.5));
+        assert_eq!(below.to_average, lit!(-3.5));
+        assert_eq!(below.to_lower, lit!(-0.5));
+    }
+
     #[test]
     fn test_default() {
         KeltnerChannel::default();
@@ -217,5 +323,7 @@ mod tests {
     fn test_display() {
         let kc = KeltnerChannel::new(10, int!(3)).unwrap();
         assert_eq!(format!("{}", kc), "KC(10, 3)");
+        let kc = KeltnerChannel::new_with_ma(10, int!(3), MovingAverageKind::Simple).unwrap();
+        assert_eq!(format!("{}", kc), "KC(10, 3, SMA(10))");
     }
 }
@@ -0,0 +1,238 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, SimpleMovingAverage as Sma};
+use crate::{int, lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Know Sure Thing (KST).
+///
+/// Developed by Martin Pring, KST is a momentum oscillator that combines four
+/// [rates of change](struct.RateOfChange.html) of increasing length. Each ROC is smoothed
+/// with a [simple moving average](struct.SimpleMovingAverage.html) and the longer ones get
+/// a bigger weight, so the indicator follows the major price cycle. Crossings of KST and
+/// its signal line are read as buy and sell signals.
+///
+/// # Formula
+///
+/// KST = 1 * RCMA<sub>1</sub> + 2 * RCMA<sub>2</sub> + 3 * RCMA<sub>3</sub> + 4 * RCMA<sub>4</sub>
+///
+/// signal = SMA(KST, _signal_period_)
+///
+/// Where:
+///
+/// * _RCMA<sub>i</sub>_ = SMA(ROC(price, _roc_periods<sub>i</sub>_), _sma_periods<sub>i</sub>_)
+///
+/// # Parameters
+///
+/// * _roc_periods_ - periods of the four ROCs (integers greater than 0). Default is
+///   10, 15, 20, 30.
+/// * _sma_periods_ - periods of the SMAs smoothing each ROC (integers greater than 0).
+///   Default is 10, 10, 10, 15.
+/// * _signal_period_ - period of the signal SMA (integer greater than 0). Default is 9.
+///
+/// # Links
+///
+/// * [Know Sure Thing, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:know_sure_thing_kst)
+///
+#[doc(alias = "KST")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KnowSureThing {
+    rocs: [RateOfChange; 4],
+    smas: [Sma; 4],
+    signal: Sma,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnowSureThingOutput {
+    pub kst: crate::Num,
+    pub signal: crate::Num,
+}
+
+impl From<KnowSureThingOutput> for (crate::Num, crate::Num) {
+    fn from(kst: KnowSureThingOutput) -> Self {
+        (kst.kst, kst.signal)
+    }
+}
+
+impl KnowSureThing {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(
+        roc_periods: [usize; 4],
+        sma_periods: [usize; 4],
+        signal_period: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            rocs: [
+                RateOfChange::new(roc_periods[0])?,
+                RateOfChange::new(roc_periods[1])?,
+                RateOfChange::new(roc_periods[2])?,
+                RateOfChange::new(roc_periods[3])?,
+            ],
+            smas: [
+                Sma::new(sma_periods[0])?,
+                Sma::new(sma_periods[1])?,
+                Sma::new(sma_periods[2])?,
+                Sma::new(sma_periods[3])?,
+            ],
+            signal: Sma::new(signal_period)?,
+        })
+    }
+}
+
+impl Next<crate::Num> for KnowSureThing {
+    type Output = KnowSureThingOutput;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let mut kst = lit!(0.0);
+        for (i, (roc, sma)) in self.rocs.iter_mut().zip(self.smas.iter_mut()).enumerate() {
+            kst += int!(i + 1) * sma.next(roc.next(input));
+        }
+
+        Self::Output {
+            kst,
+            signal: self.signal.next(kst),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for KnowSureThing {
+    type Output = KnowSureThingOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for KnowSureThing {
+    fn reset(&mut self) {
+        for roc in self.rocs.iter_mut() {
+            roc.reset();
+        }
+        for sma in self.smas.iter_mut() {
+            sma.reset();
+        }
+        self.signal.reset();
+    }
+}
+
+impl Default for KnowSureThing {
+    fn default() -> Self {
+        Self::new([10, 15, 20, 30], [10, 10, 10, 15], 9).unwrap()
+    }
+}
+
+impl fmt::Display for KnowSureThing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KST({},{},{},{},{},{},{},{},{})",
+            self.rocs[0].period(),
+            self.rocs[1].period(),
+            self.rocs[2].period(),
+            self.rocs[3].period(),
+            self.smas[0].period(),
+            self.smas[1].period(),
+            self.smas[2].period(),
+            self.smas[3].period(),
+            self.signal.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(KnowSureThing);
+
+    #[test]
+    fn test_new() {
+        assert!(KnowSureThing::new([0, 15, 20, 30], [10, 10, 10, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 0], [10, 10, 10, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 0, 10, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 10, 10, 15], 0).is_err());
+        assert!(KnowSureThing::new([1, 1, 1, 1], [1, 1, 1, 1], 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+
+        let out = kst.next(lit!(10.0));
+        assert_eq!(out.kst, lit!(0.0));
+        assert_eq!(out.signal, lit!(0.0));
+
+        // every ROC is 10
+        let out = kst.next(lit!(11.0));
+        assert_eq!(out.kst, lit!(100.0));
+        assert_eq!(out.signal, lit!(50.0));
+
+        // 9.091 + 2 * 20 + 3 * 20 + 4 * 20
+        let out = kst.next(&Bar::new().close(12));
+        assert_eq!(round(out.kst), lit!(189.091));
+        assert_eq!(round(out.signal), lit!(144.545));
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        let mut kst = KnowSureThing::default();
+
+        // the price grows by 1% a bar, once every window is full the ROCs are constant
+        let mut price = lit!(100.0);
+        let mut out = kst.next(price);
+        for _ in 0..60 {
+            price *= lit!(1.01);
+            out = kst.next(price);
+        }
+        assert_eq!(round(out.kst), lit!(247.853));
+        assert_eq!(round(out.signal), lit!(247.853));
+
+        // the price turns down, KST drops below its signal
+        for _ in 0..5 {
+            price *= lit!(0.98);
+            out = kst.next(price);
+        }
+        assert!(out.kst < out.signal);
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut kst = KnowSureThing::default();
+
+        for _ in 0..50 {
+            let out = kst.next(lit!(42.0));
+            assert_eq!(out.kst, lit!(0.0));
+            assert_eq!(out.signal, lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+
+        kst.next(lit!(10.0));
+        kst.next(lit!(11.0));
+
+        kst.reset();
+        assert_eq!(kst.next(lit!(10.0)).kst, lit!(0.0));
+        assert_eq!(kst.next(lit!(11.0)).kst, lit!(100.0));
+    }
+
+    #[test]
+    fn test_default() {
+        KnowSureThing::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = KnowSureThing::default();
+        assert_eq!(format!("{}", indicator), "KST(10,15,20,30,10,10,10,15,9)");
+    }
+}
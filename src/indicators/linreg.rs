@@ -0,0 +1,304 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, num, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Least-squares line fitted to the last `period` values of a series.
+///
+/// The oldest value of the window is at _x_ = 0 and the newest one at _x_ = _n_ - 1. The
+/// sums are updated on every push instead of being recomputed over the window: once the
+/// window is full, evicting the oldest value shifts all the others one step to the left,
+/// which lowers the sum of _xy_ by the sum of the remaining values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct LinearRegression {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_x: crate::Num,
+    sum_x2: crate::Num,
+    sum_y: crate::Num,
+    sum_y2: crate::Num,
+    sum_xy: crate::Num,
+    deque: Box<[crate::Num]>,
+}
+
+impl LinearRegression {
+    pub(crate) fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_x: lit!(0.0),
+                sum_x2: lit!(0.0),
+                sum_y: lit!(0.0),
+                sum_y2: lit!(0.0),
+                sum_xy: lit!(0.0),
+                deque: vec![lit!(0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    pub(crate) fn period(&self) -> usize {
+        self.period
+    }
+
+    pub(crate) fn push(&mut self, y: crate::Num) {
+        if self.count < self.period {
+            let x = int!(self.count);
+            self.sum_x += x;
+            self.sum_x2 += x * x;
+            self.count += 1;
+        } else {
+            let old_y = self.deque[self.index];
+            self.sum_y -= old_y;
+            self.sum_y2 -= old_y * old_y;
+            // the old value was at x = 0, the remaining ones move one step to the left
+            self.sum_xy -= self.sum_y;
+        }
+
+        self.deque[self.index] = y;
+        self.sum_y += y;
+        self.sum_y2 += y * y;
+        self.sum_xy += int!(self.count - 1) * y;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+    }
+
+    pub(crate) fn slope(&self) -> crate::Num {
+        let n = int!(self.count);
+        let denominator = n * self.sum_x2 - self.sum_x * self.sum_x;
+        if denominator == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            (n * self.sum_xy - self.sum_x * self.sum_y) / denominator
+        }
+    }
+
+    pub(crate) fn intercept(&self) -> crate::Num {
+        if self.count == 0 {
+            return lit!(0.0);
+        }
+        (self.sum_y - self.slope() * self.sum_x) / int!(self.count)
+    }
+
+    /// Value of the line at the newest value of the window.
+    pub(crate) fn value(&self) -> crate::Num {
+        if self.count == 0 {
+            return lit!(0.0);
+        }
+        self.intercept() + self.slope() * int!(self.count - 1)
+    }
+
+    /// Standard error of the estimate, sqrt(sum((y - line)^2) / (n - 2)). It is 0 while the
+    /// window holds 2 values or less, since a line passes through any 2 points.
+    pub(crate) fn standard_error(&self) -> crate::Num {
+        if self.count <= 2 {
+            return lit!(0.0);
+        }
+
+        let sse = self.sum_y2 - self.intercept() * self.sum_y - self.slope() * self.sum_xy;
+        // rounding errors can push a perfect fit slightly below 0
+        num::sqrt(sse.max(lit!(0.0)) / int!(self.count - 2))
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sum_x = lit!(0.0);
+        self.sum_x2 = lit!(0.0);
+        self.sum_y = lit!(0.0);
+        self.sum_y2 = lit!(0.0);
+        self.sum_xy = lit!(0.0);
+        for i in 0..self.period {
+            self.deque[i] = lit!(0.0);
+        }
+    }
+}
+
+/// Linear Regression Moving Average (LINREG).
+///
+/// Fits a least-squares line to the last _period_ prices and returns the value of the line
+/// at the current bar, the endpoint of the line. It reacts to trends faster than a simple
+/// moving average of the same period.
+///
+/// # Formula
+///
+/// slope = (n * sum(xy) - sum(x) * sum(y)) / (n * sum(x<sup>2</sup>) - sum(x)<sup>2</sup>)
+///
+/// intercept = (sum(y) - slope * sum(x)) / n
+///
+/// LINREG = intercept + slope * (n - 1)
+///
+/// Where:
+///
+/// * _y_ - prices of the window
+/// * _x_ - position of the price within the window, from 0 for the oldest price to n - 1
+///   for the current one
+/// * _n_ - number of prices in the window, at most _period_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Linear Regression Curve, TradingView](https://www.tradingview.com/support/solutions/43000594635-linear-regression-curve/)
+///
+#[doc(alias = "LINREG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegressionMovingAverage {
+    regression: LinearRegression,
+}
+
+impl LinearRegressionMovingAverage {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            regression: LinearRegression::new(period)?,
+        })
+    }
+
+    /// Slope of the regression line, i.e. the change of the line per bar.
+    #[must_use]
+    pub fn slope(&self) -> crate::Num {
+        self.regression.slope()
+    }
+}
+
+impl Period for LinearRegressionMovingAverage {
+    fn period(&self) -> usize {
+        self.regression.period()
+    }
+}
+
+impl Next<crate::Num> for LinearRegressionMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.regression.push(input);
+        self.regression.value()
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegressionMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for LinearRegressionMovingAverage {
+    fn reset(&mut self) {
+        self.regression.reset();
+    }
+}
+
+impl Default for LinearRegressionMovingAverage {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegressionMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINREG({})", self.regression.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(LinearRegressionMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegressionMovingAverage::new(0).is_err());
+        assert!(LinearRegressionMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_linear() {
+        let mut linreg = LinearRegressionMovingAverage::new(4).unwrap();
+
+        assert_eq!(linreg.next(lit!(3.0)), lit!(3.0));
+        assert_eq!(linreg.slope(), lit!(0.0));
+
+        // y = 2x + 3, the line goes through every price
+        for x in 1..10 {
+            let y = lit!(2.0) * int!(x) + lit!(3.0);
+            assert_eq!(linreg.next(y), y);
+            assert_eq!(linreg.slope(), lit!(2.0));
+        }
+    }
+
+    #[test]
+    fn test_next() {
+        let mut linreg = LinearRegressionMovingAverage::new(3).unwrap();
+
+        assert_eq!(linreg.next(lit!(1.0)), lit!(1.0));
+        assert_eq!(linreg.next(lit!(3.0)), lit!(3.0));
+        // slope 0.5, intercept 1.5
+        assert_eq!(linreg.next(lit!(2.0)), lit!(2.5));
+        assert_eq!(linreg.slope(), lit!(0.5));
+        // 1 leaves the window: 3, 2, 6, slope 1.5, intercept 2.167
+        assert_eq!(round(linreg.next(&Bar::new().close(6))), lit!(5.167));
+        assert_eq!(linreg.slope(), lit!(1.5));
+    }
+
+    #[test]
+    fn test_standard_error() {
+        let mut regression = LinearRegression::new(4).unwrap();
+
+        for y in [1, 3, 2] {
+            regression.push(int!(y));
+        }
+        // residuals -0.5, 1, -0.5
+        assert_eq!(round(regression.standard_error()), lit!(1.225));
+
+        regression.reset();
+        for y in [3, 5, 7, 9, 11] {
+            regression.push(int!(y));
+        }
+        assert_eq!(regression.standard_error(), lit!(0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut linreg = LinearRegressionMovingAverage::new(3).unwrap();
+
+        linreg.next(lit!(1.0));
+        linreg.next(lit!(3.0));
+
+        linreg.reset();
+        assert_eq!(linreg.next(lit!(5.0)), lit!(5.0));
+        assert_eq!(linreg.slope(), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegressionMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = LinearRegressionMovingAverage::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "LINREG(14)");
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{lit, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Mass Index (MASS).
+///
+/// Developed by Donald Dorsey, the Mass Index looks for reversals in the widening and
+/// narrowing of the high-low range. It compares a 9-period EMA of the range to an EMA of
+/// itself and sums the ratio over _period_ bars, so the index rises when the range expands.
+/// Dorsey's "reversal bulge" is a rise of the index above 27 followed by a drop below 26.5.
+///
+/// # Formula
+///
+/// MASS = sum(EMA<sub>9</sub>(high - low) / EMA<sub>9</sub>(EMA<sub>9</sub>(high - low)), _period_)
+///
+/// While the double smoothed range is 0 the ratio is 1. For a constant range the ratio is
+/// 1 as well, so the index settles at _period_.
+///
+/// # Parameters
+///
+/// * _period_ - number of ratios summed (integer greater than 0). Default is 25.
+///
+/// # Links
+///
+/// * [Mass Index, Wikipedia](https://en.wikipedia.org/wiki/Mass_index)
+///
+#[doc(alias = "MASS")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MassIndex {
+    period: usize,
+    ema: Ema,
+    double_ema: Ema,
+    index: usize,
+    sum: crate::Num,
+    deque: Box<[crate::Num]>,
+}
+
+impl MassIndex {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                ema: Ema::new(9)?,
+                double_ema: Ema::new(9)?,
+                index: 0,
+                sum: lit!(0.0),
+                deque: vec![lit!(0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for MassIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low> Next<&T> for MassIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ema = self.ema.next(input.high() - input.low());
+        let double_ema = self.double_ema.next(ema);
+        let ratio = if double_ema == lit!(0.0) {
+            lit!(1.0)
+        } else {
+            ema / double_ema
+        };
+
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = ratio;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        self.sum = self.sum - old_val + ratio;
+        self.sum
+    }
+}
+
+impl Reset for MassIndex {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.double_ema.reset();
+        self.index = 0;
+        self.sum = lit!(0.0);
+        for i in 0..self.period {
+            self.deque[i] = lit!(0.0);
+        }
+    }
+}
+
+impl Default for MassIndex {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl fmt::Display for MassIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MASS({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(MassIndex::new(0).is_err());
+        assert!(MassIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mass = MassIndex::new(3).unwrap();
+
+        assert_eq!(mass.next(&Bar::new().high(12).low(10)), lit!(1.0));
+        // ema = 2 + 0.2 * 2 = 2.4, double ema = 2 + 0.2 * 0.4 = 2.08
+        assert_eq!(round(mass.next(&Bar::new().high(14).low(10))), lit!(2.154));
+        // ema = 2.4 + 0.2 * -0.4 = 2.32, double ema = 2.08 + 0.2 * 0.24 = 2.128
+        assert_eq!(round(mass.next(&Bar::new().high(12).low(10))), lit!(3.244));
+        // the first ratio leaves the window
+        // ema = 2.32 + 0.2 * -0.32 = 2.256, double ema = 2.128 + 0.2 * 0.128 = 2.1536
+        assert_eq!(round(mass.next(&Bar::new().high(12).low(10))), lit!(3.292));
+    }
+
+    #[test]
+    fn test_next_constant_range() {
+        let mut mass = MassIndex::default();
+
+        for i in 0..50 {
+            let low = int!(100 + i);
+            let value = mass.next(&Bar::new().high(low + lit!(2.0)).low(low));
+            if i >= 24 {
+                assert_eq!(value, lit!(25.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_expanding_range() {
+        let mut mass = MassIndex::default();
+
+        for _ in 0..50 {
+            mass.next(&Bar::new().high(12).low(10));
+        }
+        // the range widens, the fast EMA runs ahead of the double smoothed one
+        let mut last = lit!(25.0);
+        for range in 3..10 {
+            let value = mass.next(&Bar::new().high(10 + range).low(10));
+            assert!(value > last);
+            last = value;
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mass = MassIndex::new(3).unwrap();
+
+        mass.next(&Bar::new().high(12).low(10));
+        mass.next(&Bar::new().high(14).low(10));
+
+        mass.reset();
+        assert_eq!(mass.next(&Bar::new().high(12).low(10)), lit!(1.0));
+        assert_eq!(round(mass.next(&Bar::new().high(14).low(10))), lit!(2.154));
+    }
+
+    #[test]
+    fn test_default() {
+        MassIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = MassIndex::default();
+        assert_eq!(format!("{}", indicator), "MASS(25)");
+    }
+}
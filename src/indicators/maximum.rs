@@ -1,7 +1,7 @@
-use std::fmt;
+use alloc::collections::VecDeque;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::helpers::NEG_INFINITY;
 use crate::{High, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,14 +11,37 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+/// * _tie_break_ - which of several equal highest values is treated as the maximum, see
+///   [`TieBreak`]. Default is [`TieBreak::KeepOldest`].
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Maximum {
     period: usize,
-    max_index: usize,
-    cur_index: usize,
-    deque: Box<[rust_decimal::Decimal]>,
+    index: usize,
+    tie_break: TieBreak,
+    // (value, index) pairs of the inputs that can still become the maximum, values are
+    // monotonic from the front, which holds the current maximum
+    deque: VecDeque<(crate::Num, usize)>,
+}
+
+/// Tie-breaking policy of [`Maximum`] and [`Minimum`](struct.Minimum.html) for equal extremes.
+///
+/// The returned value is the same under both policies, but the policy decides which bar is
+/// considered the extreme and therefore its [`age`](Maximum::age) and when it is evicted
+/// from the window:
+///
+/// * `KeepOldest` - an equal value does not replace the current extreme. The extreme is
+///   evicted _period_ bars after its first occurrence, and the search then moves on to the
+///   next occurrence.
+/// * `KeepNewest` - an equal value replaces the current extreme, so it is evicted _period_
+///   bars after its last occurrence.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    #[default]
+    KeepOldest,
+    KeepNewest,
 }
 
 impl Maximum {
@@ -26,29 +49,44 @@ impl Maximum {
     ///
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_tiebreak(period, TieBreak::default())
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_tiebreak(period: usize, tie_break: TieBreak) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
-                max_index: 0,
-                cur_index: 0,
-                deque: vec![NEG_INFINITY; period].into_boxed_slice(),
+                index: 0,
+                tie_break,
+                deque: VecDeque::with_capacity(period),
             }),
         }
     }
 
-    fn find_max_index(&self) -> usize {
-        let mut max = NEG_INFINITY;
-        let mut index: usize = 0;
+    #[must_use]
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val > max {
-                max = val;
-                index = i;
-            }
+    /// Returns the number of bars since the current highest value was seen, 0 meaning the
+    /// latest input.
+    #[must_use]
+    pub fn age(&self) -> usize {
+        match self.deque.front() {
+            Some(&(_, index)) => self.index.wrapping_sub(index).wrapping_sub(1),
+            None => 0,
         }
+    }
 
-        index
+    fn is_better(&self, value: crate::Num, max: crate::Num) -> bool {
+        match self.tie_break {
+            TieBreak::KeepOldest => value > max,
+            TieBreak::KeepNewest => value >= max,
+        }
     }
 }
 
@@ -58,30 +96,27 @@ impl Period for Maximum {
     }
 }
 
-impl Next<rust_decimal::Decimal> for Maximum {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for Maximum {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
-        self.deque[self.cur_index] = input;
-
-        if input > self.deque[self.max_index] {
-            self.max_index = self.cur_index;
-        } else if self.max_index == self.cur_index {
-            self.max_index = self.find_max_index();
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        while matches!(self.deque.back(), Some(&(value, _)) if self.is_better(input, value)) {
+            self.deque.pop_back();
         }
+        self.deque.push_back((input, self.index));
 
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
+        // the indexes are distinct, so at most one input leaves the window
+        if self.index.wrapping_sub(self.deque[0].1) >= self.period {
+            self.deque.pop_front();
+        }
+        self.index = self.index.wrapping_add(1);
 
-        self.deque[self.max_index]
+        self.deque[0].0
     }
 }
 
 impl<T: High> Next<&T> for Maximum {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.high())
@@ -90,9 +125,8 @@ impl<T: High> Next<&T> for Maximum {
 
 impl Reset for Maximum {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = NEG_INFINITY;
-        }
+        self.index = 0;
+        self.deque.clear();
     }
 }
 
@@ -111,8 +145,10 @@ impl fmt::Display for Maximum {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lit;
     use crate::test_helper::*;
+    use crate::{int, lit};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     test_indicator!(Maximum);
 
@@ -139,7 +175,7 @@ mod tests {
 
     #[test]
     fn test_next_with_bars() {
-        fn bar(high: rust_decimal::Decimal) -> Bar {
+        fn bar(high: crate::Num) -> Bar {
             Bar::new().high(high)
         }
 
@@ -151,6 +187,59 @@ mod tests {
         assert_eq!(max.next(&bar(lit!(2.0))), lit!(3.5));
     }
 
+    #[test]
+    fn test_tie_break() {
+        let mut oldest = Maximum::new(3).unwrap();
+        let mut newest = Maximum::new_with_tiebreak(3, TieBreak::KeepNewest).unwrap();
+        assert_eq!(oldest.tie_break(), TieBreak::KeepOldest);
+        assert_eq!(newest.tie_break(), TieBreak::KeepNewest);
+
+        // (input, age with KeepOldest, age with KeepNewest)
+        let steps = [
+            (lit!(5.0), 0, 0),
+            (lit!(5.0), 1, 0),
+            (lit!(5.0), 2, 0),
+            // the first 5 leaves the window, KeepOldest moves on to the second one
+            (lit!(1.0), 2, 1),
+            (lit!(1.0), 2, 2),
+            // all 5s have left the window
+            (lit!(1.0), 2, 0),
+        ];
+        for (input, oldest_age, newest_age) in steps {
+            assert_eq!(oldest.next(input), newest.next(input));
+            assert_eq!(oldest.age(), oldest_age);
+            assert_eq!(newest.age(), newest_age);
+        }
+    }
+
+    #[test]
+    fn test_same_as_naive_scan() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for tie_break in [TieBreak::KeepOldest, TieBreak::KeepNewest] {
+            for period in [1, 2, 3, 10, 50] {
+                let mut max = Maximum::new_with_tiebreak(period, tie_break).unwrap();
+                let mut inputs = Vec::new();
+
+                for _ in 0..1000 {
+                    // few distinct values to exercise equal extremes
+                    let input: crate::Num = int!(rng.gen_range(0..20));
+                    inputs.push(input);
+
+                    let window = &inputs[inputs.len().saturating_sub(period)..];
+                    let expected = window.iter().copied().reduce(|a, b| a.max(b)).unwrap();
+                    let position = match tie_break {
+                        TieBreak::KeepOldest => window.iter().position(|&v| v == expected),
+                        TieBreak::KeepNewest => window.iter().rposition(|&v| v == expected),
+                    };
+
+                    assert_eq!(max.next(input), expected);
+                    assert_eq!(max.age(), window.len() - 1 - position.unwrap());
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut max = Maximum::new(100).unwrap();
@@ -0,0 +1,186 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// McGinley Dynamic (MD).
+///
+/// Developed by John R. McGinley, the McGinley Dynamic is a moving average which adjusts
+/// its speed to the market: it slows down when the price runs away above it and speeds up
+/// when the price falls below it. This keeps it from running ahead of a rally and lets it
+/// follow a sell-off closely.
+///
+/// # Formula
+///
+/// MD<sub>t</sub> = MD<sub>t-1</sub> + (price - MD<sub>t-1</sub>) / (k * _period_ * (price / MD<sub>t-1</sub>)<sup>4</sup>)
+///
+/// Where:
+///
+/// * _k_ - constant of 0.6
+///
+/// The first price seeds the indicator. If the previous value or the price is 0 the
+/// formula is undefined, the indicator is seeded again with the price instead.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [McGinley Dynamic, Investopedia](https://www.investopedia.com/articles/forex/09/mcginley-dynamic-indicator.asp)
+///
+#[doc(alias = "MD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct McGinleyDynamic {
+    period: usize,
+    current: Option<crate::Num>,
+}
+
+impl McGinleyDynamic {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                current: None,
+            }),
+        }
+    }
+}
+
+impl Period for McGinleyDynamic {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for McGinleyDynamic {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let md = match self.current {
+            Some(prev) if prev != lit!(0.0) && input != lit!(0.0) => {
+                let ratio = input / prev;
+                let ratio4 = ratio * ratio * ratio * ratio;
+                prev + (input - prev) / (lit!(0.6) * int!(self.period) * ratio4)
+            }
+            _ => input,
+        };
+
+        self.current = Some(md);
+        md
+    }
+}
+
+impl<T: Close> Next<&T> for McGinleyDynamic {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for McGinleyDynamic {
+    fn reset(&mut self) {
+        self.current = None;
+    }
+}
+
+impl Default for McGinleyDynamic {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for McGinleyDynamic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MD({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(McGinleyDynamic);
+
+    #[test]
+    fn test_new() {
+        assert!(McGinleyDynamic::new(0).is_err());
+        assert!(McGinleyDynamic::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut md = McGinleyDynamic::new(5).unwrap();
+
+        assert_eq!(md.next(lit!(10.0)), lit!(10.0));
+        // 10 + 1 / (3 * 1.1^4)
+        assert_eq!(round(md.next(lit!(11.0))), lit!(10.228));
+        assert_eq!(round(md.next(&Bar::new().close(12))), lit!(10.539));
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut md = McGinleyDynamic::new(5).unwrap();
+
+        assert_eq!(md.next(lit!(0.0)), lit!(0.0));
+        assert_eq!(md.next(lit!(10.0)), lit!(10.0));
+        assert_eq!(md.next(lit!(0.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_less_overshoot_than_ema() {
+        let mut md = McGinleyDynamic::new(5).unwrap();
+        let mut ema = ExponentialMovingAverage::new(5).unwrap();
+
+        // neither runs ahead of the rally
+        for close in 10..17 {
+            let price = int!(close);
+            assert!(md.next(price) <= price);
+            assert!(ema.next(price) <= price);
+        }
+
+        // both stay above the price for a while once it turns down
+        let mut md_overshoot = lit!(0.0);
+        let mut ema_overshoot = lit!(0.0);
+        for close in (10..16).rev() {
+            let price = int!(close);
+            md_overshoot += (md.next(price) - price).max(lit!(0.0));
+            ema_overshoot += (ema.next(price) - price).max(lit!(0.0));
+        }
+
+        assert!(md_overshoot > lit!(0.0));
+        assert!(md_overshoot < ema_overshoot);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut md = McGinleyDynamic::new(5).unwrap();
+
+        md.next(lit!(10.0));
+        md.next(lit!(11.0));
+
+        md.reset();
+        assert_eq!(md.next(lit!(20.0)), lit!(20.0));
+    }
+
+    #[test]
+    fn test_default() {
+        McGinleyDynamic::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = McGinleyDynamic::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "MD(14)");
+    }
+}
@@ -1,4 +1,6 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -33,8 +35,8 @@ pub struct MeanAbsoluteDeviation {
     period: usize,
     index: usize,
     count: usize,
-    sum: rust_decimal::Decimal,
-    deque: Box<[rust_decimal::Decimal]>,
+    sum: crate::Num,
+    deque: Box<[crate::Num]>,
 }
 
 impl MeanAbsoluteDeviation {
@@ -43,7 +45,7 @@ impl MeanAbsoluteDeviation {
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
                 index: 0,
@@ -61,10 +63,10 @@ impl Period for MeanAbsoluteDeviation {
     }
 }
 
-impl Next<rust_decimal::Decimal> for MeanAbsoluteDeviation {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for MeanAbsoluteDeviation {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         self.sum = if self.count < self.period {
             self.count += 1;
             self.sum + input
@@ -90,7 +92,7 @@ impl Next<rust_decimal::Decimal> for MeanAbsoluteDeviation {
 }
 
 impl<T: Close> Next<&T> for MeanAbsoluteDeviation {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
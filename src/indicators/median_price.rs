@@ -0,0 +1,109 @@
+use core::fmt;
+
+use crate::{lit, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Median Price.
+///
+/// The middle of the range of the bar. It is stateless, so it can be used to turn a
+/// stream of bars into a stream of prices for another indicator.
+///
+/// # Formula
+///
+/// Median Price = (high + low) / 2
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MedianPrice {}
+
+impl MedianPrice {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T: High + Low> Next<&T> for MedianPrice {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        (input.high() + input.low()) / lit!(2.0)
+    }
+}
+
+impl Reset for MedianPrice {
+    fn reset(&mut self) {}
+}
+
+impl Default for MedianPrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for MedianPrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN_PRICE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut median = MedianPrice::new();
+
+        assert_eq!(median.next(&Bar::new().high(12).low(8)), lit!(10.0));
+        assert_eq!(median.next(&Bar::new().high(11).low(8)), lit!(9.5));
+        assert_eq!(median.next(&Bar::new().high(10).low(10)), lit!(10.0));
+    }
+
+    #[test]
+    fn test_next_ignores_close() {
+        let mut median = MedianPrice::new();
+
+        let bar1 = Bar::new().high(12).low(8).close(8);
+        let bar2 = Bar::new().high(12).low(8).close(12);
+        assert_eq!(median.next(&bar1), median.next(&bar2));
+    }
+
+    #[test]
+    fn test_into_sma() {
+        let mut median = MedianPrice::new();
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+
+        assert_eq!(
+            sma.next(median.next(&Bar::new().high(12).low(8))),
+            lit!(10.0)
+        );
+        assert_eq!(
+            sma.next(median.next(&Bar::new().high(16).low(12))),
+            lit!(12.0)
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut median = MedianPrice::new();
+
+        median.next(&Bar::new().high(12).low(8));
+        median.reset();
+        assert_eq!(median.next(&Bar::new().high(12).low(8)), lit!(10.0));
+    }
+
+    #[test]
+    fn test_default() {
+        MedianPrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = MedianPrice::new();
+        assert_eq!(format!("{}", indicator), "MEDIAN_PRICE");
+    }
+}
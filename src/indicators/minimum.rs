@@ -1,7 +1,8 @@
-use std::fmt;
+use alloc::collections::VecDeque;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::helpers::INFINITY;
+use crate::indicators::TieBreak;
 use crate::{Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,14 +12,18 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+/// * _tie_break_ - which of several equal lowest values is treated as the minimum, see
+///   [`TieBreak`]. Default is [`TieBreak::KeepOldest`].
 ///
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct Minimum {
     period: usize,
-    min_index: usize,
-    cur_index: usize,
-    deque: Box<[rust_decimal::Decimal]>,
+    index: usize,
+    tie_break: TieBreak,
+    // (value, index) pairs of the inputs that can still become the minimum, values are
+    // monotonic from the front, which holds the current minimum
+    deque: VecDeque<(crate::Num, usize)>,
 }
 
 impl Minimum {
@@ -26,29 +31,44 @@ impl Minimum {
     ///
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_tiebreak(period, TieBreak::default())
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_tiebreak(period: usize, tie_break: TieBreak) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
-                min_index: 0,
-                cur_index: 0,
-                deque: vec![INFINITY; period].into_boxed_slice(),
+                index: 0,
+                tie_break,
+                deque: VecDeque::with_capacity(period),
             }),
         }
     }
 
-    fn find_min_index(&self) -> usize {
-        let mut min = INFINITY;
-        let mut index: usize = 0;
+    #[must_use]
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val < min {
-                min = val;
-                index = i;
-            }
+    /// Returns the number of bars since the current lowest value was seen, 0 meaning the
+    /// latest input.
+    #[must_use]
+    pub fn age(&self) -> usize {
+        match self.deque.front() {
+            Some(&(_, index)) => self.index.wrapping_sub(index).wrapping_sub(1),
+            None => 0,
         }
+    }
 
-        index
+    fn is_better(&self, value: crate::Num, min: crate::Num) -> bool {
+        match self.tie_break {
+            TieBreak::KeepOldest => value < min,
+            TieBreak::KeepNewest => value <= min,
+        }
     }
 }
 
@@ -58,30 +78,27 @@ impl Period for Minimum {
     }
 }
 
-impl Next<rust_decimal::Decimal> for Minimum {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for Minimum {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
-        self.deque[self.cur_index] = input;
-
-        if input < self.deque[self.min_index] {
-            self.min_index = self.cur_index;
-        } else if self.min_index == self.cur_index {
-            self.min_index = self.find_min_index();
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        while matches!(self.deque.back(), Some(&(value, _)) if self.is_better(input, value)) {
+            self.deque.pop_back();
         }
+        self.deque.push_back((input, self.index));
 
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
+        // the indexes are distinct, so at most one input leaves the window
+        if self.index.wrapping_sub(self.deque[0].1) >= self.period {
+            self.deque.pop_front();
+        }
+        self.index = self.index.wrapping_add(1);
 
-        self.deque[self.min_index]
+        self.deque[0].0
     }
 }
 
 impl<T: Low> Next<&T> for Minimum {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.low())
@@ -90,9 +107,8 @@ impl<T: Low> Next<&T> for Minimum {
 
 impl Reset for Minimum {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = INFINITY;
-        }
+        self.index = 0;
+        self.deque.clear();
     }
 }
 
@@ -111,8 +127,10 @@ impl fmt::Display for Minimum {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lit;
     use crate::test_helper::*;
+    use crate::{int, lit};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     test_indicator!(Minimum);
 
@@ -140,7 +158,7 @@ mod tests {
 
     #[test]
     fn test_next_with_bars() {
-        fn bar(low: rust_decimal::Decimal) -> Bar {
+        fn bar(low: crate::Num) -> Bar {
             Bar::new().low(low)
         }
 
@@ -152,6 +170,56 @@ mod tests {
         assert_eq!(min.next(&bar(lit!(5.0))), lit!(1.2));
     }
 
+    #[test]
+    fn test_tie_break() {
+        let mut oldest = Minimum::new(2).unwrap();
+        let mut newest = Minimum::new_with_tiebreak(2, TieBreak::KeepNewest).unwrap();
+
+        // (input, age with KeepOldest, age with KeepNewest)
+        let steps = [
+            (lit!(1.0), 0, 0),
+            (lit!(1.0), 1, 0),
+            // the first 1 leaves the window
+            (lit!(1.0), 1, 0),
+            (lit!(3.0), 1, 1),
+            // all 1s have left the window
+            (lit!(3.0), 1, 0),
+        ];
+        for (input, oldest_age, newest_age) in steps {
+            assert_eq!(oldest.next(input), newest.next(input));
+            assert_eq!(oldest.age(), oldest_age);
+            assert_eq!(newest.age(), newest_age);
+        }
+    }
+
+    #[test]
+    fn test_same_as_naive_scan() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for tie_break in [TieBreak::KeepOldest, TieBreak::KeepNewest] {
+            for period in [1, 2, 3, 10, 50] {
+                let mut min = Minimum::new_with_tiebreak(period, tie_break).unwrap();
+                let mut inputs = Vec::new();
+
+                for _ in 0..1000 {
+                    // few distinct values to exercise equal extremes
+                    let input: crate::Num = int!(rng.gen_range(0..20));
+                    inputs.push(input);
+
+                    let window = &inputs[inputs.len().saturating_sub(period)..];
+                    let expected = window.iter().copied().reduce(|a, b| a.min(b)).unwrap();
+                    let position = match tie_break {
+                        TieBreak::KeepOldest => window.iter().position(|&v| v == expected),
+                        TieBreak::KeepNewest => window.iter().rposition(|&v| v == expected),
+                    };
+
+                    assert_eq!(min.next(input), expected);
+                    assert_eq!(min.age(), window.len() - 1 - position.unwrap());
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut min = Minimum::new(10).unwrap();
@@ -1,5 +1,5 @@
 mod exponential_moving_average;
-pub use self::exponential_moving_average::ExponentialMovingAverage;
+pub use self::exponential_moving_average::{EmaSeed, ExponentialMovingAverage};
 
 mod weighted_moving_average;
 pub use self::weighted_moving_average::WeightedMovingAverage;
@@ -14,13 +14,13 @@ mod mean_absolute_deviation;
 pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
 
 mod relative_strength_index;
-pub use self::relative_strength_index::RelativeStrengthIndex;
+pub use self::relative_strength_index::{RelativeStrengthIndex, RsiScale, RsiSmoothing};
 
 mod minimum;
 pub use self::minimum::Minimum;
 
 mod maximum;
-pub use self::maximum::Maximum;
+pub use self::maximum::{Maximum, TieBreak};
 
 mod fast_stochastic;
 pub use self::fast_stochastic::FastStochastic;
@@ -36,7 +36,8 @@ pub use self::average_true_range::AverageTrueRange;
 
 mod moving_average_convergence_divergence;
 pub use self::moving_average_convergence_divergence::{
-    MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput,
+    HistogramState, MacdSignal, MacdSignalOutput, MovingAverageConvergenceDivergence,
+    MovingAverageConvergenceDivergenceOutput,
 };
 
 mod percentage_price_oscillator;
@@ -67,3 +68,184 @@ pub use self::money_flow_index::MoneyFlowIndex;
 
 mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
+
+mod directional_movement_index;
+pub use self::directional_movement_index::{
+    DirectionalMovementIndex, DirectionalMovementIndexOutput,
+};
+
+mod parabolic_sar;
+pub use self::parabolic_sar::{ParabolicSAR, ParabolicSAROutput, Trend};
+
+mod interpolate;
+pub use self::interpolate::Interpolate;
+
+mod momentum;
+pub use self::momentum::Momentum;
+
+mod chande_momentum_oscillator;
+pub use self::chande_momentum_oscillator::ChandeMomentumOscillator;
+
+mod strict;
+pub use self::strict::Strict;
+
+mod trix;
+pub use self::trix::Trix;
+
+mod ultimate_oscillator;
+pub use self::ultimate_oscillator::UltimateOscillator;
+
+mod aroon;
+pub use self::aroon::{Aroon, AroonOutput};
+
+mod donchian_channel;
+pub use self::donchian_channel::{DonchianChannel, DonchianChannelOutput};
+
+mod vwap_bands;
+pub use self::vwap_bands::{VwapBands, VwapBandsOutput};
+
+mod super_trend;
+pub use self::super_trend::{SuperTrend, SuperTrendOutput};
+
+mod close_location_value;
+pub use self::close_location_value::CloseLocationValue;
+
+mod accumulation_distribution;
+pub use self::accumulation_distribution::AccumulationDistribution;
+
+mod chaikin_money_flow;
+pub use self::chaikin_money_flow::ChaikinMoneyFlow;
+
+mod ulcer_index;
+pub use self::ulcer_index::UlcerIndex;
+
+mod chaikin_oscillator;
+pub use self::chaikin_oscillator::ChaikinOscillator;
+
+mod price_volume_trend;
+pub use self::price_volume_trend::PriceVolumeTrend;
+
+mod force_index;
+pub use self::force_index::ForceIndex;
+
+mod ease_of_movement;
+pub use self::ease_of_movement::EaseOfMovement;
+
+mod volume_index;
+pub use self::volume_index::{NegativeVolumeIndex, PositiveVolumeIndex};
+
+mod vortex;
+pub use self::vortex::{VortexIndicator, VortexIndicatorOutput};
+
+mod awesome_oscillator;
+pub use self::awesome_oscillator::AwesomeOscillator;
+
+mod detrended_price_oscillator;
+pub use self::detrended_price_oscillator::DetrendedPriceOscillator;
+
+mod coppock_curve;
+pub use self::coppock_curve::CoppockCurve;
+
+mod stochastic_rsi;
+pub use self::stochastic_rsi::StochasticRsi;
+
+mod balance_of_power;
+pub use self::balance_of_power::BalanceOfPower;
+
+mod qstick;
+pub use self::qstick::Qstick;
+
+mod median_price;
+pub use self::median_price::MedianPrice;
+
+mod weighted_close;
+pub use self::weighted_close::WeightedClose;
+
+mod connors_rsi;
+pub use self::connors_rsi::ConnorsRsi;
+
+mod alma;
+pub use self::alma::ArnaudLegouxMovingAverage;
+
+mod mcginley_dynamic;
+pub use self::mcginley_dynamic::McGinleyDynamic;
+
+mod t3;
+pub use self::t3::T3MovingAverage;
+
+mod vidya;
+pub use self::vidya::Vidya;
+
+mod linreg;
+pub use self::linreg::LinearRegressionMovingAverage;
+
+mod standard_error_bands;
+pub use self::standard_error_bands::{StandardErrorBands, StandardErrorBandsOutput};
+
+mod percentage_volume_oscillator;
+pub use self::percentage_volume_oscillator::{
+    PercentageVolumeOscillator, PercentageVolumeOscillatorOutput,
+};
+
+mod mass_index;
+pub use self::mass_index::MassIndex;
+
+mod kst;
+pub use self::kst::{KnowSureThing, KnowSureThingOutput};
+
+mod elder_ray;
+pub use self::elder_ray::{ElderRay, ElderRayOutput};
+
+mod zig_zag;
+pub use self::zig_zag::{ZigZag, ZigZagPivot, ZigZagPivotKind};
+
+mod fractal;
+pub use self::fractal::{Fractal, FractalOutput};
+
+mod disparity_index;
+pub use self::disparity_index::DisparityIndex;
+
+mod schaff_trend_cycle;
+pub use self::schaff_trend_cycle::SchaffTrendCycle;
+
+mod smoothed_moving_average;
+pub use self::smoothed_moving_average::SmoothedMovingAverage;
+
+mod alligator;
+pub use self::alligator::{Alligator, AlligatorOutput};
+
+mod pivot_points;
+pub use self::pivot_points::{PivotKind, PivotPoints, PivotPointsOutput};
+
+mod moving_average;
+pub use self::moving_average::{MovingAverage, MovingAverageKind};
+
+mod high_low_range;
+pub use self::high_low_range::{HighLowRange, HighLowRangeOutput};
+
+mod typical_price;
+pub use self::typical_price::TypicalPrice;
+
+mod average_price;
+pub use self::average_price::AveragePrice;
+
+mod heikin_ashi;
+pub use self::heikin_ashi::{HeikinAshi, HeikinAshiOutput};
+
+mod stochastic;
+pub use self::stochastic::{Stochastic, StochasticOutput};
+
+mod rolling_median;
+pub use self::rolling_median::RollingMedian;
+
+mod rolling_sum;
+pub use self::rolling_sum::{RollingSum, SumSource};
+
+mod vwma;
+pub use self::vwma::VolumeWeightedMovingAverage;
+
+mod resampler;
+pub use self::resampler::Resampler;
+
+mod accelerator_oscillator;
+pub use self::accelerator_oscillator::AcceleratorOscillator;
@@ -0,0 +1,164 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::RingWindow;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Momentum (MOM)
+///
+/// The absolute price change over the last _n_ periods.
+///
+/// # Formula
+///
+/// MOM = Price<sub>t</sub> - Price<sub>t-n</sub>
+///
+/// Where:
+///
+/// * MOM - current value of Momentum indicator
+/// * P<sub>t</sub> - price at the moment
+/// * P<sub>t-n</sub> - price _n_ periods ago
+///
+/// While fewer than _n_ previous prices are available, the first observed price is used
+/// as P<sub>t-n</sub>.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods integer greater than 0
+///
+/// # Links
+///
+/// * [Momentum, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+///
+#[doc(alias = "MOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Momentum {
+    period: usize,
+    window: RingWindow,
+}
+
+impl Momentum {
+    /// # Errors
+    ///
+    /// Will return `Err` if period is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                window: RingWindow::new(period + 1),
+            }),
+        }
+    }
+}
+
+impl Period for Momentum {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for Momentum {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> crate::Num {
+        self.window.push(input);
+        let previous = self.window.lookback(self.period).unwrap();
+
+        input - previous
+    }
+}
+
+impl<T: Close> Next<&T> for Momentum {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> crate::Num {
+        self.next(input.close())
+    }
+}
+
+impl Default for Momentum {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for Momentum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MOM({})", self.period)
+    }
+}
+
+impl Reset for Momentum {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    test_indicator!(Momentum);
+
+    #[test]
+    fn test_new() {
+        assert!(Momentum::new(0).is_err());
+        assert!(Momentum::new(1).is_ok());
+        assert!(Momentum::new(100_000).is_ok());
+    }
+
+    #[test]
+    fn test_next_f64() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        assert_eq!(mom.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(round_float(mom.next(lit!(10.4))), lit!(0.4));
+        assert_eq!(round_float(mom.next(lit!(10.57))), lit!(0.57));
+        assert_eq!(round_float(mom.next(lit!(10.8))), lit!(0.8));
+        assert_eq!(round_float(mom.next(lit!(10.9))), lit!(0.5));
+        assert_eq!(round_float(mom.next(lit!(10.0))), lit!(-0.57));
+    }
+
+    #[test]
+    fn test_next_bar() {
+        fn bar(close: crate::Num) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut mom = Momentum::new(3).unwrap();
+
+        assert_eq!(mom.next(&bar(lit!(10.0))), lit!(0.0));
+        assert_eq!(round_float(mom.next(&bar(lit!(10.4)))), lit!(0.4));
+        assert_eq!(round_float(mom.next(&bar(lit!(10.57)))), lit!(0.57));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        mom.next(lit!(12.3));
+        mom.next(lit!(15.0));
+
+        mom.reset();
+
+        assert_eq!(mom.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(round_float(mom.next(lit!(10.4))), lit!(0.4));
+        assert_eq!(round_float(mom.next(lit!(10.57))), lit!(0.57));
+    }
+
+    #[test]
+    fn test_default() {
+        Momentum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mom = Momentum::new(9).unwrap();
+        assert_eq!(format!("{}", mom), "MOM(9)");
+    }
+}
@@ -1,8 +1,10 @@
-use std::cmp::Ordering;
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::cmp::Ordering;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{lit, Close, High, Low, Next, Period, Reset, Volume};
+use crate::{lit, Close, High, Low, Next, Period, Reset, TypicalPriceExt, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -44,10 +46,10 @@ pub struct MoneyFlowIndex {
     period: usize,
     index: usize,
     count: usize,
-    previous_typical_price: rust_decimal::Decimal,
-    total_positive_money_flow: rust_decimal::Decimal,
-    total_negative_money_flow: rust_decimal::Decimal,
-    deque: Box<[rust_decimal::Decimal]>,
+    previous_typical_price: crate::Num,
+    total_positive_money_flow: crate::Num,
+    total_negative_money_flow: crate::Num,
+    deque: Box<[crate::Num]>,
 }
 
 impl MoneyFlowIndex {
@@ -56,7 +58,7 @@ impl MoneyFlowIndex {
     /// Will return `Err` if period is 0
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
                 index: 0,
@@ -68,6 +70,13 @@ impl MoneyFlowIndex {
             }),
         }
     }
+
+    /// Returns the typical price `(high + low + close) / 3` of `input`, as used by the MFI.
+    ///
+    /// Same as [`TypicalPriceExt::typical_price`].
+    pub fn typical_price<T: High + Low + Close>(input: &T) -> crate::Num {
+        input.typical_price()
+    }
 }
 
 impl Period for MoneyFlowIndex {
@@ -77,10 +86,10 @@ impl Period for MoneyFlowIndex {
 }
 
 impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
-    fn next(&mut self, input: &T) -> rust_decimal::Decimal {
-        let tp = (input.close() + input.high() + input.low()) / lit!(3.0);
+    fn next(&mut self, input: &T) -> crate::Num {
+        let tp = Self::typical_price(input);
 
         self.index = if self.index + 1 < self.period {
             self.index + 1
@@ -103,18 +112,18 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
             }
         }
 
-        match tp.cmp(&self.previous_typical_price) {
-            Ordering::Greater => {
+        match tp.partial_cmp(&self.previous_typical_price) {
+            Some(Ordering::Greater) => {
                 let raw_money_flow = tp * input.volume();
                 self.total_positive_money_flow += raw_money_flow;
                 self.deque[self.index] = raw_money_flow;
             }
-            Ordering::Less => {
+            Some(Ordering::Less) => {
                 let raw_money_flow = tp * input.volume();
                 self.total_negative_money_flow += raw_money_flow;
                 self.deque[self.index] = -raw_money_flow;
             }
-            Ordering::Equal => {
+            _ => {
                 self.deque[self.index] = lit!(0.0);
             }
         }
@@ -200,6 +209,25 @@ mod tests {
         assert_eq!(round(mfi.next(&bar8)), lit!(60.87));
     }
 
+    #[test]
+    fn test_next_without_negative_flow() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+
+        let bar1 = Bar::new().high(3).low(1).close(2).volume(100);
+        let bar2 = Bar::new().high(4).low(2).close(3).volume(100);
+        let bar3 = Bar::new().high(5).low(3).close(4).volume(100);
+
+        mfi.next(&bar1);
+        assert_eq!(mfi.next(&bar2), lit!(100.0));
+        assert_eq!(mfi.next(&bar3), lit!(100.0));
+    }
+
+    #[test]
+    fn test_typical_price() {
+        let bar = Bar::new().high(12).low(6).close(lit!(10.5));
+        assert_eq!(MoneyFlowIndex::typical_price(&bar), lit!(9.5));
+    }
+
     #[test]
     fn test_reset() {
         let mut mfi = MoneyFlowIndex::new(3).unwrap();
@@ -0,0 +1,261 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{
+    ExponentialMovingAverage, SimpleMovingAverage, SmoothedMovingAverage, WeightedMovingAverage,
+};
+use crate::{Close, Current, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Type of a [`MovingAverage`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MovingAverageKind {
+    /// [Simple moving average](struct.SimpleMovingAverage.html).
+    #[default]
+    Simple,
+    /// [Exponential moving average](struct.ExponentialMovingAverage.html).
+    Exponential,
+    /// [Weighted moving average](struct.WeightedMovingAverage.html).
+    Weighted,
+    /// [Smoothed moving average](struct.SmoothedMovingAverage.html).
+    Smoothed,
+}
+
+/// A moving average whose type is chosen at runtime.
+///
+/// It dispatches to one of the moving averages of the crate, selected by a
+/// [`MovingAverageKind`]. Composite indicators use it to let the caller choose how their
+/// inputs are averaged.
+///
+/// # Parameters
+///
+/// * _kind_ - type of the moving average. Default is [`MovingAverageKind::Simple`].
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{MovingAverage, MovingAverageKind};
+/// use ta::{lit, Next};
+///
+/// let mut ma = MovingAverage::new(MovingAverageKind::Exponential, 3).unwrap();
+/// assert_eq!(ma.next(lit!(2.0)), lit!(2.0));
+/// assert_eq!(ma.next(lit!(5.0)), lit!(3.5));
+/// assert_eq!(format!("{}", ma), "EMA(3)");
+/// ```
+#[doc(alias = "MA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MovingAverage {
+    inner: Inner,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum Inner {
+    Simple(SimpleMovingAverage),
+    Exponential(ExponentialMovingAverage),
+    Weighted(WeightedMovingAverage),
+    Smoothed(SmoothedMovingAverage),
+}
+
+impl MovingAverage {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(kind: MovingAverageKind, period: usize) -> Result<Self> {
+        let inner = match kind {
+            MovingAverageKind::Simple => Inner::Simple(SimpleMovingAverage::new(period)?),
+            MovingAverageKind::Exponential => {
+                Inner::Exponential(ExponentialMovingAverage::new(period)?)
+            }
+            MovingAverageKind::Weighted => Inner::Weighted(WeightedMovingAverage::new(period)?),
+            MovingAverageKind::Smoothed => Inner::Smoothed(SmoothedMovingAverage::new(period)?),
+        };
+
+        Ok(Self { inner })
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> MovingAverageKind {
+        match self.inner {
+            Inner::Simple(_) => MovingAverageKind::Simple,
+            Inner::Exponential(_) => MovingAverageKind::Exponential,
+            Inner::Weighted(_) => MovingAverageKind::Weighted,
+            Inner::Smoothed(_) => MovingAverageKind::Smoothed,
+        }
+    }
+}
+
+impl Period for MovingAverage {
+    fn period(&self) -> usize {
+        match &self.inner {
+            Inner::Simple(ma) => ma.period(),
+            Inner::Exponential(ma) => ma.period(),
+            Inner::Weighted(ma) => ma.period(),
+            Inner::Smoothed(ma) => ma.period(),
+        }
+    }
+}
+
+impl Next<crate::Num> for MovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        match &mut self.inner {
+            Inner::Simple(ma) => ma.next(input),
+            Inner::Exponential(ma) => ma.next(input),
+            Inner::Weighted(ma) => ma.next(input),
+            Inner::Smoothed(ma) => ma.next(input),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for MovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for MovingAverage {
+    type Output = crate::Num;
+
+    fn current(&self) -> Option<crate::Num> {
+        match &self.inner {
+            Inner::Simple(ma) => ma.current(),
+            Inner::Exponential(ma) => ma.current(),
+            Inner::Weighted(ma) => ma.current(),
+            Inner::Smoothed(ma) => ma.current(),
+        }
+    }
+}
+
+impl Reset for MovingAverage {
+    fn reset(&mut self) {
+        match &mut self.inner {
+            Inner::Simple(ma) => ma.reset(),
+            Inner::Exponential(ma) => ma.reset(),
+            Inner::Weighted(ma) => ma.reset(),
+            Inner::Smoothed(ma) => ma.reset(),
+        }
+    }
+}
+
+impl Default for MovingAverage {
+    fn default() -> Self {
+        Self::new(MovingAverageKind::default(), 9).unwrap()
+    }
+}
+
+impl fmt::Display for MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.inner {
+            Inner::Simple(ma) => ma.fmt(f),
+            Inner::Exponential(ma) => ma.fmt(f),
+            Inner::Weighted(ma) => ma.fmt(f),
+            Inner::Smoothed(ma) => ma.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    test_indicator!(MovingAverage);
+
+    const KINDS: [MovingAverageKind; 4] = [
+        MovingAverageKind::Simple,
+        MovingAverageKind::Exponential,
+        MovingAverageKind::Weighted,
+        MovingAverageKind::Smoothed,
+    ];
+
+    #[test]
+    fn test_new() {
+        for kind in KINDS {
+            assert!(MovingAverage::new(kind, 0).is_err());
+            let ma = MovingAverage::new(kind, 4).unwrap();
+            assert_eq!(ma.kind(), kind);
+            assert_eq!(ma.period(), 4);
+        }
+    }
+
+    #[test]
+    fn test_next() {
+        let inputs = [lit!(2.0), lit!(5.0), lit!(1.0), lit!(6.25), lit!(3.0)];
+
+        let mut ma = MovingAverage::new(MovingAverageKind::Simple, 3).unwrap();
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        for input in inputs {
+            assert_eq!(ma.next(input), sma.next(input));
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageKind::Exponential, 3).unwrap();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        for input in inputs {
+            assert_eq!(ma.next(input), ema.next(input));
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageKind::Weighted, 3).unwrap();
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        for input in inputs {
+            assert_eq!(ma.next(input), wma.next(input));
+        }
+
+        let mut ma = MovingAverage::new(MovingAverageKind::Smoothed, 3).unwrap();
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+        for input in inputs {
+            assert_eq!(ma.next(&Bar::new().close(input)), smma.next(input));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        for kind in KINDS {
+            let mut ma = MovingAverage::new(kind, 3).unwrap();
+
+            ma.next(lit!(2.0));
+            ma.next(lit!(8.0));
+
+            ma.reset();
+            assert_eq!(ma.next(lit!(5.0)), lit!(5.0));
+        }
+    }
+
+    #[test]
+    fn test_current() {
+        for kind in KINDS {
+            let mut ma = MovingAverage::new(kind, 3).unwrap();
+            assert_eq!(ma.current(), None);
+
+            for input in [lit!(2.0), lit!(5.0), lit!(1.0), lit!(6.25)] {
+                let value = ma.next(input);
+                assert_eq!(ma.current(), Some(value), "{}", ma);
+            }
+
+            ma.reset();
+            assert_eq!(ma.current(), None);
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(MovingAverage::default().kind(), MovingAverageKind::Simple);
+    }
+
+    #[test]
+    fn test_display() {
+        let displays = ["SMA(5)", "EMA(5)", "WMA(5)", "SMMA(5)"];
+        for (kind, display) in KINDS.iter().zip(displays) {
+            let ma = MovingAverage::new(*kind, 5).unwrap();
+            assert_eq!(format!("{}", ma), display);
+        }
+    }
+}
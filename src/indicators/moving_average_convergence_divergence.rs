@@ -1,8 +1,9 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::stream::CrossSignal;
+use crate::{lit, Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -35,6 +36,8 @@ pub struct MovingAverageConvergenceDivergence {
     fast_ema: Ema,
     slow_ema: Ema,
     signal_ema: Ema,
+    histogram: Option<crate::Num>,
+    prev_histogram: Option<crate::Num>,
 }
 
 impl MovingAverageConvergenceDivergence {
@@ -46,27 +49,60 @@ impl MovingAverageConvergenceDivergence {
             fast_ema: Ema::new(fast_period)?,
             slow_ema: Ema::new(slow_period)?,
             signal_ema: Ema::new(signal_period)?,
+            histogram: None,
+            prev_histogram: None,
+        })
+    }
+
+    /// Classifies the latest histogram value by its sign and by its change compared to the
+    /// previous histogram value.
+    ///
+    /// A histogram of 0 counts as above zero, and an unchanged histogram counts as falling.
+    ///
+    /// Returns `None` until two values have been calculated.
+    #[must_use]
+    pub fn histogram_state(&self) -> Option<HistogramState> {
+        let histogram = self.histogram?;
+        let prev_histogram = self.prev_histogram?;
+        let rising = histogram > prev_histogram;
+
+        Some(match (histogram >= lit!(0.0), rising) {
+            (true, true) => HistogramState::RisingAboveZero,
+            (true, false) => HistogramState::FallingAboveZero,
+            (false, false) => HistogramState::FallingBelowZero,
+            (false, true) => HistogramState::RisingBelowZero,
         })
     }
 }
 
+/// State of the MACD histogram, as used to color it on charts.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistogramState {
+    RisingAboveZero,
+    FallingAboveZero,
+    FallingBelowZero,
+    RisingBelowZero,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct MovingAverageConvergenceDivergenceOutput {
-    pub macd: rust_decimal::Decimal,
-    pub signal: rust_decimal::Decimal,
-    pub histogram: rust_decimal::Decimal,
+    pub macd: crate::Num,
+    pub signal: crate::Num,
+    pub histogram: crate::Num,
 }
 
-impl From<MovingAverageConvergenceDivergenceOutput> for (rust_decimal::Decimal,rust_decimal::Decimal,rust_decimal::Decimal) {
+impl From<MovingAverageConvergenceDivergenceOutput> for (crate::Num, crate::Num, crate::Num) {
     fn from(mo: MovingAverageConvergenceDivergenceOutput) -> Self {
         (mo.macd, mo.signal, mo.histogram)
     }
 }
 
-impl Next<rust_decimal::Decimal> for MovingAverageConvergenceDivergence {
+impl Next<crate::Num> for MovingAverageConvergenceDivergence {
     type Output = MovingAverageConvergenceDivergenceOutput;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let fast_val = self.fast_ema.next(input);
         let slow_val = self.slow_ema.next(input);
 
@@ -74,6 +110,8 @@ impl Next<rust_decimal::Decimal> for MovingAverageConvergenceDivergence {
         let signal = self.signal_ema.next(macd);
         let histogram = macd - signal;
 
+        self.prev_histogram = self.histogram.replace(histogram);
+
         MovingAverageConvergenceDivergenceOutput {
             macd,
             signal,
@@ -95,6 +133,8 @@ impl Reset for MovingAverageConvergenceDivergence {
         self.fast_ema.reset();
         self.slow_ema.reset();
         self.signal_ema.reset();
+        self.histogram = None;
+        self.prev_histogram = None;
     }
 }
 
@@ -116,16 +156,100 @@ impl fmt::Display for MovingAverageConvergenceDivergence {
     }
 }
 
+/// Turns successive MACD outputs into crossover events.
+///
+/// Reports two kinds of crossings, which may happen on the same output:
+///
+/// * signal line - the MACD line crosses its signal line, i.e. the histogram changes its sign
+/// * zero line - the MACD line itself changes its sign
+///
+/// As in [`MovingAverageConvergenceDivergence::histogram_state`], a value of 0 counts as
+/// above zero. The first output never reports a crossing.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{MacdSignal, MovingAverageConvergenceDivergence};
+/// use ta::stream::CrossSignal;
+/// use ta::{lit, Next};
+///
+/// let mut macd = MovingAverageConvergenceDivergence::new(3, 6, 4).unwrap();
+/// let mut signal = MacdSignal::new();
+///
+/// for price in [lit!(2.0), lit!(3.0), lit!(7.0), lit!(6.7)] {
+///     signal.next(&macd.next(price));
+/// }
+/// let events = signal.next(&macd.next(lit!(5.0)));
+/// assert_eq!(events.signal_line, CrossSignal::CrossDown);
+/// assert_eq!(events.zero_line, CrossSignal::None);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct MacdSignal {
+    prev_macd: Option<crate::Num>,
+    prev_histogram: Option<crate::Num>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MacdSignalOutput {
+    /// Crossing of the MACD line and the signal line.
+    pub signal_line: CrossSignal,
+    /// Crossing of the MACD line and zero.
+    pub zero_line: CrossSignal,
+}
+
+impl MacdSignal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn zero_cross(prev: Option<crate::Num>, current: crate::Num) -> CrossSignal {
+    match prev {
+        Some(prev) if prev < lit!(0.0) && current >= lit!(0.0) => CrossSignal::CrossUp,
+        Some(prev) if prev >= lit!(0.0) && current < lit!(0.0) => CrossSignal::CrossDown,
+        _ => CrossSignal::None,
+    }
+}
+
+impl Next<&MovingAverageConvergenceDivergenceOutput> for MacdSignal {
+    type Output = MacdSignalOutput;
+
+    fn next(&mut self, input: &MovingAverageConvergenceDivergenceOutput) -> Self::Output {
+        let out = MacdSignalOutput {
+            signal_line: zero_cross(self.prev_histogram, input.histogram),
+            zero_line: zero_cross(self.prev_macd, input.macd),
+        };
+        self.prev_macd = Some(input.macd);
+        self.prev_histogram = Some(input.histogram);
+
+        out
+    }
+}
+
+impl Reset for MacdSignal {
+    fn reset(&mut self) {
+        self.prev_macd = None;
+        self.prev_histogram = None;
+    }
+}
+
+impl fmt::Display for MacdSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MACD_SIGNAL")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::lit;
     use crate::test_helper::*;
     type Macd = MovingAverageConvergenceDivergence;
 
     test_indicator!(Macd);
 
-    fn round(nums: (rust_decimal::Decimal,rust_decimal::Decimal,rust_decimal::Decimal)) -> (rust_decimal::Decimal,rust_decimal::Decimal,rust_decimal::Decimal) {
+    fn round(nums: (crate::Num, crate::Num, crate::Num)) -> (crate::Num, crate::Num, crate::Num) {
         let n0 = (nums.0 * lit!(100.0)).round() / lit!(100.0);
         let n1 = (nums.1 * lit!(100.0)).round() / lit!(100.0);
         let n2 = (nums.2 * lit!(100.0)).round() / lit!(100.0);
@@ -170,6 +294,63 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_output() {
+        let out = MovingAverageConvergenceDivergenceOutput {
+            macd: lit!(1.5),
+            signal: lit!(0.5),
+            histogram: lit!(1.0),
+        };
+
+        let json = serde_json::to_string(&out).unwrap();
+        // decimals are serialized as strings, so they stay exact
+        #[cfg(not(feature = "f64-backend"))]
+        assert_eq!(json, r#"{"macd":"1.5","signal":"0.5","histogram":"1.0"}"#);
+
+        let deserialized: MovingAverageConvergenceDivergenceOutput =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, out);
+
+        let mut macd = Macd::new(3, 6, 4).unwrap();
+        macd.next(lit!(2.0));
+        let out = macd.next(lit!(3.0));
+        let json = serde_json::to_string(&out).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MovingAverageConvergenceDivergenceOutput>(&json).unwrap(),
+            out
+        );
+    }
+
+    #[test]
+    fn test_histogram_state() {
+        use HistogramState::*;
+
+        let mut macd = Macd::new(3, 6, 4).unwrap();
+        assert_eq!(macd.histogram_state(), None);
+
+        // no previous histogram on the first bar
+        macd.next(lit!(2.0));
+        assert_eq!(macd.histogram_state(), None);
+
+        let steps = [
+            (lit!(3.0), RisingAboveZero),  // 0.13
+            (lit!(7.0), RisingAboveZero),  // 0.62
+            (lit!(6.7), FallingAboveZero), // 0.43
+            (lit!(5.0), FallingBelowZero), // -0.04
+            (lit!(4.0), FallingBelowZero), // -0.32
+            (lit!(4.5), RisingBelowZero),  // -0.25
+            (lit!(6.0), RisingAboveZero),  // 0.01
+        ];
+        for (input, state) in steps {
+            macd.next(input);
+            assert_eq!(macd.histogram_state(), Some(state));
+        }
+
+        macd.reset();
+        assert_eq!(macd.histogram_state(), None);
+    }
+
     #[test]
     fn test_reset() {
         let mut macd = Macd::new(3, 6, 4).unwrap();
@@ -205,4 +386,67 @@ mod tests {
         let indicator = Macd::new(13, 30, 10).unwrap();
         assert_eq!(format!("{}", indicator), "MACD(13, 30, 10)");
     }
+
+    #[test]
+    fn test_macd_signal() {
+        use CrossSignal::*;
+
+        let mut macd = Macd::new(3, 6, 4).unwrap();
+        let mut signal = MacdSignal::new();
+
+        // (input, signal line crossing, zero line crossing), histogram and MACD in comments
+        let steps = [
+            (lit!(2.0), None, None),           // 0, 0
+            (lit!(3.0), None, None),           // 0.13, 0.21
+            (lit!(7.0), None, None),           // 0.62, 1.12
+            (lit!(6.7), None, None),           // 0.43, 1.22
+            (lit!(5.0), CrossDown, None),      // -0.04, 0.71
+            (lit!(4.0), None, None),           // -0.32, 0.22
+            (lit!(4.5), None, None),           // -0.25, 0.12
+            (lit!(6.0), CrossUp, None),        // 0.01, 0.39
+            (lit!(3.0), CrossDown, CrossDown), // -0.36, -0.22
+            (lit!(1.0), None, None),           // -0.58, -0.83
+            (lit!(1.5), None, None),           // -0.34, -0.82
+            (lit!(4.0), CrossUp, None),        // 0.19, -0.17
+            (lit!(6.0), None, CrossUp),        // 0.52, 0.52
+        ];
+        for (input, signal_line, zero_line) in steps {
+            let out = signal.next(&macd.next(input));
+            assert_eq!(
+                out,
+                MacdSignalOutput {
+                    signal_line,
+                    zero_line
+                },
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_macd_signal_reset() {
+        let mut signal = MacdSignal::new();
+        let out = |macd, histogram| MovingAverageConvergenceDivergenceOutput {
+            macd,
+            signal: macd - histogram,
+            histogram,
+        };
+
+        signal.next(&out(lit!(1.0), lit!(1.0)));
+        assert_eq!(
+            signal.next(&out(lit!(-1.0), lit!(-1.0))),
+            MacdSignalOutput {
+                signal_line: CrossSignal::CrossDown,
+                zero_line: CrossSignal::CrossDown,
+            }
+        );
+
+        signal.reset();
+        assert_eq!(
+            signal.next(&out(lit!(1.0), lit!(1.0))),
+            MacdSignalOutput::default()
+        );
+        assert_eq!(format!("{}", signal), "MACD_SIGNAL");
+    }
 }
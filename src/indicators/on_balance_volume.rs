@@ -1,7 +1,7 @@
-use std::cmp::Ordering;
-use std::fmt;
+use core::cmp::Ordering;
+use core::fmt;
 
-use crate::{lit, Close, Next, Reset, Volume};
+use crate::{lit, Close, Next, Reset, ResetTo, Volume};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -31,13 +31,12 @@ use serde::{Deserialize, Serialize};
 /// * [On Balance Volume, Wikipedia](https://en.wikipedia.org/wiki/On-balance_volume)
 /// * [On Balance Volume, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:on_balance_volume_obv)
 ///
-
 #[doc(alias = "OBV")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct OnBalanceVolume {
-    obv: rust_decimal::Decimal,
-    prev_close: rust_decimal::Decimal,
+    obv: crate::Num,
+    prev_close: crate::Num,
 }
 
 impl OnBalanceVolume {
@@ -51,17 +50,17 @@ impl OnBalanceVolume {
 }
 
 impl<T: Close + Volume> Next<&T> for OnBalanceVolume {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
-    fn next(&mut self, input: &T) -> rust_decimal::Decimal {
-        match input.close().cmp(&self.prev_close) {
-            Ordering::Greater => {
+    fn next(&mut self, input: &T) -> crate::Num {
+        match input.close().partial_cmp(&self.prev_close) {
+            Some(Ordering::Greater) => {
                 self.obv += input.volume();
             }
-            Ordering::Less => {
+            Some(Ordering::Less) => {
                 self.obv -= input.volume();
             }
-            Ordering::Equal => {}
+            _ => {}
         }
 
         self.prev_close = input.close();
@@ -69,6 +68,14 @@ impl<T: Close + Volume> Next<&T> for OnBalanceVolume {
     }
 }
 
+impl ResetTo for OnBalanceVolume {
+    /// Sets the accumulated volume to `seed`. The previous close is kept, so the next bar
+    /// is still compared against the last seen close.
+    fn reset_to(&mut self, seed: crate::Num) {
+        self.obv = seed;
+    }
+}
+
 impl Default for OnBalanceVolume {
     fn default() -> Self {
         Self::new()
@@ -133,6 +140,17 @@ mod tests {
         assert_eq!(obv.next(&bar3), lit!(6000.0));
     }
 
+    #[test]
+    fn test_reset_to() {
+        let mut obv = OnBalanceVolume::new();
+
+        obv.next(&Bar::new().close(4).volume(1000));
+
+        obv.reset_to(lit!(100.0));
+        assert_eq!(obv.next(&Bar::new().close(5).volume(50)), lit!(150.0));
+        assert_eq!(obv.next(&Bar::new().close(3).volume(20)), lit!(130.0));
+    }
+
     #[test]
     fn test_default() {
         OnBalanceVolume::default();
@@ -0,0 +1,288 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{lit, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parabolic SAR (stop and reverse).
+///
+/// Developed by J. Welles Wilder, the parabolic SAR trails the price as the trend extends
+/// over time. The indicator is below prices when they are rising and above prices when
+/// they are falling. When the price crosses the SAR, the trend reverses and the SAR jumps
+/// to the extreme point of the previous trend.
+///
+/// # Formula
+///
+/// SAR<sub>t+1</sub> = SAR<sub>t</sub> + α * (EP - SAR<sub>t</sub>)
+///
+/// Where:
+///
+/// * _EP_ - extreme point, the highest high of an uptrend or the lowest low of a downtrend
+/// * _α_ - acceleration factor. It starts at _start_ and increases by _step_ every time a
+///   new extreme point is recorded, up to _max_.
+///
+/// The SAR never penetrates the ranges of the prior two periods: in an uptrend it is
+/// capped at the lowest low of the previous two bars, in a downtrend it is floored at
+/// their highest high. When the price crosses the SAR the trend reverses, the SAR is set to
+/// the previous extreme point and the acceleration factor is reset to _start_.
+///
+/// The first bar is assumed to start an uptrend.
+///
+/// # Parameters
+///
+/// * _start_ - initial acceleration factor (greater than 0). Default is 0.02.
+/// * _step_ - acceleration factor increment (greater than 0). Default is 0.02.
+/// * _max_ - maximum acceleration factor (not less than _start_). Default is 0.2.
+///
+/// # Links
+///
+/// * [Parabolic SAR, Wikipedia](https://en.wikipedia.org/wiki/Parabolic_SAR)
+///
+#[doc(alias = "PSAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ParabolicSAR {
+    start: crate::Num,
+    step: crate::Num,
+    max: crate::Num,
+    trend: Trend,
+    sar: crate::Num,
+    extreme_point: crate::Num,
+    acceleration_factor: crate::Num,
+    prev_bars: [Option<(crate::Num, crate::Num)>; 2],
+}
+
+/// Direction of the trend tracked by [`ParabolicSAR`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Up,
+    Down,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParabolicSAROutput {
+    pub sar: crate::Num,
+    pub trend: Trend,
+}
+
+impl ParabolicSAR {
+    /// # Errors
+    ///
+    /// Will return `Err` if `start` or `step` is not positive, or `max` is less than `start`
+    pub fn new(start: crate::Num, step: crate::Num, max: crate::Num) -> Result<Self> {
+        if start <= lit!(0.0) || step <= lit!(0.0) || max < start {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            start,
+            step,
+            max,
+            trend: Trend::Up,
+            sar: lit!(0.0),
+            extreme_point: lit!(0.0),
+            acceleration_factor: start,
+            prev_bars: [None, None],
+        })
+    }
+
+    #[must_use]
+    pub fn start(&self) -> crate::Num {
+        self.start
+    }
+
+    #[must_use]
+    pub fn step(&self) -> crate::Num {
+        self.step
+    }
+
+    #[must_use]
+    pub fn max(&self) -> crate::Num {
+        self.max
+    }
+}
+
+impl<T: High + Low> Next<&T> for ParabolicSAR {
+    type Output = ParabolicSAROutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+
+        if self.prev_bars[0].is_none() {
+            self.trend = Trend::Up;
+            self.sar = low;
+            self.extreme_point = high;
+            self.acceleration_factor = self.start;
+            self.prev_bars[0] = Some((high, low));
+
+            return Self::Output {
+                sar: self.sar,
+                trend: self.trend,
+            };
+        }
+
+        let mut sar = self.sar + self.acceleration_factor * (self.extreme_point - self.sar);
+
+        match self.trend {
+            Trend::Up => {
+                for &(_, prev_low) in self.prev_bars.iter().flatten() {
+                    sar = sar.min(prev_low);
+                }
+
+                if low < sar {
+                    self.trend = Trend::Down;
+                    sar = self.extreme_point;
+                    self.extreme_point = low;
+                    self.acceleration_factor = self.start;
+                } else if high > self.extreme_point {
+                    self.extreme_point = high;
+                    self.acceleration_factor = (self.acceleration_factor + self.step).min(self.max);
+                }
+            }
+            Trend::Down => {
+                for &(prev_high, _) in self.prev_bars.iter().flatten() {
+                    sar = sar.max(prev_high);
+                }
+
+                if high > sar {
+                    self.trend = Trend::Up;
+                    sar = self.extreme_point;
+                    self.extreme_point = high;
+                    self.acceleration_factor = self.start;
+                } else if low < self.extreme_point {
+                    self.extreme_point = low;
+                    self.acceleration_factor = (self.acceleration_factor + self.step).min(self.max);
+                }
+            }
+        }
+
+        self.sar = sar;
+        self.prev_bars[1] = self.prev_bars[0];
+        self.prev_bars[0] = Some((high, low));
+
+        Self::Output {
+            sar: self.sar,
+            trend: self.trend,
+        }
+    }
+}
+
+impl Reset for ParabolicSAR {
+    fn reset(&mut self) {
+        self.trend = Trend::Up;
+        self.sar = lit!(0.0);
+        self.extreme_point = lit!(0.0);
+        self.acceleration_factor = self.start;
+        self.prev_bars = [None, None];
+    }
+}
+
+impl Default for ParabolicSAR {
+    fn default() -> Self {
+        Self::new(lit!(0.02), lit!(0.02), lit!(0.2)).unwrap()
+    }
+}
+
+impl fmt::Display for ParabolicSAR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PSAR({}, {}, {})", self.start, self.step, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Psar = ParabolicSAR;
+
+    #[test]
+    fn test_new() {
+        assert!(Psar::new(lit!(0.0), lit!(0.02), lit!(0.2)).is_err());
+        assert!(Psar::new(lit!(0.02), lit!(0.0), lit!(0.2)).is_err());
+        assert!(Psar::new(lit!(0.02), lit!(0.02), lit!(0.01)).is_err());
+        assert!(Psar::new(lit!(0.02), lit!(0.02), lit!(0.2)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut psar = Psar::default();
+
+        let bar1 = Bar::new().high(10).low(9);
+        let bar2 = Bar::new().high(11).low(10);
+        let bar3 = Bar::new().high(12).low(11);
+        let bar4 = Bar::new().high(13).low(12);
+
+        let out = psar.next(&bar1);
+        assert_eq!(out.sar, lit!(9.0));
+        assert_eq!(out.trend, Trend::Up);
+
+        // raw SAR of 9.02 and 9.08 would penetrate the low of bar1
+        assert_eq!(psar.next(&bar2).sar, lit!(9.0));
+        assert_eq!(psar.next(&bar3).sar, lit!(9.0));
+
+        // bar1 is out of the window: 9 + 0.06 * (12 - 9)
+        let out = psar.next(&bar4);
+        assert_eq!(out.sar, lit!(9.18));
+        assert_eq!(out.trend, Trend::Up);
+    }
+
+    #[test]
+    fn test_reversal() {
+        let mut psar = Psar::default();
+
+        psar.next(&Bar::new().high(10).low(9));
+        psar.next(&Bar::new().high(11).low(10));
+        psar.next(&Bar::new().high(12).low(11));
+        psar.next(&Bar::new().high(13).low(12));
+
+        // low breaks the SAR: reverse to the extreme point of the uptrend
+        let out = psar.next(&Bar::new().high(lit!(12.5)).low(8));
+        assert_eq!(out.sar, lit!(13.0));
+        assert_eq!(out.trend, Trend::Down);
+
+        // raw SAR of 12.9 would penetrate the high of two bars ago
+        let out = psar.next(&Bar::new().high(9).low(7));
+        assert_eq!(out.sar, lit!(13.0));
+        assert_eq!(out.trend, Trend::Down);
+
+        // 13 + 0.04 * (7 - 13) = 12.76
+        let out = psar.next(&Bar::new().high(8).low(7));
+        assert_eq!(out.sar, lit!(12.76));
+        assert_eq!(out.trend, Trend::Down);
+
+        // high breaks the SAR: reverse to the extreme point of the downtrend
+        let out = psar.next(&Bar::new().high(14).low(10));
+        assert_eq!(out.sar, lit!(7.0));
+        assert_eq!(out.trend, Trend::Up);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut psar = Psar::default();
+
+        let bar1 = Bar::new().high(10).low(9);
+        let bar2 = Bar::new().high(11).low(10);
+
+        psar.next(&bar1);
+        psar.next(&bar2);
+        psar.next(&Bar::new().high(5).low(4));
+
+        psar.reset();
+        assert_eq!(psar.next(&bar1).sar, lit!(9.0));
+        assert_eq!(psar.next(&bar2).sar, lit!(9.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Psar::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Psar::default();
+        assert_eq!(format!("{}", indicator), "PSAR(0.02, 0.02, 0.2)");
+    }
+}
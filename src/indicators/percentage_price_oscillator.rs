@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
@@ -50,23 +50,24 @@ impl PercentagePriceOscillator {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub struct PercentagePriceOscillatorOutput {
-    pub ppo: rust_decimal::Decimal,
-    pub signal: rust_decimal::Decimal,
-    pub histogram: rust_decimal::Decimal,
+    pub ppo: crate::Num,
+    pub signal: crate::Num,
+    pub histogram: crate::Num,
 }
 
-impl From<PercentagePriceOscillatorOutput> for (rust_decimal::Decimal,rust_decimal::Decimal,rust_decimal::Decimal) {
+impl From<PercentagePriceOscillatorOutput> for (crate::Num, crate::Num, crate::Num) {
     fn from(po: PercentagePriceOscillatorOutput) -> Self {
         (po.ppo, po.signal, po.histogram)
     }
 }
 
-impl Next<rust_decimal::Decimal> for PercentagePriceOscillator {
+impl Next<crate::Num> for PercentagePriceOscillator {
     type Output = PercentagePriceOscillatorOutput;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let fast_val = self.fast_ema.next(input);
         let slow_val = self.slow_ema.next(input);
 
@@ -122,16 +123,15 @@ mod tests {
     use crate::test_helper::*;
     type Ppo = PercentagePriceOscillator;
 
-    use rust_decimal::Decimal;
+    use crate::{num, Num};
 
     test_indicator!(Ppo);
 
-    fn round(nums: (Decimal, Decimal, Decimal)) -> (Decimal, Decimal, Decimal) {
-        use rust_decimal::prelude::RoundingStrategy::MidpointAwayFromZero;
+    fn round(nums: (Num, Num, Num)) -> (Num, Num, Num) {
         (
-            nums.0.round_dp_with_strategy(2, MidpointAwayFromZero),
-            nums.1.round_dp_with_strategy(2, MidpointAwayFromZero),
-            nums.2.round_dp_with_strategy(2, MidpointAwayFromZero),
+            num::round(nums.0, 2),
+            num::round(nums.1, 2),
+            num::round(nums.2, 2),
         )
     }
 
@@ -0,0 +1,233 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{lit, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percentage Volume Oscillator (PVO).
+///
+/// The PVO is the [Percentage Price Oscillator](struct.PercentagePriceOscillator.html)
+/// applied to volume instead of price. It shows whether the volume is expanding (positive)
+/// or contracting (negative) relative to its longer term average.
+///
+/// # Formula
+///
+/// PVO = (EMA<sub>fast</sub>(volume) - EMA<sub>slow</sub>(volume)) / EMA<sub>slow</sub>(volume) * 100
+///
+/// signal = EMA<sub>signal</sub>(PVO)
+///
+/// histogram = PVO - signal
+///
+/// While the slow EMA is 0, PVO is 0.
+///
+/// # Parameters
+///
+/// * _`fast_period`_ - period for the fast EMA. Default is 12.
+/// * _`slow_period`_ - period for the slow EMA. Default is 26.
+/// * _`signal_period`_ - period for the signal EMA. Default is 9.
+///
+/// # Links
+///
+/// * [Percentage Volume Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:percentage_volume_oscillator_pvo)
+///
+#[doc(alias = "PVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentageVolumeOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+}
+
+impl PercentageVolumeOscillator {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods are 0
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        Ok(PercentageVolumeOscillator {
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            signal_ema: Ema::new(signal_period)?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentageVolumeOscillatorOutput {
+    pub pvo: crate::Num,
+    pub signal: crate::Num,
+    pub histogram: crate::Num,
+}
+
+impl From<PercentageVolumeOscillatorOutput> for (crate::Num, crate::Num, crate::Num) {
+    fn from(po: PercentageVolumeOscillatorOutput) -> Self {
+        (po.pvo, po.signal, po.histogram)
+    }
+}
+
+impl Next<crate::Num> for PercentageVolumeOscillator {
+    type Output = PercentageVolumeOscillatorOutput;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let fast_val = self.fast_ema.next(input);
+        let slow_val = self.slow_ema.next(input);
+
+        let pvo = if slow_val == lit!(0.0) {
+            lit!(0.0)
+        } else {
+            (fast_val - slow_val) / slow_val * lit!(100.0)
+        };
+        let signal = self.signal_ema.next(pvo);
+        let histogram = pvo - signal;
+
+        PercentageVolumeOscillatorOutput {
+            pvo,
+            signal,
+            histogram,
+        }
+    }
+}
+
+impl<T: Volume> Next<&T> for PercentageVolumeOscillator {
+    type Output = PercentageVolumeOscillatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.volume())
+    }
+}
+
+impl Reset for PercentageVolumeOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+    }
+}
+
+impl Default for PercentageVolumeOscillator {
+    fn default() -> Self {
+        Self::new(12, 26, 9).unwrap()
+    }
+}
+
+impl fmt::Display for PercentageVolumeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PVO({},{},{})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    type Pvo = PercentageVolumeOscillator;
+
+    use crate::{num, Num};
+
+    test_indicator!(Pvo);
+
+    fn round(nums: (Num, Num, Num)) -> (Num, Num, Num) {
+        (
+            num::round(round_float(nums.0), 2),
+            num::round(round_float(nums.1), 2),
+            num::round(round_float(nums.2), 2),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Pvo::new(0, 1, 1).is_err());
+        assert!(Pvo::new(1, 0, 1).is_err());
+        assert!(Pvo::new(1, 1, 0).is_err());
+        assert!(Pvo::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(2000)).into()),
+            (lit!(0.0), lit!(0.0), lit!(0.0))
+        );
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(3000)).into()),
+            (lit!(9.38), lit!(3.75), lit!(5.63))
+        );
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(4200)).into()),
+            (lit!(18.26), lit!(9.56), lit!(8.71))
+        );
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(8000)).into()),
+            (lit!(31.70), lit!(18.41), lit!(13.29))
+        );
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(6700)).into()),
+            (lit!(23.94), lit!(20.63), lit!(3.32))
+        );
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(6500)).into()),
+            (lit!(16.98), lit!(19.17), lit!(-2.19))
+        );
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(0)).into()),
+            (lit!(0.0), lit!(0.0), lit!(0.0))
+        );
+        assert_eq!(
+            round(pvo.next(&Bar::new().volume(0)).into()),
+            (lit!(0.0), lit!(0.0), lit!(0.0))
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvo = Pvo::new(3, 6, 4).unwrap();
+
+        assert_eq!(
+            round(pvo.next(lit!(2.0)).into()),
+            (lit!(0.0), lit!(0.0), lit!(0.0))
+        );
+        assert_eq!(
+            round(pvo.next(lit!(3.0)).into()),
+            (lit!(9.38), lit!(3.75), lit!(5.63))
+        );
+
+        pvo.reset();
+
+        assert_eq!(
+            round(pvo.next(lit!(2.0)).into()),
+            (lit!(0.0), lit!(0.0), lit!(0.0))
+        );
+        assert_eq!(
+            round(pvo.next(lit!(3.0)).into()),
+            (lit!(9.38), lit!(3.75), lit!(5.63))
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        Pvo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Pvo::default();
+        assert_eq!(format!("{}", indicator), "PVO(12,26,9)");
+    }
+}
@@ -0,0 +1,253 @@
+use core::fmt;
+
+use crate::{lit, Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Pivot points.
+///
+/// Pivot points are support and resistance levels derived from the high, low and close of
+/// a completed period, usually the previous day. The levels are fixed for the whole next
+/// period.
+///
+/// The indicator is fed with the completed period and returns the levels for the period
+/// that follows it, so the output is one bar behind the input: feed the daily bar of
+/// yesterday to get the levels for today. It keeps no state between bars.
+///
+/// # Formula
+///
+/// Where _H_, _L_ and _C_ are the high, low and close of the completed period and
+/// _R_ = _H_ - _L_:
+///
+/// * Classic - P = (H + L + C) / 3, R1 = 2P - L, S1 = 2P - H, R2 = P + R, S2 = P - R,
+///   R3 = H + 2(P - L), S3 = L - 2(H - P)
+/// * Fibonacci - P = (H + L + C) / 3, R1/S1 = P ± 0.382R, R2/S2 = P ± 0.618R,
+///   R3/S3 = P ± R
+/// * Camarilla - P = (H + L + C) / 3, R1/S1 = C ± 1.1R / 12, R2/S2 = C ± 1.1R / 6,
+///   R3/S3 = C ± 1.1R / 4
+/// * Woodie - P = (H + L + 2C) / 4, the levels are computed as for Classic
+///
+/// # Parameters
+///
+/// * _kind_ - set of formulas, see [`PivotKind`]. Default is [`PivotKind::Classic`].
+///
+/// # Links
+///
+/// * [Pivot point, Wikipedia](https://en.wikipedia.org/wiki/Pivot_point_(technical_analysis))
+///
+#[doc(alias = "PP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PivotPoints {
+    kind: PivotKind,
+}
+
+/// Set of formulas used by [`PivotPoints`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotKind {
+    #[default]
+    Classic,
+    Fibonacci,
+    Camarilla,
+    Woodie,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotPointsOutput {
+    pub pivot: crate::Num,
+    pub r1: crate::Num,
+    pub r2: crate::Num,
+    pub r3: crate::Num,
+    pub s1: crate::Num,
+    pub s2: crate::Num,
+    pub s3: crate::Num,
+}
+
+impl PivotPoints {
+    #[must_use]
+    pub fn new(kind: PivotKind) -> Self {
+        Self { kind }
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> PivotKind {
+        self.kind
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for PivotPoints {
+    type Output = PivotPointsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        let range = high - low;
+
+        match self.kind {
+            PivotKind::Classic | PivotKind::Woodie => {
+                let pivot = if self.kind == PivotKind::Woodie {
+                    (high + low + lit!(2.0) * close) / lit!(4.0)
+                } else {
+                    (high + low + close) / lit!(3.0)
+                };
+
+                PivotPointsOutput {
+                    pivot,
+                    r1: lit!(2.0) * pivot - low,
+                    r2: pivot + range,
+                    r3: high + lit!(2.0) * (pivot - low),
+                    s1: lit!(2.0) * pivot - high,
+                    s2: pivot - range,
+                    s3: low - lit!(2.0) * (high - pivot),
+                }
+            }
+            PivotKind::Fibonacci => {
+                let pivot = (high + low + close) / lit!(3.0);
+
+                PivotPointsOutput {
+                    pivot,
+                    r1: pivot + lit!(0.382) * range,
+                    r2: pivot + lit!(0.618) * range,
+                    r3: pivot + range,
+                    s1: pivot - lit!(0.382) * range,
+                    s2: pivot - lit!(0.618) * range,
+                    s3: pivot - range,
+                }
+            }
+            PivotKind::Camarilla => {
+                let pivot = (high + low + close) / lit!(3.0);
+                let range = lit!(1.1) * range;
+
+                PivotPointsOutput {
+                    pivot,
+                    r1: close + range / lit!(12.0),
+                    r2: close + range / lit!(6.0),
+                    r3: close + range / lit!(4.0),
+                    s1: close - range / lit!(12.0),
+                    s2: close - range / lit!(6.0),
+                    s3: close - range / lit!(4.0),
+                }
+            }
+        }
+    }
+}
+
+impl Reset for PivotPoints {
+    fn reset(&mut self) {}
+}
+
+impl Default for PivotPoints {
+    fn default() -> Self {
+        Self::new(PivotKind::default())
+    }
+}
+
+impl fmt::Display for PivotPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            PivotKind::Classic => "CLASSIC",
+            PivotKind::Fibonacci => "FIBONACCI",
+            PivotKind::Camarilla => "CAMARILLA",
+            PivotKind::Woodie => "WOODIE",
+        };
+        write!(f, "PP({})", kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn levels(kind: PivotKind) -> PivotPointsOutput {
+        let mut pp = PivotPoints::new(kind);
+        let out = pp.next(&Bar::new().high(110).low(100).close(108));
+
+        PivotPointsOutput {
+            pivot: round(out.pivot),
+            r1: round(out.r1),
+            r2: round(out.r2),
+            r3: round(out.r3),
+            s1: round(out.s1),
+            s2: round(out.s2),
+            s3: round(out.s3),
+        }
+    }
+
+    #[test]
+    fn test_next_classic() {
+        let out = levels(PivotKind::Classic);
+        assert_eq!(out.pivot, lit!(106.0));
+        assert_eq!(out.r1, lit!(112.0));
+        assert_eq!(out.r2, lit!(116.0));
+        assert_eq!(out.r3, lit!(122.0));
+        assert_eq!(out.s1, lit!(102.0));
+        assert_eq!(out.s2, lit!(96.0));
+        assert_eq!(out.s3, lit!(92.0));
+    }
+
+    #[test]
+    fn test_next_fibonacci() {
+        let out = levels(PivotKind::Fibonacci);
+        assert_eq!(out.pivot, lit!(106.0));
+        assert_eq!(out.r1, lit!(109.82));
+        assert_eq!(out.r2, lit!(112.18));
+        assert_eq!(out.r3, lit!(116.0));
+        assert_eq!(out.s1, lit!(102.18));
+        assert_eq!(out.s2, lit!(99.82));
+        assert_eq!(out.s3, lit!(96.0));
+    }
+
+    #[test]
+    fn test_next_camarilla() {
+        let out = levels(PivotKind::Camarilla);
+        assert_eq!(out.pivot, lit!(106.0));
+        assert_eq!(out.r1, lit!(108.917));
+        assert_eq!(out.r2, lit!(109.833));
+        assert_eq!(out.r3, lit!(110.75));
+        assert_eq!(out.s1, lit!(107.083));
+        assert_eq!(out.s2, lit!(106.167));
+        assert_eq!(out.s3, lit!(105.25));
+    }
+
+    #[test]
+    fn test_next_woodie() {
+        let out = levels(PivotKind::Woodie);
+        assert_eq!(out.pivot, lit!(106.5));
+        assert_eq!(out.r1, lit!(113.0));
+        assert_eq!(out.r2, lit!(116.5));
+        assert_eq!(out.r3, lit!(123.0));
+        assert_eq!(out.s1, lit!(103.0));
+        assert_eq!(out.s2, lit!(96.5));
+        assert_eq!(out.s3, lit!(93.0));
+    }
+
+    #[test]
+    fn test_next_levels_ordered() {
+        let kinds = [
+            PivotKind::Classic,
+            PivotKind::Fibonacci,
+            PivotKind::Camarilla,
+            PivotKind::Woodie,
+        ];
+        for kind in kinds {
+            let out = levels(kind);
+            assert!(out.s3 < out.s2 && out.s2 < out.s1);
+            assert!(out.r1 < out.r2 && out.r2 < out.r3);
+            assert!(out.s1 < out.r1);
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(PivotPoints::default().kind(), PivotKind::Classic);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", PivotPoints::default()), "PP(CLASSIC)");
+        let indicator = PivotPoints::new(PivotKind::Camarilla);
+        assert_eq!(format!("{}", indicator), "PP(CAMARILLA)");
+    }
+}
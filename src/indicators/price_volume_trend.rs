@@ -0,0 +1,130 @@
+use core::fmt;
+
+use crate::{lit, Close, Next, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Price Volume Trend (PVT).
+///
+/// A cumulative volume based indicator similar to [OBV](struct.OnBalanceVolume.html), but
+/// instead of adding or subtracting the whole volume it adds the volume weighted by the
+/// relative change of the close.
+///
+/// # Formula
+///
+/// PVT<sub>t</sub> = PVT<sub>t-1</sub> + volume * (close - close<sub>t-1</sub>) / close<sub>t-1</sub>
+///
+/// The first bar contributes 0 since there is no previous close, as does a bar following
+/// a close of 0.
+///
+/// # Links
+///
+/// * [Volume-price trend, Wikipedia](https://en.wikipedia.org/wiki/Volume%E2%80%93price_trend)
+///
+#[doc(alias = "PVT")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PriceVolumeTrend {
+    pvt: crate::Num,
+    prev_close: Option<crate::Num>,
+}
+
+impl PriceVolumeTrend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pvt: lit!(0.0),
+            prev_close: None,
+        }
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for PriceVolumeTrend {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+
+        if let Some(prev_close) = self.prev_close.replace(close) {
+            if prev_close != lit!(0.0) {
+                self.pvt += input.volume() * (close - prev_close) / prev_close;
+            }
+        }
+
+        self.pvt
+    }
+}
+
+impl Reset for PriceVolumeTrend {
+    fn reset(&mut self) {
+        self.pvt = lit!(0.0);
+        self.prev_close = None;
+    }
+}
+
+impl Default for PriceVolumeTrend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PriceVolumeTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PVT")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        let bar1 = Bar::new().close(10).volume(1000);
+        // +10%
+        let bar2 = Bar::new().close(11).volume(2000);
+        // -50%
+        let bar3 = Bar::new().close(lit!(5.5)).volume(100);
+        // unchanged
+        let bar4 = Bar::new().close(lit!(5.5)).volume(5000);
+
+        assert_eq!(pvt.next(&bar1), lit!(0.0));
+        assert_eq!(pvt.next(&bar2), lit!(200.0));
+        assert_eq!(pvt.next(&bar3), lit!(150.0));
+        assert_eq!(pvt.next(&bar4), lit!(150.0));
+    }
+
+    #[test]
+    fn test_next_zero_prev_close() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        pvt.next(&Bar::new().close(0).volume(1000));
+        assert_eq!(pvt.next(&Bar::new().close(5).volume(1000)), lit!(0.0));
+        assert_eq!(pvt.next(&Bar::new().close(10).volume(1000)), lit!(1000.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut pvt = PriceVolumeTrend::new();
+
+        pvt.next(&Bar::new().close(10).volume(1000));
+        pvt.next(&Bar::new().close(11).volume(2000));
+
+        pvt.reset();
+        assert_eq!(pvt.next(&Bar::new().close(11).volume(2000)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        PriceVolumeTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = PriceVolumeTrend::new();
+        assert_eq!(format!("{}", indicator), "PVT");
+    }
+}
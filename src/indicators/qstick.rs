@@ -0,0 +1,136 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Qstick.
+///
+/// Developed by Tushar Chande, Qstick is the moving average of the candle body size. It is
+/// positive when the closes are mostly above the opens, and negative when the closes are
+/// mostly below them.
+///
+/// # Formula
+///
+/// Qstick = SMA(close - open, _period_)
+///
+/// Where:
+///
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 8.
+///
+/// # Links
+///
+/// * [Qstick, Investopedia](https://www.investopedia.com/terms/q/qstick.asp)
+///
+#[doc(alias = "QSTICK")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Qstick {
+    sma: Sma,
+}
+
+impl Qstick {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for Qstick {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: Open + Close> Next<&T> for Qstick {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.sma.next(input.close() - input.open())
+    }
+}
+
+impl Reset for Qstick {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for Qstick {
+    fn default() -> Self {
+        Self::new(8).unwrap()
+    }
+}
+
+impl fmt::Display for Qstick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "QSTICK({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Qstick::new(0).is_err());
+        assert!(Qstick::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut qstick = Qstick::new(2).unwrap();
+
+        assert_eq!(qstick.next(&Bar::new().open(10).close(12)), lit!(2.0));
+        assert_eq!(qstick.next(&Bar::new().open(12).close(8)), lit!(-1.0));
+        assert_eq!(qstick.next(&Bar::new().open(8).close(8)), lit!(-2.0));
+    }
+
+    #[test]
+    fn test_next_bullish_streak() {
+        let mut qstick = Qstick::new(3).unwrap();
+
+        qstick.next(&Bar::new().open(15).close(10));
+        qstick.next(&Bar::new().open(14).close(10));
+
+        // positive as soon as the bearish candles have left the window
+        for open in 10..20 {
+            let value = qstick.next(&Bar::new().open(open).close(open + 1));
+            assert_eq!(value > lit!(0.0), open >= 12);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut qstick = Qstick::new(2).unwrap();
+
+        qstick.next(&Bar::new().open(10).close(12));
+
+        qstick.reset();
+        assert_eq!(qstick.next(&Bar::new().open(12).close(8)), lit!(-4.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Qstick::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Qstick::default();
+        assert_eq!(format!("{}", indicator), "QSTICK(8)");
+    }
+}
@@ -1,6 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
+use crate::helpers::RingWindow;
+use crate::indicators::Strict;
 use crate::{lit, Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -28,9 +30,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct RateOfChange {
     period: usize,
-    index: usize,
-    count: usize,
-    deque: Box<[rust_decimal::Decimal]>,
+    window: RingWindow,
 }
 
 impl RateOfChange {
@@ -39,15 +39,23 @@ impl RateOfChange {
     /// Will return `Err` if period is 0
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
-                index: 0,
-                count: 0,
-                deque: vec![lit!(0.0); period].into_boxed_slice(),
+                window: RingWindow::new(period + 1),
             }),
         }
     }
+
+    /// Creates a [strict](struct.Strict.html) version of the indicator, which returns
+    /// `Err(TaError::InsufficientData { .. })` until the price `period` bars ago is known, i.e. `period + 1` inputs were seen.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_strict(period: usize) -> Result<Strict<Self>> {
+        Ok(Strict::new(Self::new(period)?, period + 1))
+    }
 }
 
 impl Period for RateOfChange {
@@ -56,36 +64,21 @@ impl Period for RateOfChange {
     }
 }
 
-impl Next<rust_decimal::Decimal> for RateOfChange {
-    type Output = rust_decimal::Decimal;
-
-    fn next(&mut self, input: rust_decimal::Decimal) -> rust_decimal::Decimal {
-        let previous = if self.count > self.period {
-            self.deque[self.index]
-        } else {
-            self.count += 1;
-            if self.count == 1 {
-                input
-            } else {
-                self.deque[0]
-            }
-        };
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
+impl Next<crate::Num> for RateOfChange {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> crate::Num {
+        self.window.push(input);
+        let previous = self.window.lookback(self.period).unwrap();
 
         (input - previous) / previous * lit!(100.0)
     }
 }
 
 impl<T: Close> Next<&T> for RateOfChange {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
-    fn next(&mut self, input: &T) -> rust_decimal::Decimal {
+    fn next(&mut self, input: &T) -> crate::Num {
         self.next(input.close())
     }
 }
@@ -104,11 +97,7 @@ impl fmt::Display for RateOfChange {
 
 impl Reset for RateOfChange {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
-        for i in 0..self.period {
-            self.deque[i] = lit!(0.0);
-        }
+        self.window.clear();
     }
 }
 
@@ -140,7 +129,7 @@ mod tests {
 
     #[test]
     fn test_next_bar() {
-        fn bar(close: rust_decimal::Decimal) -> Bar {
+        fn bar(close: crate::Num) -> Bar {
             Bar::new().close(close)
         }
 
@@ -151,6 +140,39 @@ mod tests {
         assert_eq!(round(roc.next(&bar(lit!(10.57)))), lit!(5.7));
     }
 
+    #[test]
+    fn test_strict() {
+        assert!(RateOfChange::new_strict(0).is_err());
+
+        // the price 2 bars ago is known from the 3rd input on
+        let mut roc = RateOfChange::new_strict(2).unwrap();
+        assert_eq!(
+            roc.next(lit!(10.0)),
+            Err(TaError::InsufficientData { have: 1, need: 3 })
+        );
+        assert_eq!(
+            roc.next(lit!(11.0)),
+            Err(TaError::InsufficientData { have: 2, need: 3 })
+        );
+        assert_eq!(roc.next(lit!(12.0)), Ok(lit!(20.0)));
+
+        roc.reset();
+        assert!(roc.next(lit!(10.0)).is_err());
+    }
+
+    #[test]
+    fn test_strict_error_count() {
+        for period in 1..10 {
+            let mut indicator = RateOfChange::new_strict(period).unwrap();
+            let errors = (0..3 * period)
+                .map(|i| indicator.next(crate::int!(10 + i % 4)))
+                .take_while(Result::is_err)
+                .count();
+            // the price `period` bars ago is known from input `period + 1` on
+            assert_eq!(errors, period);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut roc = RateOfChange::new(3).unwrap();
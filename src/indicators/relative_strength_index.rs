@@ -1,8 +1,8 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{lit, Close, Next, Period, Reset};
+use crate::indicators::{MovingAverage, MovingAverageKind};
+use crate::{lit, Close, Current, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,13 +20,16 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Formula
 ///
-/// RSI<sub>t</sub> = EMA<sub>Ut</sub> * 100 / (EMA<sub>Ut</sub> + EMA<sub>Dt</sub>)
+/// RSI<sub>t</sub> = MA<sub>Ut</sub> * 100 / (MA<sub>Ut</sub> + MA<sub>Dt</sub>)
 ///
 /// Where:
 ///
 /// * RSI<sub>t</sub> - value of RSI indicator in a moment of time _t_
-/// * EMA<sub>Ut</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of up periods in a moment of time _t_
-/// * EMA<sub>Dt</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of down periods in a moment of time _t_
+/// * MA<sub>Ut</sub> - moving average of up periods in a moment of time _t_
+/// * MA<sub>Dt</sub> - moving average of down periods in a moment of time _t_
+///
+/// The moving average is Wilder's [SMMA](struct.SmoothedMovingAverage.html) by default, see
+/// [`RsiSmoothing`]. If both averages are 0 the RSI is 50.
 ///
 /// If current period has value higher than previous period, than:
 ///
@@ -50,6 +53,9 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 14.
+/// * _scale_ - range of the output, see [`RsiScale`]. Default is [`RsiScale::ZeroHundred`].
+/// * _smoothing_ - moving average of the up and down periods, see [`RsiSmoothing`]. Default is
+///   [`RsiSmoothing::Wilder`].
 ///
 /// # Links
 /// * [Relative strength index (Wikipedia)](https://en.wikipedia.org/wiki/Relative_strength_index)
@@ -60,25 +66,116 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct RelativeStrengthIndex {
     period: usize,
-    up_ema_indicator: Ema,
-    down_ema_indicator: Ema,
-    prev_val: rust_decimal::Decimal,
+    scale: RsiScale,
+    smoothing: RsiSmoothing,
+    up_ma: MovingAverage,
+    down_ma: MovingAverage,
+    prev_val: crate::Num,
     is_new: bool,
 }
 
+/// Output range of [`RelativeStrengthIndex`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RsiScale {
+    /// Classic RSI in the range of 0..100.
+    #[default]
+    ZeroHundred,
+    /// RSI shifted by -50 into the range of -50..50.
+    Centered,
+    /// RSI divided by 100 into the range of 0..1.
+    ZeroOne,
+}
+
+/// Moving average of the up and down periods of [`RelativeStrengthIndex`].
+///
+/// Charting platforms disagree on it, so RSI values only match when the smoothing does.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RsiSmoothing {
+    /// Wilder's [SMMA](struct.SmoothedMovingAverage.html), the original definition.
+    #[default]
+    Wilder,
+    /// [EMA](struct.ExponentialMovingAverage.html), which reacts faster than the SMMA of the
+    /// same period.
+    Exponential,
+}
+
+impl RsiSmoothing {
+    fn kind(self) -> MovingAverageKind {
+        match self {
+            RsiSmoothing::Wilder => MovingAverageKind::Smoothed,
+            RsiSmoothing::Exponential => MovingAverageKind::Exponential,
+        }
+    }
+}
+
 impl RelativeStrengthIndex {
     /// # Errors
     ///
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_scale(period, RsiScale::ZeroHundred)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_scale(period: usize, scale: RsiScale) -> Result<Self> {
+        Self::new_with_options(period, scale, RsiSmoothing::Wilder)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_smoothing(period: usize, smoothing: RsiSmoothing) -> Result<Self> {
+        Self::new_with_options(period, RsiScale::ZeroHundred, smoothing)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_options(
+        period: usize,
+        scale: RsiScale,
+        smoothing: RsiSmoothing,
+    ) -> Result<Self> {
         Ok(Self {
             period,
-            up_ema_indicator: Ema::new(period)?,
-            down_ema_indicator: Ema::new(period)?,
+            scale,
+            smoothing,
+            up_ma: MovingAverage::new(smoothing.kind(), period)?,
+            down_ma: MovingAverage::new(smoothing.kind(), period)?,
             prev_val: lit!(0.0),
             is_new: true,
         })
     }
+
+    #[must_use]
+    pub fn scale(&self) -> RsiScale {
+        self.scale
+    }
+
+    #[must_use]
+    pub fn smoothing(&self) -> RsiSmoothing {
+        self.smoothing
+    }
+
+    fn rsi(&self, up_ma: crate::Num, down_ma: crate::Num) -> crate::Num {
+        let sum = up_ma + down_ma;
+        // the averages decay to 0 on a long flat series
+        let rsi = if sum > lit!(0.0) {
+            lit!(100.0) * up_ma / sum
+        } else {
+            lit!(50.0)
+        };
+
+        match self.scale {
+            RsiScale::ZeroHundred => rsi,
+            RsiScale::Centered => rsi - lit!(50.0),
+            RsiScale::ZeroOne => rsi / lit!(100.0),
+        }
+    }
 }
 
 impl Period for RelativeStrengthIndex {
@@ -87,10 +184,10 @@ impl Period for RelativeStrengthIndex {
     }
 }
 
-impl Next<rust_decimal::Decimal> for RelativeStrengthIndex {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for RelativeStrengthIndex {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let mut up = lit!(0.0);
         let mut down = lit!(0.0);
 
@@ -106,26 +203,36 @@ impl Next<rust_decimal::Decimal> for RelativeStrengthIndex {
         }
 
         self.prev_val = input;
-        let up_ema = self.up_ema_indicator.next(up);
-        let down_ema = self.down_ema_indicator.next(down);
-        lit!(100.0) * up_ema / (up_ema + down_ema)
+        let up_ma = self.up_ma.next(up);
+        let down_ma = self.down_ma.next(down);
+        self.rsi(up_ma, down_ma)
     }
 }
 
 impl<T: Close> Next<&T> for RelativeStrengthIndex {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
     }
 }
 
+impl Current for RelativeStrengthIndex {
+    type Output = crate::Num;
+
+    fn current(&self) -> Option<crate::Num> {
+        let up_ma = self.up_ma.current()?;
+        let down_ma = self.down_ma.current()?;
+        Some(self.rsi(up_ma, down_ma))
+    }
+}
+
 impl Reset for RelativeStrengthIndex {
     fn reset(&mut self) {
         self.is_new = true;
         self.prev_val = lit!(0.0);
-        self.up_ema_indicator.reset();
-        self.down_ema_indicator.reset();
+        self.up_ma.reset();
+        self.down_ma.reset();
     }
 }
 
@@ -135,9 +242,32 @@ impl Default for RelativeStrengthIndex {
     }
 }
 
+impl fmt::Display for RsiScale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RsiScale::ZeroHundred => write!(f, "ZERO_HUNDRED"),
+            RsiScale::Centered => write!(f, "CENTERED"),
+            RsiScale::ZeroOne => write!(f, "ZERO_ONE"),
+        }
+    }
+}
+
+impl fmt::Display for RsiSmoothing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RsiSmoothing::Wilder => write!(f, "SMMA"),
+            RsiSmoothing::Exponential => write!(f, "EMA"),
+        }
+    }
+}
+
 impl fmt::Display for RelativeStrengthIndex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "RSI({})", self.period)
+        match (self.smoothing, self.scale) {
+            (RsiSmoothing::Wilder, RsiScale::ZeroHundred) => write!(f, "RSI({})", self.period),
+            (smoothing, RsiScale::ZeroHundred) => write!(f, "RSI({}, {})", self.period, smoothing),
+            (smoothing, scale) => write!(f, "RSI({}, {}, {})", self.period, smoothing, scale),
+        }
     }
 }
 
@@ -159,10 +289,109 @@ mod tests {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
         assert_eq!(rsi.next(lit!(10.0)), lit!(50.0));
         assert_eq!(rsi.next(lit!(10.5)).round(), lit!(86.0));
+        assert_eq!(rsi.next(lit!(10.0)).round(), lit!(50.0));
+        assert_eq!(rsi.next(lit!(9.5)).round(), lit!(31.0));
+    }
+
+    #[test]
+    fn test_next_exponential() {
+        let mut rsi =
+            RelativeStrengthIndex::new_with_smoothing(3, RsiSmoothing::Exponential).unwrap();
+        assert_eq!(rsi.next(lit!(10.0)), lit!(50.0));
+        assert_eq!(rsi.next(lit!(10.5)).round(), lit!(86.0));
         assert_eq!(rsi.next(lit!(10.0)).round(), lit!(35.0));
         assert_eq!(rsi.next(lit!(9.5)).round(), lit!(16.0));
     }
 
+    #[test]
+    fn test_smoothing_diverges_on_trend() {
+        let mut wilder = RelativeStrengthIndex::new(5).unwrap();
+        let mut exponential =
+            RelativeStrengthIndex::new_with_smoothing(5, RsiSmoothing::Exponential).unwrap();
+
+        // a rising series with a pullback every third period
+        let mut price = lit!(100.0);
+        for i in 0..30 {
+            price += if i % 3 == 2 { lit!(-1.0) } else { lit!(2.0) };
+            let wilder = wilder.next(price);
+            let exponential = exponential.next(price);
+
+            assert!(wilder >= lit!(0.0) && wilder <= lit!(100.0));
+            assert!(exponential >= lit!(0.0) && exponential <= lit!(100.0));
+            if i >= 5 {
+                assert!(wilder != exponential);
+            }
+        }
+
+        // the faster EMA drops further on a pullback
+        let wilder = wilder.next(price - lit!(3.0));
+        let exponential = exponential.next(price - lit!(3.0));
+        assert!(exponential < wilder);
+    }
+
+    #[test]
+    fn test_equal_gains_and_losses() {
+        for smoothing in [RsiSmoothing::Wilder, RsiSmoothing::Exponential] {
+            let mut rsi = RelativeStrengthIndex::new_with_smoothing(3, smoothing).unwrap();
+
+            // long enough for the averages to decay to 0
+            for _ in 0..2000 {
+                assert_eq!(round(rsi.next(lit!(10.0))), lit!(50.0));
+            }
+        }
+
+        // SMMA averages all inputs while warming up
+        let mut rsi = RelativeStrengthIndex::new(4).unwrap();
+        rsi.next(lit!(10.0));
+        rsi.next(lit!(11.0));
+        assert_eq!(rsi.next(lit!(10.0)), lit!(50.0));
+    }
+
+    #[test]
+    fn test_next_with_scale() {
+        let inputs = [lit!(10.0), lit!(10.5), lit!(10.0), lit!(9.5), lit!(11.0)];
+
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        let mut centered = RelativeStrengthIndex::new_with_scale(3, RsiScale::Centered).unwrap();
+        let mut zero_one = RelativeStrengthIndex::new_with_scale(3, RsiScale::ZeroOne).unwrap();
+
+        assert_eq!(centered.next(inputs[0]), lit!(0.0));
+        assert_eq!(zero_one.next(inputs[0]), lit!(0.5));
+        rsi.next(inputs[0]);
+
+        for &input in &inputs[1..] {
+            let value = rsi.next(input);
+            assert_eq!(centered.next(input), value - lit!(50.0));
+            assert_eq!(zero_one.next(input), value / lit!(100.0));
+        }
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let rsi = RelativeStrengthIndex::default();
+        assert_eq!(rsi.smoothing(), RsiSmoothing::Wilder);
+
+        let rsi = RelativeStrengthIndex::new_with_options(
+            14,
+            RsiScale::Centered,
+            RsiSmoothing::Exponential,
+        )
+        .unwrap();
+        assert_eq!(rsi.scale(), RsiScale::Centered);
+        assert_eq!(rsi.smoothing(), RsiSmoothing::Exponential);
+        assert!(RelativeStrengthIndex::new_with_smoothing(0, RsiSmoothing::Exponential).is_err());
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(
+            RelativeStrengthIndex::default().scale(),
+            RsiScale::ZeroHundred
+        );
+        let rsi = RelativeStrengthIndex::new_with_scale(14, RsiScale::ZeroOne).unwrap();
+        assert_eq!(rsi.scale(), RsiScale::ZeroOne);
+    }
+
     #[test]
     fn test_reset() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
@@ -174,6 +403,20 @@ mod tests {
         assert_eq!(rsi.next(lit!(10.5)).round(), lit!(86.0));
     }
 
+    #[test]
+    fn test_current() {
+        let mut rsi = RelativeStrengthIndex::new_with_scale(3, RsiScale::Centered).unwrap();
+        assert_eq!(rsi.current(), None);
+
+        for input in [lit!(10.0), lit!(10.5), lit!(10.0), lit!(9.5)] {
+            let value = rsi.next(input);
+            assert_eq!(rsi.current(), Some(value));
+        }
+
+        rsi.reset();
+        assert_eq!(rsi.current(), None);
+    }
+
     #[test]
     fn test_default() {
         RelativeStrengthIndex::default();
@@ -183,5 +426,9 @@ mod tests {
     fn test_display() {
         let rsi = RelativeStrengthIndex::new(16).unwrap();
         assert_eq!(format!("{}", rsi), "RSI(16)");
+        let rsi = RelativeStrengthIndex::new_with_smoothing(16, RsiSmoothing::Exponential).unwrap();
+        assert_eq!(format!("{}", rsi), "RSI(16, EMA)");
+        let rsi = RelativeStrengthIndex::new_with_scale(16, RsiScale::Centered).unwrap();
+        assert_eq!(format!("{}", rsi), "RSI(16, SMMA, CENTERED)");
     }
 }
@@ -0,0 +1,243 @@
+use core::fmt;
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+
+use crate::errors::{Result, TaError};
+use crate::{Candle, Close, High, Low, Next, Open, Reset, Volume};
+
+/// Aggregates bars into candles of a higher timeframe, e.g. 1-minute bars into 5-minute
+/// candles.
+///
+/// Every bar falls into the bucket of _duration_ that contains its timestamp, buckets are
+/// aligned to the Unix epoch, so 5-minute buckets start at :00, :05, :10 and so on. A bucket
+/// is emitted as soon as a bar of a later bucket arrives, so the bars must be fed in the
+/// order of their timestamps. Buckets without bars are skipped, see
+/// [`GapFiller`](crate::stream::GapFiller) to fill them.
+///
+/// # Formula
+///
+/// * time - the start of the bucket
+/// * open - the open of the first bar
+/// * high - the highest high
+/// * low - the lowest low
+/// * close - the close of the last bar
+/// * volume - the sum of the volumes
+///
+/// # Parameters
+///
+/// * _duration_ - length of the buckets (greater than 0).
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use ta::indicators::Resampler;
+/// use ta::{lit, Candle, Close, Next};
+///
+/// let mut resampler = Resampler::new(Duration::minutes(5)).unwrap();
+/// let bar = |minute, close| {
+///     let time = Utc.with_ymd_and_hms(2024, 1, 10, 9, minute, 0).unwrap();
+///     Candle::from_f64(time, close, close, close, close, 100.0).unwrap()
+/// };
+///
+/// assert_eq!(resampler.next(&bar(0, 10.0)), None);
+/// assert_eq!(resampler.next(&bar(4, 12.0)), None);
+///
+/// // the first bar of 09:05 completes the 09:00 candle
+/// let candle = resampler.next(&bar(5, 11.0)).unwrap();
+/// assert_eq!(candle.close(), lit!(12.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    duration: Duration,
+    bucket: Option<Bucket>,
+}
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    time: DateTime<Utc>,
+    open: crate::Num,
+    high: crate::Num,
+    low: crate::Num,
+    close: crate::Num,
+    volume: crate::Num,
+}
+
+impl Bucket {
+    fn candle(&self) -> Candle {
+        Candle::builder()
+            .time(self.time)
+            .open(self.open)
+            .high(self.high)
+            .low(self.low)
+            .close(self.close)
+            .volume(self.volume)
+            .build_unchecked()
+    }
+}
+
+impl Resampler {
+    /// # Errors
+    ///
+    /// Will return `Err` if `duration` is not greater than 0
+    pub fn new(duration: Duration) -> Result<Self> {
+        if duration <= Duration::zero() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            duration,
+            bucket: None,
+        })
+    }
+
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Emits the bucket that is still being filled and starts over, e.g. at the end of the
+    /// data. Returns `None` if no bars were fed since the last emitted candle.
+    pub fn flush(&mut self) -> Option<Candle> {
+        self.bucket.take().map(|bucket| bucket.candle())
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<(DateTime<Utc>, &T)> for Resampler {
+    type Output = Option<Candle>;
+
+    fn next(&mut self, (time, input): (DateTime<Utc>, &T)) -> Self::Output {
+        // can only fail for timestamps near the limits of DateTime
+        let start = time.duration_trunc(self.duration).unwrap_or(time);
+
+        match &mut self.bucket {
+            Some(bucket) if bucket.time == start => {
+                bucket.high = bucket.high.max(input.high());
+                bucket.low = bucket.low.min(input.low());
+                bucket.close = input.close();
+                bucket.volume += input.volume();
+                None
+            }
+            _ => {
+                let completed = self.flush();
+                self.bucket = Some(Bucket {
+                    time: start,
+                    open: input.open(),
+                    high: input.high(),
+                    low: input.low(),
+                    close: input.close(),
+                    volume: input.volume(),
+                });
+                completed
+            }
+        }
+    }
+}
+
+impl Next<&Candle> for Resampler {
+    type Output = Option<Candle>;
+
+    fn next(&mut self, input: &Candle) -> Self::Output {
+        self.next((input.datetime(), input))
+    }
+}
+
+impl Reset for Resampler {
+    fn reset(&mut self) {
+        self.bucket = None;
+    }
+}
+
+impl fmt::Display for Resampler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RESAMPLE({})", self.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lit;
+    use crate::test_helper::*;
+    use chrono::TimeZone;
+
+    fn time(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 10, hour, minute, 0).unwrap()
+    }
+
+    fn candle(
+        time: DateTime<Utc>,
+        (open, high, low, close, volume): (f64, f64, f64, f64, f64),
+    ) -> Candle {
+        Candle::from_f64(time, open, high, low, close, volume).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Resampler::new(Duration::zero()).is_err());
+        assert!(Resampler::new(Duration::minutes(-5)).is_err());
+        assert!(Resampler::new(Duration::minutes(5)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut resampler = Resampler::new(Duration::minutes(5)).unwrap();
+
+        let bars = [
+            (time(9, 2), (10.0, 11.0, 9.5, 10.5, 100.0)),
+            (time(9, 3), (10.5, 12.0, 10.0, 11.5, 150.0)),
+            (time(9, 4), (11.5, 11.75, 9.0, 9.25, 50.0)),
+        ];
+        for (time, values) in bars {
+            assert_eq!(resampler.next(&candle(time, values)), None);
+        }
+
+        // crossing the boundary emits the 09:00 bucket
+        let out = resampler.next(&candle(time(9, 5), (9.0, 9.5, 8.5, 9.0, 10.0)));
+        assert_eq!(
+            out,
+            Some(candle(time(9, 0), (10.0, 12.0, 9.0, 9.25, 300.0)))
+        );
+
+        // a gap skips the empty buckets
+        let out = resampler.next(&candle(time(9, 21), (9.0, 9.0, 9.0, 9.0, 5.0)));
+        assert_eq!(out, Some(candle(time(9, 5), (9.0, 9.5, 8.5, 9.0, 10.0))));
+
+        assert_eq!(
+            resampler.flush(),
+            Some(candle(time(9, 20), (9.0, 9.0, 9.0, 9.0, 5.0)))
+        );
+        assert_eq!(resampler.flush(), None);
+    }
+
+    #[test]
+    fn test_next_with_timestamp() {
+        let mut resampler = Resampler::new(Duration::hours(1)).unwrap();
+        let bar = Bar::new().open(2).high(4).low(1).close(3).volume(10);
+
+        assert_eq!(resampler.next((time(9, 0), &bar)), None);
+        assert_eq!(resampler.next((time(9, 59), &bar)), None);
+
+        let out = resampler.next((time(10, 0), &bar)).unwrap();
+        assert_eq!(out.datetime(), time(9, 0));
+        assert_eq!(Volume::volume(&out), lit!(20.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut resampler = Resampler::new(Duration::minutes(5)).unwrap();
+        resampler.next(&candle(time(9, 0), (1.0, 1.0, 1.0, 1.0, 1.0)));
+
+        resampler.reset();
+        assert_eq!(resampler.flush(), None);
+        assert_eq!(
+            resampler.next(&candle(time(9, 1), (2.0, 2.0, 2.0, 2.0, 1.0))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let resampler = Resampler::new(Duration::minutes(5)).unwrap();
+        assert_eq!(format!("{}", resampler), "RESAMPLE(PT300S)");
+    }
+}
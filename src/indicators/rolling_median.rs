@@ -0,0 +1,222 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::RingWindow;
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rolling median over a given time frame.
+///
+/// The values of the window are kept sorted next to the window itself, so each call of
+/// `next` costs two binary searches and a move of at most _period_ values, instead of a sort
+/// of the whole window.
+///
+/// # Formula
+///
+/// The middle value of the last _period_ inputs sorted by value. If the window holds an even
+/// number of inputs, the mean of the two middle values. Until _period_ inputs were seen, the
+/// median of all inputs so far.
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingMedian;
+/// use ta::{lit, Next};
+///
+/// let mut median = RollingMedian::new(3).unwrap();
+///
+/// assert_eq!(median.next(lit!(4.0)), lit!(4.0));
+/// assert_eq!(median.next(lit!(1.0)), lit!(2.5));
+/// assert_eq!(median.next(lit!(9.0)), lit!(4.0));
+/// assert_eq!(median.next(lit!(2.0)), lit!(2.0));
+/// ```
+#[doc(alias = "MEDIAN")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingMedian {
+    period: usize,
+    window: RingWindow,
+    // the values of the window in ascending order
+    sorted: Vec<crate::Num>,
+}
+
+impl RollingMedian {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                window: RingWindow::new(period),
+                sorted: Vec::with_capacity(period),
+            }),
+        }
+    }
+}
+
+impl Period for RollingMedian {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for RollingMedian {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        if let Some(evicted) = self.window.push(input) {
+            // any of several equal values can be removed
+            let index = self.sorted.partition_point(|&value| value < evicted);
+            self.sorted.remove(index);
+        }
+        let index = self.sorted.partition_point(|&value| value < input);
+        self.sorted.insert(index, input);
+
+        let middle = self.sorted.len() / 2;
+        if self.sorted.len() % 2 == 1 {
+            self.sorted[middle]
+        } else {
+            (self.sorted[middle - 1] + self.sorted[middle]) / lit!(2.0)
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for RollingMedian {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for RollingMedian {
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sorted.clear();
+    }
+}
+
+impl Default for RollingMedian {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for RollingMedian {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    test_indicator!(RollingMedian);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingMedian::new(0).is_err());
+        assert!(RollingMedian::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut median = RollingMedian::new(4).unwrap();
+
+        assert_eq!(median.next(lit!(5.0)), lit!(5.0));
+        assert_eq!(median.next(lit!(3.0)), lit!(4.0));
+        assert_eq!(median.next(lit!(8.0)), lit!(5.0));
+        // 3, 5, 8, 10
+        assert_eq!(median.next(lit!(10.0)), lit!(6.5));
+        // 5 leaves: 3, 8, 10, 1
+        assert_eq!(median.next(lit!(1.0)), lit!(5.5));
+        // 3 leaves: 8, 10, 1, 7
+        assert_eq!(median.next(lit!(7.0)), lit!(7.5));
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut median = RollingMedian::new(3).unwrap();
+
+        assert_eq!(median.next(lit!(2.0)), lit!(2.0));
+        assert_eq!(median.next(lit!(2.0)), lit!(2.0));
+        assert_eq!(median.next(lit!(5.0)), lit!(2.0));
+        // one of the 2s leaves: 2, 5, 5
+        assert_eq!(median.next(lit!(5.0)), lit!(5.0));
+        // the other 2 leaves: 5, 5, 1
+        assert_eq!(median.next(lit!(1.0)), lit!(5.0));
+        // 5, 1, 1
+        assert_eq!(median.next(lit!(1.0)), lit!(1.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut median = RollingMedian::new(2).unwrap();
+
+        assert_eq!(median.next(&Bar::new().close(4)), lit!(4.0));
+        assert_eq!(median.next(&Bar::new().close(7)), lit!(5.5));
+        assert_eq!(median.next(&Bar::new().close(1)), lit!(4.0));
+    }
+
+    #[test]
+    fn test_same_as_sorting() {
+        let mut rng = StdRng::seed_from_u64(11);
+
+        for period in [1, 2, 5, 20] {
+            let mut median = RollingMedian::new(period).unwrap();
+            let mut inputs = Vec::new();
+
+            for _ in 0..500 {
+                // few distinct values to get many duplicates
+                let input = crate::int!(rng.gen_range(0..10));
+                inputs.push(input);
+
+                let start = inputs.len().saturating_sub(period);
+                let mut window = inputs[start..].to_vec();
+                window.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let middle = window.len() / 2;
+                let expected = if window.len() % 2 == 1 {
+                    window[middle]
+                } else {
+                    (window[middle - 1] + window[middle]) / lit!(2.0)
+                };
+
+                assert_eq!(median.next(input), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut median = RollingMedian::new(3).unwrap();
+        median.next(lit!(4.0));
+        median.next(lit!(10.0));
+
+        median.reset();
+        assert_eq!(median.next(lit!(6.0)), lit!(6.0));
+        assert_eq!(median.next(lit!(2.0)), lit!(4.0));
+    }
+
+    #[test]
+    fn test_default() {
+        RollingMedian::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = RollingMedian::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "MEDIAN(7)");
+    }
+}
@@ -0,0 +1,243 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{lit, Close, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sum of the last _period_ values.
+///
+/// Uses the same running sum over a ring buffer as
+/// [SimpleMovingAverage](struct.SimpleMovingAverage.html), but returns the sum instead of
+/// the mean. Until _period_ inputs were seen, the sum of all inputs so far.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+/// * _source_ - which value of a bar is summed, see [`SumSource`]. Default is the close.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RollingSum;
+/// use ta::{lit, Next};
+///
+/// let mut sum = RollingSum::new(2).unwrap();
+///
+/// assert_eq!(sum.next(lit!(4.0)), lit!(4.0));
+/// assert_eq!(sum.next(lit!(5.0)), lit!(9.0));
+/// assert_eq!(sum.next(lit!(1.0)), lit!(6.0));
+/// ```
+#[doc(alias = "SUM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RollingSum {
+    period: usize,
+    source: SumSource,
+    index: usize,
+    sum: crate::Num,
+    deque: Box<[crate::Num]>,
+}
+
+/// Value of a bar summed by [`RollingSum`].
+///
+/// Plain numbers are always summed as they are.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SumSource {
+    #[default]
+    Close,
+    Volume,
+}
+
+impl RollingSum {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_source(period, SumSource::default())
+    }
+
+    /// Creates a rolling sum of the volumes of bars, e.g. the traded volume of the last
+    /// _period_ bars.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_volume(period: usize) -> Result<Self> {
+        Self::new_with_source(period, SumSource::Volume)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_source(period: usize, source: SumSource) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                source,
+                index: 0,
+                sum: lit!(0.0),
+                deque: vec![lit!(0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn source(&self) -> SumSource {
+        self.source
+    }
+}
+
+impl Period for RollingSum {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for RollingSum {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        // the buffer starts zeroed, so a value leaving it during warmup doesn't change the sum
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.sum = self.sum - old_val + input;
+        self.sum
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for RollingSum {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        match self.source {
+            SumSource::Close => self.next(input.close()),
+            SumSource::Volume => self.next(input.volume()),
+        }
+    }
+}
+
+impl Reset for RollingSum {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.sum = lit!(0.0);
+        for i in 0..self.period {
+            self.deque[i] = lit!(0.0);
+        }
+    }
+}
+
+impl Default for RollingSum {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for RollingSum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.source {
+            SumSource::Close => write!(f, "SUM({})", self.period),
+            SumSource::Volume => write!(f, "VOLUME_SUM({})", self.period),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(RollingSum);
+
+    #[test]
+    fn test_new() {
+        assert!(RollingSum::new(0).is_err());
+        assert!(RollingSum::new_volume(0).is_err());
+        assert!(RollingSum::new(1).is_ok());
+        assert_eq!(RollingSum::new(1).unwrap().source(), SumSource::Close);
+        assert_eq!(
+            RollingSum::new_volume(1).unwrap().source(),
+            SumSource::Volume
+        );
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sum = RollingSum::new(3).unwrap();
+        assert_eq!(sum.next(lit!(4.0)), lit!(4.0));
+        assert_eq!(sum.next(lit!(5.0)), lit!(9.0));
+        assert_eq!(sum.next(lit!(6.0)), lit!(15.0));
+        assert_eq!(sum.next(lit!(-2.0)), lit!(9.0));
+        assert_eq!(sum.next(lit!(0.5)), lit!(4.5));
+    }
+
+    #[test]
+    fn test_same_as_sma_times_count() {
+        let inputs = [4, 5, 6, 6, 6, 6, 2, 10, 3, 7];
+
+        for period in [1, 3, 4] {
+            let mut sum = RollingSum::new(period).unwrap();
+            let mut sma = SimpleMovingAverage::new(period).unwrap();
+
+            for (i, &input) in inputs.iter().enumerate() {
+                let count = crate::int!((i + 1).min(period));
+                let mean = sma.next(crate::int!(input));
+                assert_eq!(sum.next(crate::int!(input)), mean * count);
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let bars = [
+            Bar::new().close(10).volume(100),
+            Bar::new().close(12).volume(250),
+            Bar::new().close(11).volume(50),
+        ];
+
+        let mut close_sum = RollingSum::new(2).unwrap();
+        let mut volume_sum = RollingSum::new_volume(2).unwrap();
+        let closes: Vec<_> = bars.iter().map(|bar| close_sum.next(bar)).collect();
+        let volumes: Vec<_> = bars.iter().map(|bar| volume_sum.next(bar)).collect();
+
+        assert_eq!(closes, vec![lit!(10.0), lit!(22.0), lit!(23.0)]);
+        assert_eq!(volumes, vec![lit!(100.0), lit!(350.0), lit!(300.0)]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sum = RollingSum::new(3).unwrap();
+        sum.next(lit!(4.0));
+        sum.next(lit!(10.0));
+
+        sum.reset();
+        assert_eq!(sum.next(lit!(6.0)), lit!(6.0));
+        assert_eq!(sum.next(lit!(2.0)), lit!(8.0));
+    }
+
+    #[test]
+    fn test_default() {
+        RollingSum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", RollingSum::new(7).unwrap()), "SUM(7)");
+        assert_eq!(
+            format!("{}", RollingSum::new_volume(7).unwrap()),
+            "VOLUME_SUM(7)"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{ExponentialMovingAverage as Ema, Maximum, Minimum};
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Schaff Trend Cycle (STC).
+///
+/// Developed by Doug Schaff, STC runs the MACD line through two stochastic passes over a
+/// short cycle, which makes it react to trend changes faster than MACD. The output is in
+/// the range of 0..100, values above 75 and below 25 are commonly read as overbought and
+/// oversold.
+///
+/// # Formula
+///
+/// Every input goes through the following stages, in this order:
+///
+/// 1. MACD = EMA(price, _fast_period_) - EMA(price, _slow_period_)
+/// 2. %K<sub>1</sub> = 100 * (MACD - min(MACD)) / (max(MACD) - min(MACD))
+/// 3. %D<sub>1</sub> = %D<sub>1,t-1</sub> + _factor_ * (%K<sub>1</sub> - %D<sub>1,t-1</sub>)
+/// 4. %K<sub>2</sub> = 100 * (%D<sub>1</sub> - min(%D<sub>1</sub>)) / (max(%D<sub>1</sub>) - min(%D<sub>1</sub>))
+/// 5. STC = STC<sub>t-1</sub> + _factor_ * (%K<sub>2</sub> - STC<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _min_, _max_ - lowest and highest value over the last _cycle_ values
+///
+/// When the highest and the lowest value of a stochastic pass are equal, the pass repeats
+/// its previous %K, 0 before any range was seen. Both smoothing stages are seeded with
+/// their first %K.
+///
+/// # Parameters
+///
+/// * _fast_period_ - period of the fast EMA (integer greater than 0). Default is 23.
+/// * _slow_period_ - period of the slow EMA (integer greater than 0). Default is 50.
+/// * _cycle_ - window of the stochastic passes (integer greater than 0). Default is 10.
+/// * _factor_ - smoothing factor of the stochastic passes, greater than 0 and at most 1.
+///   Default is 0.5.
+///
+/// # Links
+///
+/// * [Schaff Trend Cycle, Investopedia](https://www.investopedia.com/articles/forex/10/schaff-trend-cycle-indicator.asp)
+///
+#[doc(alias = "STC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SchaffTrendCycle {
+    factor: crate::Num,
+    fast_ema: Ema,
+    slow_ema: Ema,
+    macd_max: Maximum,
+    macd_min: Minimum,
+    k1: crate::Num,
+    d1: Option<crate::Num>,
+    d1_max: Maximum,
+    d1_min: Minimum,
+    k2: crate::Num,
+    stc: Option<crate::Num>,
+}
+
+impl SchaffTrendCycle {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0 or `factor` is outside of 0..1
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        cycle: usize,
+        factor: crate::Num,
+    ) -> Result<Self> {
+        if factor <= lit!(0.0) || factor > lit!(1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            factor,
+            fast_ema: Ema::new(fast_period)?,
+            slow_ema: Ema::new(slow_period)?,
+            macd_max: Maximum::new(cycle)?,
+            macd_min: Minimum::new(cycle)?,
+            k1: lit!(0.0),
+            d1: None,
+            d1_max: Maximum::new(cycle)?,
+            d1_min: Minimum::new(cycle)?,
+            k2: lit!(0.0),
+            stc: None,
+        })
+    }
+
+    #[must_use]
+    pub fn factor(&self) -> crate::Num {
+        self.factor
+    }
+
+    fn smooth(&self, prev: Option<crate::Num>, k: crate::Num) -> crate::Num {
+        match prev {
+            Some(prev) => prev + self.factor * (k - prev),
+            None => k,
+        }
+    }
+}
+
+/// One stochastic pass, `prev` is returned when the window has no range.
+fn stochastic(value: crate::Num, max: crate::Num, min: crate::Num, prev: crate::Num) -> crate::Num {
+    if max > min {
+        lit!(100.0) * (value - min) / (max - min)
+    } else {
+        prev
+    }
+}
+
+impl Period for SchaffTrendCycle {
+    fn period(&self) -> usize {
+        self.macd_max.period()
+    }
+}
+
+impl Next<crate::Num> for SchaffTrendCycle {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let macd = self.fast_ema.next(input) - self.slow_ema.next(input);
+        self.k1 = stochastic(
+            macd,
+            self.macd_max.next(macd),
+            self.macd_min.next(macd),
+            self.k1,
+        );
+
+        let d1 = self.smooth(self.d1, self.k1);
+        self.d1 = Some(d1);
+        self.k2 = stochastic(d1, self.d1_max.next(d1), self.d1_min.next(d1), self.k2);
+
+        let stc = self.smooth(self.stc, self.k2);
+        self.stc = Some(stc);
+        stc
+    }
+}
+
+impl<T: Close> Next<&T> for SchaffTrendCycle {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SchaffTrendCycle {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.macd_max.reset();
+        self.macd_min.reset();
+        self.k1 = lit!(0.0);
+        self.d1 = None;
+        self.d1_max.reset();
+        self.d1_min.reset();
+        self.k2 = lit!(0.0);
+        self.stc = None;
+    }
+}
+
+impl Default for SchaffTrendCycle {
+    fn default() -> Self {
+        Self::new(23, 50, 10, lit!(0.5)).unwrap()
+    }
+}
+
+impl fmt::Display for SchaffTrendCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STC({}, {}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.macd_max.period(),
+            self.factor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int;
+    use crate::test_helper::*;
+
+    test_indicator!(SchaffTrendCycle);
+
+    #[test]
+    fn test_new() {
+        assert!(SchaffTrendCycle::new(0, 50, 10, lit!(0.5)).is_err());
+        assert!(SchaffTrendCycle::new(23, 0, 10, lit!(0.5)).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 0, lit!(0.5)).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10, lit!(0.0)).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10, lit!(1.1)).is_err());
+        assert!(SchaffTrendCycle::new(1, 1, 1, lit!(1.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 3, lit!(0.5)).unwrap();
+
+        assert_eq!(stc.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(round(stc.next(lit!(11.0))), lit!(50.0));
+        assert_eq!(round(stc.next(lit!(12.0))), lit!(75.0));
+        assert_eq!(round(stc.next(lit!(11.0))), lit!(39.811));
+        assert_eq!(round(stc.next(lit!(10.0))), lit!(19.905));
+        assert_eq!(round(stc.next(lit!(12.0))), lit!(59.953));
+        assert_eq!(round(stc.next(lit!(14.0))), lit!(79.976));
+        assert_eq!(round(stc.next(&Bar::new().close(13))), lit!(76.319));
+    }
+
+    #[test]
+    fn test_next_bounded() {
+        let mut stc = SchaffTrendCycle::default();
+
+        // pseudo-random walk
+        let mut seed: i64 = 42;
+        let mut price = lit!(100.0);
+        for _ in 0..500 {
+            seed = (seed * 1_103_515_245 + 12345) % 2_147_483_648;
+            price += int!(seed % 11 - 5);
+            let value = stc.next(price);
+            assert!(value >= lit!(0.0));
+            assert!(value <= lit!(100.0));
+        }
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 3, lit!(0.5)).unwrap();
+
+        for _ in 0..20 {
+            assert_eq!(stc.next(lit!(10.0)), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 3, lit!(0.5)).unwrap();
+
+        stc.next(lit!(10.0));
+        stc.next(lit!(11.0));
+        stc.next(lit!(12.0));
+
+        stc.reset();
+        assert_eq!(stc.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(round(stc.next(lit!(11.0))), lit!(50.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SchaffTrendCycle::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SchaffTrendCycle::default();
+        assert_eq!(format!("{}", indicator), "STC(23, 50, 10, 0.5)");
+    }
+}
@@ -1,7 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{int, lit, Close, Next, Period, Reset};
+use crate::indicators::Strict;
+use crate::{int, lit, Close, Current, Next, Period, Reset, ResetTo};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +23,9 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
+/// * _recompute_every_ - number of inputs after which the sum of the window is recomputed
+///   from scratch, see [`new_with_recompute`](Self::new_with_recompute). By default it is
+///   never recomputed.
 ///
 /// # Links
 ///
@@ -32,8 +38,10 @@ pub struct SimpleMovingAverage {
     period: usize,
     index: usize,
     count: usize,
-    sum: rust_decimal::Decimal,
-    deque: Box<[rust_decimal::Decimal]>,
+    recompute_every: usize,
+    updates: usize,
+    sum: crate::Num,
+    deque: Box<[crate::Num]>,
 }
 
 impl SimpleMovingAverage {
@@ -42,16 +50,55 @@ impl SimpleMovingAverage {
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
                 index: 0,
                 count: 0,
+                recompute_every: 0,
+                updates: 0,
                 sum: lit!(0.0),
                 deque: vec![lit!(0.0); period].into_boxed_slice(),
             }),
         }
     }
+
+    /// Creates an SMA that recomputes the sum of its window every `recompute_every` inputs.
+    ///
+    /// The running sum adds the new input and subtracts the one leaving the window, so
+    /// rounding errors accumulate over millions of updates, especially with inputs of very
+    /// different magnitudes. Recomputing the sum bounds that drift at the cost of an O(_period_)
+    /// summation every `recompute_every` inputs.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` or `recompute_every` is 0
+    pub fn new_with_recompute(period: usize, recompute_every: usize) -> Result<Self> {
+        match recompute_every {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                recompute_every,
+                ..Self::new(period)?
+            }),
+        }
+    }
+
+    /// Returns the number of inputs between two recomputations of the sum, 0 if it is never
+    /// recomputed.
+    #[must_use]
+    pub fn recompute_every(&self) -> usize {
+        self.recompute_every
+    }
+
+    /// Creates a [strict](struct.Strict.html) version of the indicator, which returns
+    /// `Err(TaError::InsufficientData { .. })` until `period` inputs were seen.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_strict(period: usize) -> Result<Strict<Self>> {
+        Ok(Strict::new(Self::new(period)?, period))
+    }
 }
 
 impl Period for SimpleMovingAverage {
@@ -60,10 +107,10 @@ impl Period for SimpleMovingAverage {
     }
 }
 
-impl Next<rust_decimal::Decimal> for SimpleMovingAverage {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for SimpleMovingAverage {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -78,22 +125,42 @@ impl Next<rust_decimal::Decimal> for SimpleMovingAverage {
         }
 
         self.sum = self.sum - old_val + input;
+        if self.recompute_every > 0 {
+            self.updates += 1;
+            if self.updates == self.recompute_every {
+                self.updates = 0;
+                self.sum = self.deque.iter().sum();
+            }
+        }
+
         self.sum / int!(self.count)
     }
 }
 
 impl<T: Close> Next<&T> for SimpleMovingAverage {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
     }
 }
 
+impl Current for SimpleMovingAverage {
+    type Output = crate::Num;
+
+    fn current(&self) -> Option<crate::Num> {
+        match self.count {
+            0 => None,
+            _ => Some(self.sum / int!(self.count)),
+        }
+    }
+}
+
 impl Reset for SimpleMovingAverage {
     fn reset(&mut self) {
         self.index = 0;
         self.count = 0;
+        self.updates = 0;
         self.sum = lit!(0.0);
         for i in 0..self.period {
             self.deque[i] = lit!(0.0);
@@ -101,6 +168,18 @@ impl Reset for SimpleMovingAverage {
     }
 }
 
+impl ResetTo for SimpleMovingAverage {
+    fn reset_to(&mut self, seed: crate::Num) {
+        self.index = 0;
+        self.count = self.period;
+        self.updates = 0;
+        self.sum = seed * int!(self.period);
+        for i in 0..self.period {
+            self.deque[i] = seed;
+        }
+    }
+}
+
 impl Default for SimpleMovingAverage {
     fn default() -> Self {
         Self::new(9).unwrap()
@@ -140,7 +219,7 @@ mod tests {
 
     #[test]
     fn test_next_with_bars() {
-        fn bar(close: rust_decimal::Decimal) -> Bar {
+        fn bar(close: crate::Num) -> Bar {
             Bar::new().close(close)
         }
 
@@ -151,6 +230,74 @@ mod tests {
         assert_eq!(sma.next(&bar(lit!(1.0))), lit!(4.0));
     }
 
+    #[test]
+    fn test_new_with_recompute() {
+        assert!(SimpleMovingAverage::new_with_recompute(0, 1).is_err());
+        assert!(SimpleMovingAverage::new_with_recompute(1, 0).is_err());
+
+        let sma = SimpleMovingAverage::new_with_recompute(4, 100).unwrap();
+        assert_eq!(sma.recompute_every(), 100);
+        assert_eq!(SimpleMovingAverage::new(4).unwrap().recompute_every(), 0);
+    }
+
+    #[test]
+    fn test_recompute() {
+        let big = int!(1_000_000_000_000_000_000i64);
+        let small = lit!(0.123456789123);
+
+        let mut sma = SimpleMovingAverage::new(4).unwrap();
+        let mut recomputing = SimpleMovingAverage::new_with_recompute(4, 4).unwrap();
+        for i in 0..10_000 {
+            let input = if i % 2 == 0 { big } else { small };
+            sma.next(input);
+            recomputing.next(input);
+        }
+
+        // once the big values have left the window, the running sum is still off by their
+        // rounding errors, while the recomputed one is exact again
+        let mut fresh = SimpleMovingAverage::new(4).unwrap();
+        for _ in 0..4 {
+            sma.next(small);
+            recomputing.next(small);
+            fresh.next(small);
+        }
+        assert_eq!(recomputing.current(), fresh.current());
+        assert_ne!(sma.current(), fresh.current());
+    }
+
+    #[test]
+    fn test_strict() {
+        assert!(SimpleMovingAverage::new_strict(0).is_err());
+
+        let mut sma = SimpleMovingAverage::new_strict(3).unwrap();
+        assert_eq!(
+            sma.next(lit!(4.0)),
+            Err(TaError::InsufficientData { have: 1, need: 3 })
+        );
+        assert_eq!(
+            sma.next(lit!(5.0)),
+            Err(TaError::InsufficientData { have: 2, need: 3 })
+        );
+        assert_eq!(sma.next(lit!(6.0)), Ok(lit!(5.0)));
+        assert_eq!(sma.next(lit!(7.0)), Ok(lit!(6.0)));
+
+        sma.reset();
+        assert!(sma.next(lit!(4.0)).is_err());
+    }
+
+    #[test]
+    fn test_strict_error_count() {
+        for period in 1..10 {
+            let mut indicator = SimpleMovingAverage::new_strict(period).unwrap();
+            let errors = (0..3 * period)
+                .map(|i| indicator.next(int!(10 + i % 4)))
+                .take_while(Result::is_err)
+                .count();
+            // the first output is the average of a full window
+            assert_eq!(errors, period - 1);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut sma = SimpleMovingAverage::new(4).unwrap();
@@ -162,6 +309,37 @@ mod tests {
         assert_eq!(sma.next(lit!(99.0)), lit!(99.0));
     }
 
+    #[test]
+    fn test_current() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        assert_eq!(sma.current(), None);
+
+        for input in [lit!(4.0), lit!(5.0), lit!(9.0)] {
+            let value = sma.next(input);
+            assert_eq!(sma.current(), Some(value));
+        }
+
+        sma.reset();
+        assert_eq!(sma.current(), None);
+    }
+
+    #[test]
+    fn test_reset_to() {
+        let mut sma = SimpleMovingAverage::new(4).unwrap();
+
+        sma.next(lit!(4.0));
+        sma.next(lit!(5.0));
+
+        // the window is full of the seed, the input doesn't become the average
+        sma.reset_to(lit!(10.0));
+        assert_eq!(sma.current(), Some(lit!(10.0)));
+        assert_eq!(sma.next(lit!(14.0)), lit!(11.0));
+        assert_eq!(sma.next(lit!(14.0)), lit!(12.0));
+        sma.next(lit!(14.0));
+        sma.next(lit!(14.0));
+        assert_eq!(sma.next(lit!(18.0)), lit!(15.0));
+    }
+
     #[test]
     fn test_default() {
         SimpleMovingAverage::default();
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, FastStochastic};
@@ -34,16 +34,16 @@ impl SlowStochastic {
     }
 }
 
-impl Next<rust_decimal::Decimal> for SlowStochastic {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for SlowStochastic {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         self.ema.next(self.fast_stochastic.next(input))
     }
 }
 
 impl<T: High + Low + Close> Next<&T> for SlowStochastic {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.ema.next(self.fast_stochastic.next(input))
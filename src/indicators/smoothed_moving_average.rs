@@ -0,0 +1,167 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, Close, Current, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Smoothed moving average (SMMA), also known as running moving average (RMA) or Wilder's
+/// moving average.
+///
+/// It is an exponential moving average with a smoothing constant of 1 / _period_, which
+/// reacts slower than an [EMA](struct.ExponentialMovingAverage.html) of the same period.
+///
+/// # Formula
+///
+/// SMMA<sub>t</sub> = (SMMA<sub>t-1</sub> * (_period_ - 1) + p<sub>t</sub>) / _period_
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a time period _t_
+///
+/// The first SMMA is the simple average of the first _period_ values. Before that the
+/// indicator returns the simple average of the values seen so far.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Modified moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average)
+///
+#[doc(alias = "SMMA")]
+#[doc(alias = "RMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SmoothedMovingAverage {
+    period: usize,
+    count: usize,
+    current: crate::Num,
+}
+
+impl SmoothedMovingAverage {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                current: lit!(0.0),
+            }),
+        }
+    }
+}
+
+impl Period for SmoothedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for SmoothedMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // while warming up this is the cumulative average of the inputs
+        let n = int!(self.count);
+        self.current = (self.current * (n - lit!(1.0)) + input) / n;
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for SmoothedMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for SmoothedMovingAverage {
+    type Output = crate::Num;
+
+    fn current(&self) -> Option<crate::Num> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.current)
+        }
+    }
+}
+
+impl Reset for SmoothedMovingAverage {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = lit!(0.0);
+    }
+}
+
+impl Default for SmoothedMovingAverage {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for SmoothedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SMMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SmoothedMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(SmoothedMovingAverage::new(0).is_err());
+        assert!(SmoothedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        assert_eq!(smma.next(lit!(4.0)), lit!(4.0));
+        assert_eq!(smma.next(lit!(8.0)), lit!(6.0));
+        // simple average of the first 3 values
+        assert_eq!(smma.next(lit!(9.0)), lit!(7.0));
+        // (7 * 2 + 13) / 3
+        assert_eq!(smma.next(lit!(13.0)), lit!(9.0));
+        assert_eq!(smma.next(&Bar::new().close(3)), lit!(7.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        smma.next(lit!(4.0));
+        smma.next(lit!(8.0));
+
+        smma.reset();
+        assert_eq!(smma.next(lit!(10.0)), lit!(10.0));
+        assert_eq!(smma.next(lit!(20.0)), lit!(15.0));
+    }
+
+    #[test]
+    fn test_default() {
+        SmoothedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SmoothedMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "SMMA(7)");
+    }
+}
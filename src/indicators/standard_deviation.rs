@@ -1,8 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{int, lit, Close, Next, Period, Reset};
-use rust_decimal::MathematicalOps;
+use crate::indicators::Strict;
+use crate::{int, lit, num, Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -23,17 +25,22 @@ use serde::{Deserialize, Serialize};
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
+/// * _ddof_ - delta degrees of freedom, the sum of squared deviations is divided by
+///   _N_ - _ddof_. Default is 0, which gives the population standard deviation, use 1 for
+///   the sample standard deviation (Bessel's correction). Until more than _ddof_ values
+///   were seen the indicator returns 0.
 ///
 #[doc(alias = "SD")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct StandardDeviation {
     period: usize,
+    ddof: usize,
     index: usize,
     count: usize,
-    m: rust_decimal::Decimal,
-    m2: rust_decimal::Decimal,
-    deque: Box<[rust_decimal::Decimal]>,
+    m: crate::Num,
+    m2: crate::Num,
+    deque: Box<[crate::Num]>,
 }
 
 impl StandardDeviation {
@@ -41,10 +48,18 @@ impl StandardDeviation {
     ///
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_ddof(period, 0)
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_with_ddof(period: usize, ddof: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
+                ddof,
                 index: 0,
                 count: 0,
                 m: lit!(0.0),
@@ -54,9 +69,40 @@ impl StandardDeviation {
         }
     }
 
-    pub(super) fn mean(&self) -> rust_decimal::Decimal {
+    /// Creates a [strict](struct.Strict.html) version of the indicator, which returns
+    /// `Err(TaError::InsufficientData { .. })` until `period` inputs were seen.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new_strict(period: usize) -> Result<Strict<Self>> {
+        Ok(Strict::new(Self::new(period)?, period))
+    }
+
+    #[must_use]
+    pub fn ddof(&self) -> usize {
+        self.ddof
+    }
+
+    /// Returns the mean of the values in the window.
+    #[must_use]
+    pub fn mean(&self) -> crate::Num {
         self.m
     }
+
+    /// Returns the variance of the values in the window, the square of the standard
+    /// deviation returned by the last `next`.
+    ///
+    /// The sum of squared deviations is divided by _N_ - _ddof_, so for an indicator
+    /// created with [`new`](Self::new) this is the population variance.
+    #[must_use]
+    pub fn variance(&self) -> crate::Num {
+        if self.count <= self.ddof {
+            return lit!(0.0);
+        }
+
+        self.m2 / int!(self.count - self.ddof)
+    }
 }
 
 impl Period for StandardDeviation {
@@ -65,10 +111,10 @@ impl Period for StandardDeviation {
     }
 }
 
-impl Next<rust_decimal::Decimal> for StandardDeviation {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for StandardDeviation {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -95,14 +141,12 @@ impl Next<rust_decimal::Decimal> for StandardDeviation {
             self.m2 = lit!(0.0);
         }
 
-        (self.m2 / int!(self.count))
-            .sqrt()
-            .expect("Invalid (probably negative) number sent.")
+        num::sqrt(self.variance())
     }
 }
 
 impl<T: Close> Next<&T> for StandardDeviation {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
@@ -129,7 +173,10 @@ impl Default for StandardDeviation {
 
 impl fmt::Display for StandardDeviation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SD({})", self.period)
+        match self.ddof {
+            0 => write!(f, "SD({})", self.period),
+            _ => write!(f, "SD({}, {})", self.period, self.ddof),
+        }
     }
 }
 
@@ -144,6 +191,8 @@ mod tests {
     fn test_new() {
         assert!(StandardDeviation::new(0).is_err());
         assert!(StandardDeviation::new(1).is_ok());
+        assert!(StandardDeviation::new_with_ddof(0, 1).is_err());
+        assert_eq!(StandardDeviation::new_with_ddof(3, 1).unwrap().ddof(), 1);
     }
 
     #[test]
@@ -157,6 +206,47 @@ mod tests {
         assert_eq!(round(sd.next(lit!(100.0))), lit!(35.355));
     }
 
+    #[test]
+    fn test_next_with_ddof() {
+        let mut sd = StandardDeviation::new_with_ddof(4, 1).unwrap();
+        assert_eq!(sd.next(lit!(10.0)), lit!(0.0));
+        // sqrt(50 / 1)
+        assert_eq!(round(sd.next(lit!(20.0))), lit!(7.071));
+        // sqrt(200 / 2)
+        assert_eq!(round(sd.next(lit!(30.0))), lit!(10.0));
+        // sqrt(200 / 3)
+        assert_eq!(round(sd.next(lit!(20.0))), lit!(8.165));
+        assert_eq!(round(sd.next(lit!(10.0))), lit!(8.165));
+        // sqrt(5000 / 3)
+        assert_eq!(round(sd.next(lit!(100.0))), lit!(40.825));
+
+        let mut sd = StandardDeviation::new_with_ddof(2, 2).unwrap();
+        assert_eq!(sd.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(sd.next(lit!(20.0)), lit!(0.0));
+        assert_eq!(sd.next(lit!(30.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_variance() {
+        let mut sd = StandardDeviation::new(4).unwrap();
+        assert_eq!(sd.variance(), lit!(0.0));
+        assert_eq!(sd.mean(), lit!(0.0));
+
+        for input in [lit!(10.0), lit!(20.0), lit!(30.0), lit!(20.0), lit!(100.0)] {
+            let deviation = sd.next(input);
+            assert_eq!(round(sd.variance()), round(deviation * deviation));
+        }
+        // 20, 30, 20, 100
+        assert_eq!(sd.mean(), lit!(42.5));
+        assert_eq!(sd.variance(), lit!(1118.75));
+
+        let mut sd = StandardDeviation::new_with_ddof(4, 1).unwrap();
+        sd.next(lit!(10.0));
+        assert_eq!(sd.variance(), lit!(0.0));
+        sd.next(lit!(20.0));
+        assert_eq!(sd.variance(), lit!(50.0));
+    }
+
     #[test]
     fn test_next_floating_point_error() {
         let mut sd = StandardDeviation::new(6).unwrap();
@@ -171,7 +261,7 @@ mod tests {
 
     #[test]
     fn test_next_with_bars() {
-        fn bar(close: rust_decimal::Decimal) -> Bar {
+        fn bar(close: crate::Num) -> Bar {
             Bar::new().close(close)
         }
 
@@ -193,6 +283,34 @@ mod tests {
         assert_eq!(sd.next(lit!(4.2)), lit!(0.0));
     }
 
+    #[test]
+    fn test_strict() {
+        assert!(StandardDeviation::new_strict(0).is_err());
+
+        let mut sd = StandardDeviation::new_strict(2).unwrap();
+        assert_eq!(
+            sd.next(lit!(10.0)),
+            Err(TaError::InsufficientData { have: 1, need: 2 })
+        );
+        assert_eq!(sd.next(lit!(20.0)), Ok(lit!(5.0)));
+
+        sd.reset();
+        assert!(sd.next(lit!(10.0)).is_err());
+    }
+
+    #[test]
+    fn test_strict_error_count() {
+        for period in 1..10 {
+            let mut indicator = StandardDeviation::new_strict(period).unwrap();
+            let errors = (0..3 * period)
+                .map(|i| indicator.next(int!(10 + i % 4)))
+                .take_while(Result::is_err)
+                .count();
+            // the first output is the deviation of a full window
+            assert_eq!(errors, period - 1);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut sd = StandardDeviation::new(4).unwrap();
@@ -213,5 +331,7 @@ mod tests {
     fn test_display() {
         let sd = StandardDeviation::new(5).unwrap();
         assert_eq!(format!("{}", sd), "SD(5)");
+        let sd = StandardDeviation::new_with_ddof(5, 1).unwrap();
+        assert_eq!(format!("{}", sd), "SD(5, 1)");
     }
 }
@@ -0,0 +1,217 @@
+use core::fmt;
+
+use super::linreg::LinearRegression;
+use crate::errors::{Result, TaError};
+use crate::{lit, Bands, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard Error Bands (SEB).
+///
+/// Developed by Jon Andersen, Standard Error Bands are placed around the
+/// [Linear Regression Moving Average](struct.LinearRegressionMovingAverage.html) at a
+/// multiple of the standard error of the regression. The bands are narrow while the prices
+/// follow a straight line and widen as they scatter around it.
+///
+/// # Formula
+///
+/// SE = sqrt(sum((y - ŷ)<sup>2</sup>) / (n - 2))
+///
+/// SEB is composed as:
+///
+///  * _SEB<sub>Middle Band</sub>_ - Linear Regression Moving Average (LINREG).
+///  * _SEB<sub>Upper Band</sub>_ = LINREG + SE * _multiplier_
+///  * _SEB<sub>Lower Band</sub>_ = LINREG - SE * _multiplier_
+///
+/// Where:
+///
+/// * _y_ - prices of the window
+/// * _ŷ_ - values of the regression line at the same bars
+/// * _n_ - number of prices in the window, at most _period_
+///
+/// SE is 0 while the window holds 2 prices or less.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 21.
+/// * _multiplier_ - number of standard errors (not negative). Default is 2.0.
+///
+/// # Links
+///
+/// * [Standard Error Bands, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:standard_error_bands)
+///
+#[doc(alias = "SEB")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StandardErrorBands {
+    multiplier: crate::Num,
+    regression: LinearRegression,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardErrorBandsOutput {
+    pub upper: crate::Num,
+    pub middle: crate::Num,
+    pub lower: crate::Num,
+}
+
+impl Bands for StandardErrorBandsOutput {
+    fn upper(&self) -> crate::Num {
+        self.upper
+    }
+
+    fn average(&self) -> crate::Num {
+        self.middle
+    }
+
+    fn lower(&self) -> crate::Num {
+        self.lower
+    }
+}
+
+impl StandardErrorBands {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0 or `multiplier` is negative
+    pub fn new(period: usize, multiplier: crate::Num) -> Result<Self> {
+        if multiplier < lit!(0.0) {
+            return Err(TaError::InvalidMultiplier);
+        }
+
+        Ok(Self {
+            multiplier,
+            regression: LinearRegression::new(period)?,
+        })
+    }
+
+    #[must_use]
+    pub fn multiplier(&self) -> crate::Num {
+        self.multiplier
+    }
+}
+
+impl Period for StandardErrorBands {
+    fn period(&self) -> usize {
+        self.regression.period()
+    }
+}
+
+impl Next<crate::Num> for StandardErrorBands {
+    type Output = StandardErrorBandsOutput;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.regression.push(input);
+        let middle = self.regression.value();
+        let distance = self.regression.standard_error() * self.multiplier;
+
+        Self::Output {
+            upper: middle + distance,
+            middle,
+            lower: middle - distance,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for StandardErrorBands {
+    type Output = StandardErrorBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for StandardErrorBands {
+    fn reset(&mut self) {
+        self.regression.reset();
+    }
+}
+
+impl Default for StandardErrorBands {
+    fn default() -> Self {
+        Self::new(21, lit!(2.0)).unwrap()
+    }
+}
+
+impl fmt::Display for StandardErrorBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SEB({}, {})", self.regression.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::int;
+    use crate::test_helper::*;
+
+    test_indicator!(StandardErrorBands);
+
+    #[test]
+    fn test_new() {
+        assert!(StandardErrorBands::new(0, lit!(2.0)).is_err());
+        assert!(StandardErrorBands::new(3, lit!(-1.0)).is_err());
+        assert!(StandardErrorBands::new(1, lit!(0.0)).is_ok());
+        assert!(StandardErrorBands::new(3, lit!(2.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut seb = StandardErrorBands::new(3, lit!(2.0)).unwrap();
+
+        let out = seb.next(lit!(1.0));
+        assert_eq!(out.middle, lit!(1.0));
+        assert_eq!(out.upper, lit!(1.0));
+        assert_eq!(out.lower, lit!(1.0));
+
+        seb.next(lit!(3.0));
+
+        // line 1.5, 2, 2.5, residuals -0.5, 1, -0.5, se = sqrt(1.5)
+        let out = seb.next(&Bar::new().close(2));
+        assert_eq!(out.middle, lit!(2.5));
+        assert_eq!(round(out.upper), lit!(4.949));
+        assert_eq!(round(out.lower), lit!(0.051));
+    }
+
+    #[test]
+    fn test_next_linear() {
+        let mut seb = StandardErrorBands::new(4, lit!(2.0)).unwrap();
+
+        // y = 3x + 1, the bands collapse onto the line
+        for x in 0..10 {
+            let y = lit!(3.0) * int!(x) + lit!(1.0);
+            let out = seb.next(y);
+            assert_eq!(out.middle, y);
+            assert_eq!(out.upper, y);
+            assert_eq!(out.lower, y);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut seb = StandardErrorBands::new(3, lit!(2.0)).unwrap();
+
+        seb.next(lit!(1.0));
+        seb.next(lit!(3.0));
+        seb.next(lit!(2.0));
+
+        seb.reset();
+        let out = seb.next(lit!(5.0));
+        assert_eq!(out.middle, lit!(5.0));
+        assert_eq!(out.upper, lit!(5.0));
+    }
+
+    #[test]
+    fn test_default() {
+        StandardErrorBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = StandardErrorBands::default();
+        #[cfg(not(feature = "f64-backend"))]
+        assert_eq!(format!("{}", indicator), "SEB(21, 2.0)");
+        #[cfg(feature = "f64-backend")]
+        assert_eq!(format!("{}", indicator), "SEB(21, 2)");
+    }
+}
@@ -0,0 +1,260 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum, SimpleMovingAverage as Sma};
+use crate::{lit, Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic oscillator with both %K and %D lines.
+///
+/// Computes the raw %K of a [FastStochastic](struct.FastStochastic.html), smooths it with a
+/// simple moving average into %K and averages %K again into the signal line %D. A
+/// _`k_smoothing`_ of 1 gives the fast stochastic, 3 the commonly used slow stochastic.
+///
+/// # Formula
+///
+/// * raw %K = (close - lowest low) / (highest high - lowest low) * 100
+/// * %K = SMA(raw %K, _`k_smoothing`_)
+/// * %D = SMA(%K, _`d_period`_)
+///
+/// The lowest low and the highest high are taken over the last _`k_period`_ bars. If they
+/// are equal, raw %K is 50.
+///
+/// # Parameters
+///
+/// * _`k_period`_ - number of periods for raw %K (integer greater than 0). Default is 14.
+/// * _`k_smoothing`_ - period of the SMA smoothing raw %K (integer greater than 0). Default is 3.
+/// * _`d_period`_ - period of the SMA of %K (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Stochastic;
+/// use ta::{lit, Next};
+///
+/// let mut stoch = Stochastic::new(3, 1, 2).unwrap();
+///
+/// stoch.next(lit!(0.0));
+/// let out = stoch.next(lit!(200.0));
+/// assert_eq!((out.k, out.d), (lit!(100.0), lit!(75.0)));
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic oscillator, Wikipedia](https://en.wikipedia.org/wiki/Stochastic_oscillator)
+#[doc(alias = "STOCH")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Stochastic {
+    k_period: usize,
+    minimum: Minimum,
+    maximum: Maximum,
+    k_sma: Sma,
+    d_sma: Sma,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticOutput {
+    pub k: crate::Num,
+    pub d: crate::Num,
+}
+
+impl Stochastic {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods are 0
+    pub fn new(k_period: usize, k_smoothing: usize, d_period: usize) -> Result<Self> {
+        Ok(Self {
+            k_period,
+            minimum: Minimum::new(k_period)?,
+            maximum: Maximum::new(k_period)?,
+            k_sma: Sma::new(k_smoothing)?,
+            d_sma: Sma::new(d_period)?,
+        })
+    }
+
+    #[must_use]
+    pub fn k_smoothing(&self) -> usize {
+        self.k_sma.period()
+    }
+
+    #[must_use]
+    pub fn d_period(&self) -> usize {
+        self.d_sma.period()
+    }
+
+    fn update(&mut self, high: crate::Num, low: crate::Num, close: crate::Num) -> StochasticOutput {
+        let highest = self.maximum.next(high);
+        let lowest = self.minimum.next(low);
+
+        let raw_k = if highest == lowest {
+            // no range to compare the close to
+            lit!(50.0)
+        } else {
+            (close - lowest) / (highest - lowest) * lit!(100.0)
+        };
+        let k = self.k_sma.next(raw_k);
+        let d = self.d_sma.next(k);
+
+        StochasticOutput { k, d }
+    }
+}
+
+impl Period for Stochastic {
+    fn period(&self) -> usize {
+        self.k_period
+    }
+}
+
+impl Next<crate::Num> for Stochastic {
+    type Output = StochasticOutput;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.update(input, input, input)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for Stochastic {
+    type Output = StochasticOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.update(input.high(), input.low(), input.close())
+    }
+}
+
+impl Reset for Stochastic {
+    fn reset(&mut self) {
+        self.minimum.reset();
+        self.maximum.reset();
+        self.k_sma.reset();
+        self.d_sma.reset();
+    }
+}
+
+impl Default for Stochastic {
+    fn default() -> Self {
+        Self::new(14, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for Stochastic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STOCH({},{},{})",
+            self.k_period,
+            self.k_sma.period(),
+            self.d_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::FastStochastic;
+    use crate::test_helper::*;
+
+    test_indicator!(Stochastic);
+
+    fn bars() -> Vec<Bar> {
+        // high, low, close
+        [
+            (20, 20, 20),
+            (30, 10, 25),
+            (40, 20, 16),
+            (35, 15, 19),
+            (30, 20, 25),
+            (35, 25, 30),
+            (38, 28, 37),
+            (36, 22, 24),
+        ]
+        .iter()
+        .map(|&(high, low, close)| Bar::new().high(high).low(low).close(close))
+        .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Stochastic::new(0, 1, 1).is_err());
+        assert!(Stochastic::new(1, 0, 1).is_err());
+        assert!(Stochastic::new(1, 1, 0).is_err());
+        assert!(Stochastic::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut stoch = Stochastic::new(3, 2, 2).unwrap();
+
+        // raw %K: 50, 75, 20, 30, 40, 75
+        let expected = [
+            (lit!(50.0), lit!(50.0)),
+            (lit!(62.5), lit!(56.25)),
+            (lit!(47.5), lit!(55.0)),
+            (lit!(25.0), lit!(36.25)),
+            (lit!(35.0), lit!(30.0)),
+            (lit!(57.5), lit!(46.25)),
+        ];
+        for (bar, (k, d)) in bars().iter().zip(expected) {
+            assert_eq!(stoch.next(bar), StochasticOutput { k, d });
+        }
+    }
+
+    #[test]
+    fn test_same_as_fast_stochastic() {
+        let mut stoch = Stochastic::new(3, 1, 1).unwrap();
+        let mut fast = FastStochastic::new(3).unwrap();
+
+        for bar in bars() {
+            let expected = fast.next(&bar);
+            assert_eq!(
+                stoch.next(&bar),
+                StochasticOutput {
+                    k: expected,
+                    d: expected
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_smoothed_fast_stochastic() {
+        let mut stoch = Stochastic::new(3, 3, 2).unwrap();
+        let mut fast = FastStochastic::new(3).unwrap();
+        let mut k_sma = Sma::new(3).unwrap();
+        let mut d_sma = Sma::new(2).unwrap();
+
+        for bar in bars() {
+            let k = k_sma.next(fast.next(&bar));
+            let d = d_sma.next(k);
+            assert_eq!(stoch.next(&bar), StochasticOutput { k, d });
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch = Stochastic::new(3, 2, 2).unwrap();
+        let first = stoch.next(lit!(10.0));
+        stoch.next(lit!(20.0));
+
+        stoch.reset();
+        assert_eq!(stoch.next(lit!(10.0)), first);
+    }
+
+    #[test]
+    fn test_default() {
+        let stoch = Stochastic::default();
+        assert_eq!(
+            (stoch.period(), stoch.k_smoothing(), stoch.d_period()),
+            (14, 3, 3)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Stochastic::new(14, 3, 3).unwrap();
+        assert_eq!(format!("{}", indicator), "STOCH(14,3,3)");
+    }
+}
@@ -0,0 +1,180 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum, RelativeStrengthIndex as Rsi};
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic RSI (StochRSI).
+///
+/// Developed by Tushar Chande and Stanley Kroll, StochRSI applies the
+/// [fast stochastic](struct.FastStochastic.html) formula to the values of the
+/// [RSI](struct.RelativeStrengthIndex.html) instead of the price. It tells where the
+/// current RSI is located within its own range and returns output in the range of 0..1.
+///
+/// # Formula
+///
+/// StochRSI = (RSI - min(RSI, _period_)) / (max(RSI, _period_) - min(RSI, _period_))
+///
+/// Where:
+///
+/// * _RSI_ - [relative strength index](struct.RelativeStrengthIndex.html) with the same
+///   period
+///
+/// If the highest and the lowest RSI of the period are equal, StochRSI is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of both the RSI and the stochastic window (integer
+///   greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Stochastic RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochrsi)
+///
+#[doc(alias = "STOCHRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticRsi {
+    period: usize,
+    rsi: Rsi,
+    maximum: Maximum,
+    minimum: Minimum,
+}
+
+impl StochasticRsi {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            rsi: Rsi::new(period)?,
+            maximum: Maximum::new(period)?,
+            minimum: Minimum::new(period)?,
+        })
+    }
+}
+
+impl Period for StochasticRsi {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for StochasticRsi {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let rsi = self.rsi.next(input);
+        let max = self.maximum.next(rsi);
+        let min = self.minimum.next(rsi);
+
+        if max == min {
+            lit!(0.0)
+        } else {
+            (rsi - min) / (max - min)
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for StochasticRsi {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for StochasticRsi {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.maximum.reset();
+        self.minimum.reset();
+    }
+}
+
+impl Default for StochasticRsi {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "STOCHRSI({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(StochasticRsi);
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticRsi::new(0).is_err());
+        assert!(StochasticRsi::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stoch_rsi = StochasticRsi::new(3).unwrap();
+
+        // a single RSI value has no range
+        assert_eq!(stoch_rsi.next(lit!(10.0)), lit!(0.0));
+        // the RSI rises to the top of its range
+        assert_eq!(stoch_rsi.next(lit!(11.0)), lit!(1.0));
+        assert_eq!(stoch_rsi.next(lit!(12.0)), lit!(1.0));
+        // and falls to the bottom of it
+        assert_eq!(stoch_rsi.next(lit!(8.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut stoch_rsi = StochasticRsi::new(3).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(stoch_rsi.next(lit!(10.0)), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_next_range() {
+        let mut stoch_rsi = StochasticRsi::new(5).unwrap();
+
+        let closes = [
+            100, 125, 110, 130, 145, 120, 95, 100, 115, 150, 140, 165, 130, 120,
+        ];
+        for close in closes {
+            let value = stoch_rsi.next(&Bar::new().close(close));
+            assert!(value >= lit!(0.0));
+            assert!(value <= lit!(1.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch_rsi = StochasticRsi::new(3).unwrap();
+
+        stoch_rsi.next(lit!(10.0));
+        stoch_rsi.next(lit!(11.0));
+
+        stoch_rsi.reset();
+        assert_eq!(stoch_rsi.next(lit!(12.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = StochasticRsi::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "STOCHRSI(14)");
+    }
+}
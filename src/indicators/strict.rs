@@ -0,0 +1,106 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Strict mode of a windowed indicator.
+///
+/// Instead of emitting partial values during warmup, `next` returns
+/// `Err(TaError::InsufficientData { have, need })` until the indicator has seen enough
+/// inputs for a fully populated window, and `Ok(value)` afterwards.
+///
+/// It is created by the `new_strict` constructor of the indicator, e.g.
+/// [`SimpleMovingAverage::new_strict`](struct.SimpleMovingAverage.html#method.new_strict).
+///
+/// Strict mode is a wrapper rather than a `strict` flag of the indicator, because the output
+/// of [`Next`] is an associated type: an indicator can't return a number from `next` when
+/// created with `new` and a `Result` when created with `new_strict`. The wrapper gives the
+/// strict indicator its own type, so the lenient one keeps returning plain numbers.
+///
+/// # Example
+///
+/// ```
+/// use ta::errors::TaError;
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{lit, Next};
+///
+/// let mut sma = SimpleMovingAverage::new_strict(2).unwrap();
+///
+/// assert_eq!(sma.next(lit!(1.0)), Err(TaError::InsufficientData { have: 1, need: 2 }));
+/// assert_eq!(sma.next(lit!(2.0)), Ok(lit!(1.5)));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Strict<I> {
+    inner: I,
+    count: usize,
+    need: usize,
+}
+
+impl<I> Strict<I> {
+    pub(super) fn new(inner: I, need: usize) -> Self {
+        Self {
+            inner,
+            count: 0,
+            need,
+        }
+    }
+
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    fn check<O>(&mut self, output: O) -> Result<O> {
+        if self.count < self.need {
+            self.count += 1;
+        }
+
+        if self.count < self.need {
+            Err(TaError::InsufficientData {
+                have: self.count,
+                need: self.need,
+            })
+        } else {
+            Ok(output)
+        }
+    }
+}
+
+impl<I: Period> Period for Strict<I> {
+    fn period(&self) -> usize {
+        self.inner.period()
+    }
+}
+
+impl<I: Next<crate::Num>> Next<crate::Num> for Strict<I> {
+    type Output = Result<I::Output>;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let output = self.inner.next(input);
+        self.check(output)
+    }
+}
+
+impl<'a, T, I: Next<&'a T>> Next<&'a T> for Strict<I> {
+    type Output = Result<I::Output>;
+
+    fn next(&mut self, input: &'a T) -> Self::Output {
+        let output = self.inner.next(input);
+        self.check(output)
+    }
+}
+
+impl<I: Reset> Reset for Strict<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.count = 0;
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Strict<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -0,0 +1,225 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange;
+use crate::{lit, Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// SuperTrend.
+///
+/// A trend following indicator that places a band _multiplier_ times the
+/// [ATR](struct.AverageTrueRange.html) below the price in an uptrend and above the price in a
+/// downtrend. The trend flips when the close crosses the active band.
+///
+/// # Formula
+///
+/// Basic bands:
+///
+/// * _Basic Upper_ = (high + low) / 2 + _multiplier_ * ATR
+/// * _Basic Lower_ = (high + low) / 2 - _multiplier_ * ATR
+///
+/// Final bands only tighten in the direction of the trend:
+///
+/// * _Final Upper_ = Basic Upper if it is below the previous Final Upper or the previous
+///   close was above the previous Final Upper, otherwise the previous Final Upper
+/// * _Final Lower_ = Basic Lower if it is above the previous Final Lower or the previous
+///   close was below the previous Final Lower, otherwise the previous Final Lower
+///
+/// An uptrend turns into a downtrend when the close falls below Final Lower, a downtrend
+/// turns into an uptrend when the close rises above Final Upper. The SuperTrend value is
+/// Final Lower in an uptrend and Final Upper in a downtrend.
+///
+/// The first bar is assumed to be in an uptrend.
+///
+/// # Parameters
+///
+/// * _atr_period_ - period of the ATR (integer greater than 0). Default is 10.
+/// * _multiplier_ - ATR multiplier (greater than 0). Default is 3.0.
+///
+/// # Links
+///
+/// * [SuperTrend, TradingView](https://www.tradingview.com/support/solutions/43000634738-supertrend/)
+///
+#[doc(alias = "SUPERTREND")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SuperTrend {
+    multiplier: crate::Num,
+    atr: AverageTrueRange,
+    is_uptrend: bool,
+    // previous final upper band, final lower band and close
+    prev: Option<(crate::Num, crate::Num, crate::Num)>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuperTrendOutput {
+    pub trend_value: crate::Num,
+    pub is_uptrend: bool,
+}
+
+impl SuperTrend {
+    /// # Errors
+    ///
+    /// Will return `Err` if `atr_period` is 0 or `multiplier` is not positive
+    pub fn new(atr_period: usize, multiplier: crate::Num) -> Result<Self> {
+        if multiplier <= lit!(0.0) {
+            return Err(TaError::InvalidMultiplier);
+        }
+        Ok(Self {
+            multiplier,
+            atr: AverageTrueRange::new(atr_period)?,
+            is_uptrend: true,
+            prev: None,
+        })
+    }
+
+    #[must_use]
+    pub fn multiplier(&self) -> crate::Num {
+        self.multiplier
+    }
+}
+
+impl Period for SuperTrend {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for SuperTrend {
+    type Output = SuperTrendOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let mid = (input.high() + input.low()) / lit!(2.0);
+        let band = self.multiplier * self.atr.next(input);
+        let basic_upper = mid + band;
+        let basic_lower = mid - band;
+
+        let (upper, lower) = match self.prev {
+            Some((prev_upper, prev_lower, prev_close)) => {
+                let upper = if basic_upper < prev_upper || prev_close > prev_upper {
+                    basic_upper
+                } else {
+                    prev_upper
+                };
+                let lower = if basic_lower > prev_lower || prev_close < prev_lower {
+                    basic_lower
+                } else {
+                    prev_lower
+                };
+                (upper, lower)
+            }
+            None => (basic_upper, basic_lower),
+        };
+
+        self.is_uptrend = if self.is_uptrend {
+            close >= lower
+        } else {
+            close > upper
+        };
+        self.prev = Some((upper, lower, close));
+
+        SuperTrendOutput {
+            trend_value: if self.is_uptrend { lower } else { upper },
+            is_uptrend: self.is_uptrend,
+        }
+    }
+}
+
+impl Reset for SuperTrend {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.is_uptrend = true;
+        self.prev = None;
+    }
+}
+
+impl Default for SuperTrend {
+    fn default() -> Self {
+        Self::new(10, lit!(3.0)).unwrap()
+    }
+}
+
+impl fmt::Display for SuperTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SUPERTREND({}, {})", self.atr.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SuperTrend::new(0, lit!(3.0)).is_err());
+        assert!(SuperTrend::new(10, lit!(0.0)).is_err());
+        assert!(SuperTrend::new(10, lit!(3.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        // with ATR period 1 the ATR equals the true range
+        let mut st = SuperTrend::new(1, lit!(1.0)).unwrap();
+
+        // bands 11 / 7
+        let out = st.next(&Bar::new().high(10).low(8).close(9));
+        assert_eq!(out.trend_value, lit!(7.0));
+        assert!(out.is_uptrend);
+
+        // basic bands 14 / 8: the upper band is kept, the lower band tightens
+        let out = st.next(&Bar::new().high(12).low(10).close(11));
+        assert_eq!(out.trend_value, lit!(8.0));
+        assert!(out.is_uptrend);
+
+        // basic bands 14 / 10
+        let out = st.next(&Bar::new().high(13).low(11).close(lit!(12.5)));
+        assert_eq!(out.trend_value, lit!(10.0));
+        assert!(out.is_uptrend);
+
+        // basic bands 14.5 / 3.5: the lower band is not loosened, close breaks it
+        let out = st.next(&Bar::new().high(11).low(7).close(lit!(7.5)));
+        assert_eq!(out.trend_value, lit!(14.5));
+        assert!(!out.is_uptrend);
+
+        // basic bands 9 / 5: the upper band tightens
+        let out = st.next(&Bar::new().high(8).low(6).close(7));
+        assert_eq!(out.trend_value, lit!(9.0));
+        assert!(!out.is_uptrend);
+
+        // basic bands 14 / 6: the upper band is kept, close breaks it
+        let out = st.next(&Bar::new().high(11).low(9).close(lit!(10.5)));
+        assert_eq!(out.trend_value, lit!(6.0));
+        assert!(out.is_uptrend);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut st = SuperTrend::new(1, lit!(1.0)).unwrap();
+
+        st.next(&Bar::new().high(10).low(8).close(9));
+        st.next(&Bar::new().high(11).low(7).close(lit!(7.5)));
+
+        st.reset();
+        let out = st.next(&Bar::new().high(10).low(8).close(9));
+        assert_eq!(out.trend_value, lit!(7.0));
+        assert!(out.is_uptrend);
+    }
+
+    #[test]
+    fn test_default() {
+        SuperTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = SuperTrend::default();
+        #[cfg(not(feature = "f64-backend"))]
+        assert_eq!(format!("{}", indicator), "SUPERTREND(10, 3.0)");
+        #[cfg(feature = "f64-backend")]
+        assert_eq!(format!("{}", indicator), "SUPERTREND(10, 3)");
+    }
+}
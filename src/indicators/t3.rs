@@ -0,0 +1,214 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// T3 moving average (T3).
+///
+/// Developed by Tim Tillson, T3 is a smooth, low-lag moving average. It cascades six
+/// [EMAs](struct.ExponentialMovingAverage.html) and combines the last four of them so that
+/// most of the lag of the cascade cancels out.
+///
+/// # Formula
+///
+/// T3 = c<sub>1</sub> * e<sub>6</sub> + c<sub>2</sub> * e<sub>5</sub> + c<sub>3</sub> * e<sub>4</sub> + c<sub>4</sub> * e<sub>3</sub>
+///
+/// Where:
+///
+/// * _e<sub>1</sub>_ = EMA(price, _period_), _e<sub>n</sub>_ = EMA(e<sub>n-1</sub>, _period_)
+/// * _a_ - volume factor
+/// * c<sub>1</sub> = -a<sup>3</sup>
+/// * c<sub>2</sub> = 3a<sup>2</sup> + 3a<sup>3</sup>
+/// * c<sub>3</sub> = -6a<sup>2</sup> - 3a - 3a<sup>3</sup>
+/// * c<sub>4</sub> = 1 + 3a + a<sup>3</sup> + 3a<sup>2</sup>
+///
+/// The coefficients sum up to 1, so T3 of a constant series is that constant.
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMAs (integer greater than 0). Default is 5.
+/// * _volume_factor_ - weight of the lag correction, from 0 to 1. Default is 0.7. With 0 T3
+///   is the triple smoothed EMA _e<sub>3</sub>_.
+///
+/// # Links
+///
+/// * [T3 moving average, TradingView](https://www.tradingview.com/script/7kzBUEHc-T3-Moving-Average/)
+///
+#[doc(alias = "T3")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct T3MovingAverage {
+    period: usize,
+    volume_factor: crate::Num,
+    coefficients: [crate::Num; 4],
+    emas: [Ema; 6],
+}
+
+impl T3MovingAverage {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0 or `volume_factor` is outside of 0..1
+    pub fn new(period: usize, volume_factor: crate::Num) -> Result<Self> {
+        if volume_factor < lit!(0.0) || volume_factor > lit!(1.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let a = volume_factor;
+        let a2 = a * a;
+        let a3 = a2 * a;
+        let ema = Ema::new(period)?;
+
+        Ok(Self {
+            period,
+            volume_factor,
+            coefficients: [
+                -a3,
+                lit!(3.0) * a2 + lit!(3.0) * a3,
+                lit!(-6.0) * a2 - lit!(3.0) * a - lit!(3.0) * a3,
+                lit!(1.0) + lit!(3.0) * a + a3 + lit!(3.0) * a2,
+            ],
+            emas: [
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema,
+            ],
+        })
+    }
+
+    #[must_use]
+    pub fn volume_factor(&self) -> crate::Num {
+        self.volume_factor
+    }
+}
+
+impl Period for T3MovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for T3MovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let mut e = [lit!(0.0); 6];
+        let mut value = input;
+        for (i, ema) in self.emas.iter_mut().enumerate() {
+            value = ema.next(value);
+            e[i] = value;
+        }
+
+        let [c1, c2, c3, c4] = self.coefficients;
+        c1 * e[5] + c2 * e[4] + c3 * e[3] + c4 * e[2]
+    }
+}
+
+impl<T: Close> Next<&T> for T3MovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for T3MovingAverage {
+    fn reset(&mut self) {
+        for ema in self.emas.iter_mut() {
+            ema.reset();
+        }
+    }
+}
+
+impl Default for T3MovingAverage {
+    fn default() -> Self {
+        Self::new(5, lit!(0.7)).unwrap()
+    }
+}
+
+impl fmt::Display for T3MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "T3({}, {})", self.period, self.volume_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(T3MovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(T3MovingAverage::new(0, lit!(0.7)).is_err());
+        assert!(T3MovingAverage::new(5, lit!(-0.1)).is_err());
+        assert!(T3MovingAverage::new(5, lit!(1.1)).is_err());
+        assert!(T3MovingAverage::new(1, lit!(0.0)).is_ok());
+        assert!(T3MovingAverage::new(5, lit!(1.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut t3 = T3MovingAverage::new(3, lit!(0.7)).unwrap();
+
+        assert_eq!(t3.next(lit!(10.0)), lit!(10.0));
+        assert_eq!(round(t3.next(lit!(12.0))), lit!(10.615));
+        assert_eq!(round(t3.next(&Bar::new().close(14))), lit!(11.914));
+    }
+
+    #[test]
+    fn test_next_constant() {
+        let mut t3 = T3MovingAverage::new(3, lit!(0.7)).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(round(t3.next(lit!(10.0))), lit!(10.0));
+        }
+    }
+
+    #[test]
+    fn test_convergence() {
+        let mut t3 = T3MovingAverage::new(3, lit!(0.7)).unwrap();
+
+        t3.next(lit!(10.0));
+        t3.next(lit!(12.0));
+
+        // the lag correction overshoots the new level before settling on it
+        let mut last = t3.next(lit!(14.0));
+        let mut max = last;
+        for _ in 0..40 {
+            last = t3.next(lit!(14.0));
+            max = max.max(last);
+        }
+        assert!(max > lit!(14.1));
+        assert!((last - lit!(14.0)).abs() < lit!(0.001));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut t3 = T3MovingAverage::new(3, lit!(0.7)).unwrap();
+
+        t3.next(lit!(20.0));
+        t3.next(lit!(30.0));
+
+        t3.reset();
+        assert_eq!(t3.next(lit!(10.0)), lit!(10.0));
+        assert_eq!(round(t3.next(lit!(12.0))), lit!(10.615));
+    }
+
+    #[test]
+    fn test_default() {
+        T3MovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = T3MovingAverage::default();
+        assert_eq!(format!("{}", indicator), "T3(5, 0.7)");
+    }
+}
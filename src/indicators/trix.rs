@@ -0,0 +1,184 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple Exponential Average (TRIX).
+///
+/// TRIX is the one-period percent rate of change of a triple-smoothed exponential moving
+/// average. The triple smoothing filters out price movements that are insignificant for the
+/// trend, so TRIX oscillates around zero similarly to MACD.
+///
+/// # Formula
+///
+/// EMA3<sub>t</sub> = EMA(EMA(EMA(price)))
+///
+/// TRIX = 100 * (EMA3<sub>t</sub> - EMA3<sub>t-1</sub>) / EMA3<sub>t-1</sub>
+///
+/// Where:
+///
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html) with the given _period_
+///
+/// The first output is 0, since there is no previous EMA3 value yet.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of each EMA (integer greater than 0). Default is 15.
+///
+/// # Links
+///
+/// * [TRIX, Wikipedia](https://en.wikipedia.org/wiki/Trix_(technical_analysis))
+///
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Trix {
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+    prev: Option<crate::Num>,
+}
+
+impl Trix {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+            ema3: Ema::new(period)?,
+            prev: None,
+        })
+    }
+}
+
+impl Period for Trix {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<crate::Num> for Trix {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        let ema3 = self.ema3.next(ema2);
+
+        match self.prev.replace(ema3) {
+            Some(prev) if prev != lit!(0.0) => lit!(100.0) * (ema3 - prev) / prev,
+            _ => lit!(0.0),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Trix {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Trix {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for Trix {
+    fn default() -> Self {
+        Self::new(15).unwrap()
+    }
+}
+
+impl fmt::Display for Trix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRIX({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Trix);
+
+    #[test]
+    fn test_new() {
+        assert!(Trix::new(0).is_err());
+        assert!(Trix::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut trix = Trix::new(1).unwrap();
+
+        // with period 1 every EMA is the input itself
+        assert_eq!(trix.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(trix.next(lit!(11.0)), lit!(10.0));
+        assert_eq!(round_float(trix.next(lit!(8.8))), lit!(-20.0));
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut trix = Trix::new(3).unwrap();
+
+        trix.next(lit!(10.0));
+        assert!(trix.next(lit!(20.0)) > lit!(0.0));
+
+        // the triple smoothing is still catching up with the jump for a few bars
+        for _ in 0..5 {
+            trix.next(lit!(20.0));
+        }
+
+        let mut prev = trix.next(lit!(20.0));
+        for _ in 0..30 {
+            let value = trix.next(lit!(20.0));
+            assert!(value >= lit!(0.0));
+            assert!(value < prev);
+            prev = value;
+        }
+        assert!(prev < lit!(0.001));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut trix = Trix::new(1).unwrap();
+
+        assert_eq!(trix.next(&Bar::new().close(10)), lit!(0.0));
+        assert_eq!(trix.next(&Bar::new().close(11)), lit!(10.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut trix = Trix::new(1).unwrap();
+
+        trix.next(lit!(10.0));
+        trix.next(lit!(12.0));
+
+        trix.reset();
+        assert_eq!(trix.next(lit!(10.0)), lit!(0.0));
+        assert_eq!(trix.next(lit!(11.0)), lit!(10.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Trix::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Trix::new(15).unwrap();
+        assert_eq!(format!("{}", indicator), "TRIX(15)");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::helpers::max3;
 use crate::{lit, Close, High, Low, Next, Reset};
@@ -21,7 +21,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct TrueRange {
-    prev_close: Option<rust_decimal::Decimal>,
+    prev_close: Option<crate::Num>,
 }
 
 impl TrueRange {
@@ -43,10 +43,10 @@ impl fmt::Display for TrueRange {
     }
 }
 
-impl Next<rust_decimal::Decimal> for TrueRange {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for TrueRange {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
+    fn next(&mut self, input: crate::Num) -> Self::Output {
         let distance = match self.prev_close {
             Some(prev) => (input - prev).abs(),
             None => lit!(0.0),
@@ -57,7 +57,7 @@ impl Next<rust_decimal::Decimal> for TrueRange {
 }
 
 impl<T: High + Low + Close> Next<&T> for TrueRange {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, bar: &T) -> Self::Output {
         let max_dist = match self.prev_close {
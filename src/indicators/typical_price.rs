@@ -0,0 +1,128 @@
+use core::fmt;
+
+use crate::{Close, High, Low, Next, Reset, TypicalPriceExt};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Typical Price (TP).
+///
+/// The average of the high, the low and the close of the bar. It is stateless, so it can be
+/// used to turn a stream of bars into a stream of prices for another indicator, the way
+/// e.g. [KeltnerChannel](struct.KeltnerChannel.html) and
+/// [CommodityChannelIndex](struct.CommodityChannelIndex.html) average it.
+///
+/// # Formula
+///
+/// TP = (high + low + close) / 3
+///
+/// The same value is available on any bar through [`TypicalPriceExt::typical_price`].
+///
+#[doc(alias = "TP")]
+#[doc(alias = "HLC3")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TypicalPrice {}
+
+impl TypicalPrice {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for TypicalPrice {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        input.typical_price()
+    }
+}
+
+impl Reset for TypicalPrice {
+    fn reset(&mut self) {}
+}
+
+impl Default for TypicalPrice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for TypicalPrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TYPICAL_PRICE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+    use crate::lit;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut tp = TypicalPrice::new();
+
+        assert_eq!(tp.next(&Bar::new().high(12).low(9).close(9)), lit!(10.0));
+        assert_eq!(tp.next(&Bar::new().high(12).low(8).close(10)), lit!(10.0));
+        assert_eq!(tp.next(&Bar::new().high(13).low(8).close(12)), lit!(11.0));
+    }
+
+    #[test]
+    fn test_same_as_inline() {
+        let mut tp = TypicalPrice::new();
+
+        let bars = [
+            Bar::new()
+                .high(lit!(10.5))
+                .low(lit!(9.25))
+                .close(lit!(10.0)),
+            Bar::new()
+                .high(lit!(103.2))
+                .low(lit!(98.7))
+                .close(lit!(99.1)),
+            Bar::new()
+                .high(lit!(0.031))
+                .low(lit!(0.029))
+                .close(lit!(0.03)),
+        ];
+        for bar in bars {
+            let inline = (Close::close(&bar) + High::high(&bar) + Low::low(&bar)) / lit!(3.0);
+            assert_eq!(tp.next(&bar), inline);
+            assert_eq!(bar.typical_price(), inline);
+        }
+    }
+
+    #[test]
+    fn test_into_sma() {
+        let mut tp = TypicalPrice::new();
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+
+        let bar1 = Bar::new().high(12).low(9).close(9);
+        let bar2 = Bar::new().high(13).low(8).close(12);
+        assert_eq!(sma.next(tp.next(&bar1)), lit!(10.0));
+        assert_eq!(sma.next(tp.next(&bar2)), lit!(10.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tp = TypicalPrice::new();
+
+        tp.next(&Bar::new().high(12).low(8).close(12));
+        tp.reset();
+        assert_eq!(tp.next(&Bar::new().high(12).low(8).close(10)), lit!(10.0));
+    }
+
+    #[test]
+    fn test_default() {
+        TypicalPrice::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = TypicalPrice::new();
+        assert_eq!(format!("{}", indicator), "TYPICAL_PRICE");
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, num, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ulcer Index (UI).
+///
+/// Developed by Peter Martin, the Ulcer Index measures downside volatility: the depth and
+/// the duration of drawdowns from recent highs. Unlike the standard deviation, price
+/// increases do not raise it.
+///
+/// # Formula
+///
+/// R<sub>i</sub> = 100 * (close<sub>i</sub> - max<sub>i</sub>) / max<sub>i</sub>
+///
+/// UI = sqrt(sum(R<sub>i</sub><sup>2</sup>) / _n_)
+///
+/// Where:
+///
+/// * _max<sub>i</sub>_ - highest close from the start of the window up to bar _i_
+/// * _n_ - number of closes in the window, at most _period_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Ulcer index, Wikipedia](https://en.wikipedia.org/wiki/Ulcer_index)
+///
+#[doc(alias = "ULCER")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UlcerIndex {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[crate::Num]>,
+}
+
+impl UlcerIndex {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![lit!(0.0); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for UlcerIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for UlcerIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        // walk the window from the oldest close to the newest one
+        let oldest = if self.count < self.period {
+            0
+        } else {
+            self.index
+        };
+        let mut max = self.deque[oldest];
+        let mut sum = lit!(0.0);
+        for i in 0..self.count {
+            let close = self.deque[(oldest + i) % self.period];
+            max = max.max(close);
+            if max != lit!(0.0) {
+                let drawdown = lit!(100.0) * (close - max) / max;
+                sum += drawdown * drawdown;
+            }
+        }
+
+        num::sqrt(sum / int!(self.count))
+    }
+}
+
+impl<T: Close> Next<&T> for UlcerIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for UlcerIndex {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = lit!(0.0);
+        }
+    }
+}
+
+impl Default for UlcerIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for UlcerIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ULCER({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(UlcerIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(UlcerIndex::new(0).is_err());
+        assert!(UlcerIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_uptrend() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        for close in [10, 11, 12, 13, 14, 15] {
+            assert_eq!(ui.next(int!(close)), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_next_decline() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        assert_eq!(ui.next(lit!(10.0)), lit!(0.0));
+        // drawdowns 0, -20
+        assert_eq!(round(ui.next(lit!(8.0))), lit!(14.142));
+        // drawdowns 0, -20, -50
+        assert_eq!(round(ui.next(lit!(5.0))), lit!(31.091));
+        // 10 leaves the window, drawdowns 0, -37.5, -37.5
+        assert_eq!(round(ui.next(lit!(5.0))), lit!(30.619));
+        // the decline has left the window
+        assert_eq!(ui.next(lit!(9.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        assert_eq!(ui.next(&Bar::new().close(10)), lit!(0.0));
+        assert_eq!(round(ui.next(&Bar::new().close(8))), lit!(14.142));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ui = UlcerIndex::new(3).unwrap();
+
+        ui.next(lit!(10.0));
+        ui.next(lit!(5.0));
+
+        ui.reset();
+        assert_eq!(ui.next(lit!(5.0)), lit!(0.0));
+        assert_eq!(ui.next(lit!(6.0)), lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        UlcerIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = UlcerIndex::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "ULCER(14)");
+    }
+}
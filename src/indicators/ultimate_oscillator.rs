@@ -0,0 +1,281 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{lit, Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ultimate Oscillator (UO).
+///
+/// Developed by Larry Williams, the Ultimate Oscillator measures buying pressure over three
+/// timeframes and combines them into a weighted average, which reduces the false divergences
+/// of single timeframe oscillators. The oscillator returns output in the range of 0..100.
+///
+/// # Formula
+///
+/// BP = close - min(low, prev_close)
+///
+/// TR = max(high, prev_close) - min(low, prev_close)
+///
+/// Avg<sub>n</sub> = sum(BP, n) / sum(TR, n)
+///
+/// UO = 100 * (w<sub>1</sub> * Avg<sub>short</sub> + w<sub>2</sub> * Avg<sub>medium</sub> + w<sub>3</sub> * Avg<sub>long</sub>) / (w<sub>1</sub> + w<sub>2</sub> + w<sub>3</sub>)
+///
+/// Where:
+///
+/// * _BP_ - buying pressure
+/// * _TR_ - true range
+/// * _sum(x, n)_ - sum of _x_ over the last _n_ periods
+///
+/// For the first bar there is no previous close, so its low and high are used. If the true
+/// range of a timeframe is 0, its average is 0.
+///
+/// # Parameters
+///
+/// * _short_ - short period (integer greater than 0). Default is 7.
+/// * _medium_ - medium period (integer greater than 0). Default is 14.
+/// * _long_ - long period (integer greater than 0). Default is 28.
+/// * _weights_ - weights of the short, medium and long averages (greater than 0). Default is 4, 2 and 1.
+///
+/// # Links
+///
+/// * [Ultimate Oscillator, Wikipedia](https://en.wikipedia.org/wiki/Ultimate_oscillator)
+///
+#[doc(alias = "UO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UltimateOscillator {
+    periods: [usize; 3],
+    weights: [crate::Num; 3],
+    index: usize,
+    count: usize,
+    prev_close: Option<crate::Num>,
+    sum_bp: [crate::Num; 3],
+    sum_tr: [crate::Num; 3],
+    deque: Box<[(crate::Num, crate::Num)]>,
+}
+
+impl UltimateOscillator {
+    /// Creates an Ultimate Oscillator with the default weights of 4, 2 and 1.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if any period is 0
+    pub fn new(short: usize, medium: usize, long: usize) -> Result<Self> {
+        Self::new_with_weights(short, medium, long, lit!(4.0), lit!(2.0), lit!(1.0))
+    }
+
+    /// # Errors
+    ///
+    /// Will return `Err` if any period is 0 or any weight is not positive
+    pub fn new_with_weights(
+        short: usize,
+        medium: usize,
+        long: usize,
+        short_weight: crate::Num,
+        medium_weight: crate::Num,
+        long_weight: crate::Num,
+    ) -> Result<Self> {
+        let periods = [short, medium, long];
+        let weights = [short_weight, medium_weight, long_weight];
+        if periods.contains(&0) {
+            return Err(TaError::InvalidPeriod { got: 0 });
+        }
+        if weights.iter().any(|&w| w <= lit!(0.0)) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let length = short.max(medium).max(long);
+        Ok(Self {
+            periods,
+            weights,
+            index: 0,
+            count: 0,
+            prev_close: None,
+            sum_bp: [lit!(0.0); 3],
+            sum_tr: [lit!(0.0); 3],
+            deque: vec![(lit!(0.0), lit!(0.0)); length].into_boxed_slice(),
+        })
+    }
+
+    #[must_use]
+    pub fn periods(&self) -> (usize, usize, usize) {
+        (self.periods[0], self.periods[1], self.periods[2])
+    }
+
+    #[must_use]
+    pub fn weights(&self) -> (crate::Num, crate::Num, crate::Num) {
+        (self.weights[0], self.weights[1], self.weights[2])
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for UltimateOscillator {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (low, high) = match self.prev_close {
+            Some(prev_close) => (input.low().min(prev_close), input.high().max(prev_close)),
+            None => (input.low(), input.high()),
+        };
+        let bp = input.close() - low;
+        let tr = high - low;
+        self.prev_close = Some(input.close());
+
+        let length = self.deque.len();
+        for i in 0..3 {
+            let period = self.periods[i];
+            // the value leaving this window, if it is already full
+            if self.count >= period {
+                let (old_bp, old_tr) = self.deque[(self.index + length - period) % length];
+                self.sum_bp[i] -= old_bp;
+                self.sum_tr[i] -= old_tr;
+            }
+            self.sum_bp[i] += bp;
+            self.sum_tr[i] += tr;
+        }
+        if self.count < length {
+            self.count += 1;
+        }
+
+        self.deque[self.index] = (bp, tr);
+        self.index = if self.index + 1 < length {
+            self.index + 1
+        } else {
+            0
+        };
+
+        let mut weighted = lit!(0.0);
+        for i in 0..3 {
+            if self.sum_tr[i] != lit!(0.0) {
+                weighted += self.weights[i] * self.sum_bp[i] / self.sum_tr[i];
+            }
+        }
+
+        lit!(100.0) * weighted / (self.weights[0] + self.weights[1] + self.weights[2])
+    }
+}
+
+impl Reset for UltimateOscillator {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.prev_close = None;
+        self.sum_bp = [lit!(0.0); 3];
+        self.sum_tr = [lit!(0.0); 3];
+        for i in 0..self.deque.len() {
+            self.deque[i] = (lit!(0.0), lit!(0.0));
+        }
+    }
+}
+
+impl Default for UltimateOscillator {
+    fn default() -> Self {
+        Self::new(7, 14, 28).unwrap()
+    }
+}
+
+impl fmt::Display for UltimateOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "UO({},{},{})",
+            self.periods[0], self.periods[1], self.periods[2]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Uo = UltimateOscillator;
+
+    #[test]
+    fn test_new() {
+        assert!(Uo::new(0, 2, 3).is_err());
+        assert!(Uo::new(1, 0, 3).is_err());
+        assert!(Uo::new(1, 2, 0).is_err());
+        assert!(Uo::new(1, 2, 3).is_ok());
+
+        assert!(Uo::new_with_weights(1, 2, 3, lit!(0.0), lit!(2.0), lit!(1.0)).is_err());
+        assert!(Uo::new_with_weights(1, 2, 3, lit!(4.0), lit!(2.0), lit!(1.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut uo = Uo::new(1, 2, 3).unwrap();
+
+        // bp = 1, tr = 2
+        let bar1 = Bar::new().high(11).low(9).close(10);
+        // bp = 2, tr = 2
+        let bar2 = Bar::new().high(12).low(10).close(12);
+        // low = min(9, 12) = 9, high = max(11, 12) = 12: bp = 1, tr = 3
+        let bar3 = Bar::new().high(11).low(9).close(10);
+        // bp = 0, tr = 1
+        let bar4 = Bar::new().high(11).low(10).close(10);
+
+        // all averages are 1 / 2
+        assert_eq!(uo.next(&bar1), lit!(50.0));
+        // 100 * (4 * 1 + 2 * 3/4 + 1 * 3/4) / 7
+        assert_eq!(round(uo.next(&bar2)), lit!(89.286));
+        // 100 * (4 * 1/3 + 2 * 3/5 + 1 * 4/7) / 7
+        assert_eq!(round(uo.next(&bar3)), lit!(44.354));
+        // bar1 leaves the long window: 100 * (4 * 0 + 2 * 1/4 + 1 * 3/6) / 7
+        assert_eq!(round(uo.next(&bar4)), lit!(14.286));
+    }
+
+    #[test]
+    fn test_outlier_leaves_windows() {
+        let mut uo = Uo::new(1, 1, 2).unwrap();
+
+        // bp = 2, tr = 2
+        assert_eq!(uo.next(&Bar::new().high(11).low(9).close(11)), lit!(100.0));
+
+        // bp = 0, tr = 2 from here on
+        let bar = Bar::new().high(11).low(9).close(9);
+        // the outlier is only left in the long window: 100 * (1 * 2/4) / 7
+        assert_eq!(round(uo.next(&bar)), lit!(7.143));
+        for _ in 0..20 {
+            assert_eq!(uo.next(&bar), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut uo = Uo::new(1, 2, 3).unwrap();
+        let bar = Bar::new().high(10).low(10).close(10);
+
+        for _ in 0..5 {
+            assert_eq!(uo.next(&bar), lit!(0.0));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut uo = Uo::new(1, 2, 3).unwrap();
+
+        let bar1 = Bar::new().high(11).low(9).close(10);
+        let bar2 = Bar::new().high(12).low(10).close(12);
+
+        uo.next(&bar1);
+        uo.next(&bar2);
+
+        uo.reset();
+        assert_eq!(uo.next(&bar1), lit!(50.0));
+        assert_eq!(round(uo.next(&bar2)), lit!(89.286));
+    }
+
+    #[test]
+    fn test_default() {
+        Uo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Uo::default();
+        assert_eq!(format!("{}", indicator), "UO(7,14,28)");
+    }
+}
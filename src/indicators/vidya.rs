@@ -0,0 +1,200 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ChandeMomentumOscillator as Cmo;
+use crate::{int, lit, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Variable Index Dynamic Average (VIDYA).
+///
+/// Developed by Tushar Chande, VIDYA is an exponential moving average whose smoothing
+/// constant is scaled by the absolute value of the
+/// [Chande Momentum Oscillator](struct.ChandeMomentumOscillator.html). It follows the price
+/// like an EMA in a strong trend and flattens out when the price moves sideways.
+///
+/// # Formula
+///
+/// k = α * |CMO| / 100
+///
+/// VIDYA<sub>t</sub> = k * price + (1 - k) * VIDYA<sub>t-1</sub>
+///
+/// Where:
+///
+/// * _α_ = 2 / (_period_ + 1), the smoothing constant of an EMA
+/// * _CMO_ - [Chande Momentum Oscillator](struct.ChandeMomentumOscillator.html)
+///
+/// The first price seeds the indicator.
+///
+/// # Parameters
+///
+/// * _period_ - period of the smoothing constant (integer greater than 0). Default is 14.
+/// * _cmo_period_ - period of the CMO (integer greater than 0). Default is 9.
+///
+/// # Links
+///
+/// * [Variable Index Dynamic Average, TradingView](https://www.tradingview.com/support/solutions/43000664187-variable-index-dynamic-average-vidya/)
+///
+#[doc(alias = "VIDYA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Vidya {
+    period: usize,
+    alpha: crate::Num,
+    cmo: Cmo,
+    current: Option<crate::Num>,
+}
+
+impl Vidya {
+    /// # Errors
+    ///
+    /// Will return `Err` if any of the periods is 0
+    pub fn new(period: usize, cmo_period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                alpha: lit!(2.0) / int!(period + 1),
+                cmo: Cmo::new(cmo_period)?,
+                current: None,
+            }),
+        }
+    }
+}
+
+impl Period for Vidya {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<crate::Num> for Vidya {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let k = self.alpha * self.cmo.next(input).abs() / lit!(100.0);
+        let vidya = match self.current {
+            Some(prev) => k * input + (lit!(1.0) - k) * prev,
+            None => input,
+        };
+
+        self.current = Some(vidya);
+        vidya
+    }
+}
+
+impl<T: Close> Next<&T> for Vidya {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Vidya {
+    fn reset(&mut self) {
+        self.cmo.reset();
+        self.current = None;
+    }
+}
+
+impl Default for Vidya {
+    fn default() -> Self {
+        Self::new(14, 9).unwrap()
+    }
+}
+
+impl fmt::Display for Vidya {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VIDYA({},{})", self.period, self.cmo.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(Vidya);
+
+    #[test]
+    fn test_new() {
+        assert!(Vidya::new(0, 9).is_err());
+        assert!(Vidya::new(14, 0).is_err());
+        assert!(Vidya::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+
+        assert_eq!(vidya.next(lit!(10.0)), lit!(10.0));
+        // cmo = 100, k = 0.5
+        assert_eq!(vidya.next(lit!(12.0)), lit!(11.0));
+        // cmo = 100 * (2 - 1) / 3, k = 1/6
+        assert_eq!(round(vidya.next(&Bar::new().close(11))), lit!(11.0));
+        // cmo = 100 * (0 - 3) / 3, k = 0.5
+        assert_eq!(vidya.next(lit!(8.0)), lit!(9.5));
+    }
+
+    #[test]
+    fn test_trend_follows_ema() {
+        let mut vidya = Vidya::new(5, 3).unwrap();
+        let mut ema = ExponentialMovingAverage::new(5).unwrap();
+
+        // |CMO| is 100 in a steady trend, VIDYA becomes an EMA
+        for close in 10..20 {
+            assert_eq!(
+                round_float(vidya.next(int!(close))),
+                round_float(ema.next(int!(close)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_adaptive_on_volatile_series() {
+        let mut vidya = Vidya::new(5, 4).unwrap();
+        let mut ema = ExponentialMovingAverage::new(5).unwrap();
+
+        let mut vidya_prev = vidya.next(lit!(10.0));
+        let mut ema_prev = ema.next(lit!(10.0));
+        let mut vidya_movement = lit!(0.0);
+        let mut ema_movement = lit!(0.0);
+
+        // the price swings without going anywhere
+        for close in [14, 9, 13, 8, 14, 10, 13, 9, 12, 10] {
+            let vidya_value = vidya.next(int!(close));
+            let ema_value = ema.next(int!(close));
+            vidya_movement += (vidya_value - vidya_prev).abs();
+            ema_movement += (ema_value - ema_prev).abs();
+            vidya_prev = vidya_value;
+            ema_prev = ema_value;
+        }
+
+        assert!(vidya_movement < ema_movement / lit!(2.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vidya = Vidya::new(3, 2).unwrap();
+
+        vidya.next(lit!(10.0));
+        vidya.next(lit!(12.0));
+
+        vidya.reset();
+        assert_eq!(vidya.next(lit!(20.0)), lit!(20.0));
+        assert_eq!(vidya.next(lit!(22.0)), lit!(21.0));
+    }
+
+    #[test]
+    fn test_default() {
+        Vidya::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Vidya::default();
+        assert_eq!(format!("{}", indicator), "VIDYA(14,9)");
+    }
+}
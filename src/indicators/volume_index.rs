@@ -0,0 +1,254 @@
+use core::fmt;
+
+use crate::{lit, Close, Next, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Common state of the negative and positive volume indices.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct VolumeIndex {
+    value: crate::Num,
+    prev: Option<(crate::Num, crate::Num)>,
+}
+
+impl VolumeIndex {
+    fn new() -> Self {
+        Self {
+            value: lit!(1000.0),
+            prev: None,
+        }
+    }
+
+    /// Applies the change of the close when `update` accepts the current and the previous
+    /// volume.
+    fn next<T: Close + Volume>(
+        &mut self,
+        input: &T,
+        update: fn(crate::Num, crate::Num) -> bool,
+    ) -> crate::Num {
+        let close = input.close();
+        let volume = input.volume();
+
+        if let Some((prev_close, prev_volume)) = self.prev.replace((close, volume)) {
+            if update(volume, prev_volume) && prev_close != lit!(0.0) {
+                self.value += self.value * (close - prev_close) / prev_close;
+            }
+        }
+
+        self.value
+    }
+
+    fn reset(&mut self) {
+        self.value = lit!(1000.0);
+        self.prev = None;
+    }
+}
+
+/// Negative Volume Index (NVI).
+///
+/// Developed by Paul Dysart and popularized by Norman Fosback, the NVI follows the price
+/// changes of the days on which the volume decreases, assuming that smart money is active
+/// on quiet days.
+///
+/// # Formula
+///
+/// If volume < volume<sub>t-1</sub>:
+///
+/// NVI<sub>t</sub> = NVI<sub>t-1</sub> + NVI<sub>t-1</sub> * (close - close<sub>t-1</sub>) / close<sub>t-1</sub>
+///
+/// Otherwise NVI<sub>t</sub> = NVI<sub>t-1</sub>.
+///
+/// The index starts from a base of 1000, which is returned for the first bar.
+///
+/// # Links
+///
+/// * [Negative volume index, Wikipedia](https://en.wikipedia.org/wiki/Negative_volume_index)
+///
+#[doc(alias = "NVI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct NegativeVolumeIndex {
+    index: VolumeIndex,
+}
+
+impl NegativeVolumeIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            index: VolumeIndex::new(),
+        }
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for NegativeVolumeIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.index
+            .next(input, |volume, prev_volume| volume < prev_volume)
+    }
+}
+
+impl Reset for NegativeVolumeIndex {
+    fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+impl Default for NegativeVolumeIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for NegativeVolumeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NVI")
+    }
+}
+
+/// Positive Volume Index (PVI).
+///
+/// The counterpart of the [NVI](struct.NegativeVolumeIndex.html): it follows the price
+/// changes of the days on which the volume increases, assuming that the crowd is active on
+/// busy days.
+///
+/// # Formula
+///
+/// If volume > volume<sub>t-1</sub>:
+///
+/// PVI<sub>t</sub> = PVI<sub>t-1</sub> + PVI<sub>t-1</sub> * (close - close<sub>t-1</sub>) / close<sub>t-1</sub>
+///
+/// Otherwise PVI<sub>t</sub> = PVI<sub>t-1</sub>.
+///
+/// The index starts from a base of 1000, which is returned for the first bar.
+///
+/// # Links
+///
+/// * [Positive volume index, Wikipedia](https://en.wikipedia.org/wiki/Positive_volume_index)
+///
+#[doc(alias = "PVI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PositiveVolumeIndex {
+    index: VolumeIndex,
+}
+
+impl PositiveVolumeIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            index: VolumeIndex::new(),
+        }
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for PositiveVolumeIndex {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.index
+            .next(input, |volume, prev_volume| volume > prev_volume)
+    }
+}
+
+impl Reset for PositiveVolumeIndex {
+    fn reset(&mut self) {
+        self.index.reset();
+    }
+}
+
+impl Default for PositiveVolumeIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for PositiveVolumeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PVI")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_nvi_next() {
+        let mut nvi = NegativeVolumeIndex::new();
+
+        assert_eq!(nvi.next(&Bar::new().close(10).volume(1000)), lit!(1000.0));
+        // volume decreases, +10%
+        assert_eq!(nvi.next(&Bar::new().close(11).volume(500)), lit!(1100.0));
+        // volume increases, unchanged
+        assert_eq!(nvi.next(&Bar::new().close(22).volume(800)), lit!(1100.0));
+        // volume is the same, unchanged
+        assert_eq!(nvi.next(&Bar::new().close(11).volume(800)), lit!(1100.0));
+        // volume decreases, -50%
+        assert_eq!(
+            nvi.next(&Bar::new().close(lit!(5.5)).volume(100)),
+            lit!(550.0)
+        );
+    }
+
+    #[test]
+    fn test_pvi_next() {
+        let mut pvi = PositiveVolumeIndex::new();
+
+        assert_eq!(pvi.next(&Bar::new().close(10).volume(1000)), lit!(1000.0));
+        // volume decreases, unchanged
+        assert_eq!(pvi.next(&Bar::new().close(11).volume(500)), lit!(1000.0));
+        // volume increases, +100%
+        assert_eq!(pvi.next(&Bar::new().close(22).volume(800)), lit!(2000.0));
+        // volume is the same, unchanged
+        assert_eq!(pvi.next(&Bar::new().close(11).volume(800)), lit!(2000.0));
+        // volume increases, -50%
+        assert_eq!(
+            pvi.next(&Bar::new().close(lit!(5.5)).volume(900)),
+            lit!(1000.0)
+        );
+    }
+
+    #[test]
+    fn test_next_zero_prev_close() {
+        let mut pvi = PositiveVolumeIndex::new();
+
+        pvi.next(&Bar::new().close(0).volume(100));
+        assert_eq!(pvi.next(&Bar::new().close(10).volume(200)), lit!(1000.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut nvi = NegativeVolumeIndex::new();
+        let mut pvi = PositiveVolumeIndex::new();
+
+        let bar1 = Bar::new().close(10).volume(1000);
+        let bar2 = Bar::new().close(11).volume(500);
+        let bar3 = Bar::new().close(22).volume(800);
+
+        nvi.next(&bar1);
+        nvi.next(&bar2);
+        pvi.next(&bar2);
+        pvi.next(&bar3);
+
+        nvi.reset();
+        pvi.reset();
+        assert_eq!(nvi.next(&bar2), lit!(1000.0));
+        assert_eq!(pvi.next(&bar3), lit!(1000.0));
+    }
+
+    #[test]
+    fn test_default() {
+        NegativeVolumeIndex::default();
+        PositiveVolumeIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", NegativeVolumeIndex::new()), "NVI");
+        assert_eq!(format!("{}", PositiveVolumeIndex::new()), "PVI");
+    }
+}
@@ -0,0 +1,255 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::TrueRange;
+use crate::{lit, Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Vortex Indicator (VI).
+///
+/// Developed by Etienne Botes and Douglas Siepman, the Vortex Indicator consists of two
+/// lines capturing positive and negative trend movement. A crossover of VI+ above VI-
+/// signals the start of an uptrend, and the other way round.
+///
+/// # Formula
+///
+/// VM+ = |high - low<sub>t-1</sub>|
+///
+/// VM- = |low - high<sub>t-1</sub>|
+///
+/// VI+ = sum(VM+, _period_) / sum(TR, _period_)
+///
+/// VI- = sum(VM-, _period_) / sum(TR, _period_)
+///
+/// Where:
+///
+/// * _TR_ - [true range](struct.TrueRange.html)
+///
+/// The first bar has no previous high and low, so both of its movements are 0. If the sum
+/// of the true range is 0, both lines are 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Links
+///
+/// * [Vortex indicator, Wikipedia](https://en.wikipedia.org/wiki/Vortex_indicator)
+///
+#[doc(alias = "VTX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VortexIndicator {
+    period: usize,
+    index: usize,
+    count: usize,
+    true_range: TrueRange,
+    prev: Option<(crate::Num, crate::Num)>,
+    sum_vm_plus: crate::Num,
+    sum_vm_minus: crate::Num,
+    sum_tr: crate::Num,
+    deque: Box<[(crate::Num, crate::Num, crate::Num)]>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VortexIndicatorOutput {
+    pub vi_plus: crate::Num,
+    pub vi_minus: crate::Num,
+}
+
+impl From<VortexIndicatorOutput> for (crate::Num, crate::Num) {
+    fn from(vo: VortexIndicatorOutput) -> Self {
+        (vo.vi_plus, vo.vi_minus)
+    }
+}
+
+impl VortexIndicator {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                true_range: TrueRange::new(),
+                prev: None,
+                sum_vm_plus: lit!(0.0),
+                sum_vm_minus: lit!(0.0),
+                sum_tr: lit!(0.0),
+                deque: vec![(lit!(0.0), lit!(0.0), lit!(0.0)); period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for VortexIndicator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for VortexIndicator {
+    type Output = VortexIndicatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let high = input.high();
+        let low = input.low();
+        let tr = self.true_range.next(input);
+
+        let (vm_plus, vm_minus) = match self.prev.replace((high, low)) {
+            Some((prev_high, prev_low)) => ((high - prev_low).abs(), (low - prev_high).abs()),
+            None => (lit!(0.0), lit!(0.0)),
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let (old_vm_plus, old_vm_minus, old_tr) = self.deque[self.index];
+            self.sum_vm_plus -= old_vm_plus;
+            self.sum_vm_minus -= old_vm_minus;
+            self.sum_tr -= old_tr;
+        }
+
+        self.deque[self.index] = (vm_plus, vm_minus, tr);
+        self.sum_vm_plus += vm_plus;
+        self.sum_vm_minus += vm_minus;
+        self.sum_tr += tr;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.sum_tr == lit!(0.0) {
+            VortexIndicatorOutput {
+                vi_plus: lit!(0.0),
+                vi_minus: lit!(0.0),
+            }
+        } else {
+            VortexIndicatorOutput {
+                vi_plus: self.sum_vm_plus / self.sum_tr,
+                vi_minus: self.sum_vm_minus / self.sum_tr,
+            }
+        }
+    }
+}
+
+impl Reset for VortexIndicator {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.true_range.reset();
+        self.prev = None;
+        self.sum_vm_plus = lit!(0.0);
+        self.sum_vm_minus = lit!(0.0);
+        self.sum_tr = lit!(0.0);
+        for i in 0..self.period {
+            self.deque[i] = (lit!(0.0), lit!(0.0), lit!(0.0));
+        }
+    }
+}
+
+impl Default for VortexIndicator {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for VortexIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VTX({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VortexIndicator::new(0).is_err());
+        assert!(VortexIndicator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vi = VortexIndicator::new(2).unwrap();
+
+        // tr = 2
+        let bar1 = Bar::new().high(11).low(9).close(10);
+        // vm+ = 3, vm- = 1, tr = 2
+        let bar2 = Bar::new().high(12).low(10).close(11);
+        // vm+ = 1, vm- = 3, tr = 2
+        let bar3 = Bar::new().high(11).low(9).close(10);
+
+        let out = vi.next(&bar1);
+        assert_eq!(out.vi_plus, lit!(0.0));
+        assert_eq!(out.vi_minus, lit!(0.0));
+
+        let out = vi.next(&bar2);
+        assert_eq!(out.vi_plus, lit!(0.75));
+        assert_eq!(out.vi_minus, lit!(0.25));
+
+        // bar1 leaves the window
+        let out = vi.next(&bar3);
+        assert_eq!(out.vi_plus, lit!(1.0));
+        assert_eq!(out.vi_minus, lit!(1.0));
+    }
+
+    #[test]
+    fn test_next_uptrend() {
+        let mut vi = VortexIndicator::new(3).unwrap();
+
+        vi.next(&Bar::new().high(11).low(9).close(10));
+        for i in 1..10 {
+            let bar = Bar::new().high(11 + i).low(9 + i).close(10 + i);
+            let out = vi.next(&bar);
+            assert!(out.vi_plus > out.vi_minus);
+        }
+    }
+
+    #[test]
+    fn test_next_zero_range() {
+        let mut vi = VortexIndicator::new(2).unwrap();
+
+        let bar = Bar::new().high(10).low(10).close(10);
+        vi.next(&bar);
+        let out = vi.next(&bar);
+        assert_eq!(out.vi_plus, lit!(0.0));
+        assert_eq!(out.vi_minus, lit!(0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vi = VortexIndicator::new(2).unwrap();
+
+        let bar1 = Bar::new().high(11).low(9).close(10);
+        let bar2 = Bar::new().high(12).low(10).close(11);
+
+        vi.next(&bar1);
+        vi.next(&bar2);
+
+        vi.reset();
+        assert_eq!(vi.next(&bar2).vi_plus, lit!(0.0));
+    }
+
+    #[test]
+    fn test_default() {
+        VortexIndicator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VortexIndicator::default();
+        assert_eq!(format!("{}", indicator), "VTX(14)");
+    }
+}
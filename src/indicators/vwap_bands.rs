@@ -0,0 +1,235 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{lit, num, Bands, Close, High, Low, Next, Reset, TypicalPriceExt, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Weighted Average Price (VWAP) with standard deviation bands.
+///
+/// VWAP is the average typical price weighted by volume, accumulated from the start of the
+/// session. The bands are placed _multiplier_ volume weighted standard deviations of the
+/// typical price around the VWAP and are commonly used for mean reversion.
+///
+/// The indicator accumulates until it is reset, call [`reset`](Reset::reset) at every
+/// session boundary.
+///
+/// # Formula
+///
+/// TP = (high + low + close) / 3
+///
+/// VWAP = Σ(TP * volume) / Σ(volume)
+///
+/// σ<sup>2</sup> = Σ(TP<sup>2</sup> * volume) / Σ(volume) - VWAP<sup>2</sup>
+///
+/// VWAP bands are composed as:
+///
+///  * _Upper Band_ = VWAP + _multiplier_ * σ
+///  * _Lower Band_ = VWAP - _multiplier_ * σ
+///
+/// While the accumulated volume is 0, VWAP is the current typical price and σ is 0.
+///
+/// # Parameters
+///
+/// * _multiplier_ - number of standard deviations (not negative). Default is 2.0.
+///
+/// # Links
+///
+/// * [VWAP, Wikipedia](https://en.wikipedia.org/wiki/Volume-weighted_average_price)
+///
+#[doc(alias = "VWAP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VwapBands {
+    multiplier: crate::Num,
+    sum_volume: crate::Num,
+    sum_price_volume: crate::Num,
+    sum_price2_volume: crate::Num,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VwapBandsOutput {
+    pub vwap: crate::Num,
+    pub upper: crate::Num,
+    pub lower: crate::Num,
+}
+
+impl Bands for VwapBandsOutput {
+    fn upper(&self) -> crate::Num {
+        self.upper
+    }
+
+    fn average(&self) -> crate::Num {
+        self.vwap
+    }
+
+    fn lower(&self) -> crate::Num {
+        self.lower
+    }
+}
+
+impl VwapBands {
+    /// # Errors
+    ///
+    /// Will return `Err` if `multiplier` is negative
+    pub fn new(multiplier: crate::Num) -> Result<Self> {
+        if multiplier < lit!(0.0) {
+            return Err(TaError::InvalidMultiplier);
+        }
+        Ok(Self {
+            multiplier,
+            sum_volume: lit!(0.0),
+            sum_price_volume: lit!(0.0),
+            sum_price2_volume: lit!(0.0),
+        })
+    }
+
+    #[must_use]
+    pub fn multiplier(&self) -> crate::Num {
+        self.multiplier
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for VwapBands {
+    type Output = VwapBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let typical_price = input.typical_price();
+        let volume = input.volume();
+
+        self.sum_volume += volume;
+        self.sum_price_volume += typical_price * volume;
+        self.sum_price2_volume += typical_price * typical_price * volume;
+
+        if self.sum_volume == lit!(0.0) {
+            return Self::Output {
+                vwap: typical_price,
+                upper: typical_price,
+                lower: typical_price,
+            };
+        }
+
+        let vwap = self.sum_price_volume / self.sum_volume;
+        let variance = (self.sum_price2_volume / self.sum_volume - vwap * vwap).max(lit!(0.0));
+        let band = self.multiplier * num::sqrt(variance);
+
+        Self::Output {
+            vwap,
+            upper: vwap + band,
+            lower: vwap - band,
+        }
+    }
+}
+
+impl Reset for VwapBands {
+    fn reset(&mut self) {
+        self.sum_volume = lit!(0.0);
+        self.sum_price_volume = lit!(0.0);
+        self.sum_price2_volume = lit!(0.0);
+    }
+}
+
+impl Default for VwapBands {
+    fn default() -> Self {
+        Self::new(lit!(2.0)).unwrap()
+    }
+}
+
+impl fmt::Display for VwapBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWAPB({})", self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(price: i32, volume: i32) -> Bar {
+        Bar::new()
+            .high(price)
+            .low(price)
+            .close(price)
+            .volume(volume)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(VwapBands::new(lit!(-1.0)).is_err());
+        assert!(VwapBands::new(lit!(0.0)).is_ok());
+        assert!(VwapBands::new(lit!(2.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwap = VwapBands::new(lit!(2.0)).unwrap();
+
+        let out = vwap.next(&Bar::new().high(12).low(9).close(9).volume(100));
+        assert_eq!(out.vwap, lit!(10.0));
+        assert_eq!(out.upper, lit!(10.0));
+        assert_eq!(out.lower, lit!(10.0));
+
+        // vwap = (10 * 100 + 14 * 300) / 400 = 13
+        // variance = (100 * 100 + 196 * 300) / 400 - 169 = 3
+        let out = vwap.next(&bar(14, 300));
+        assert_eq!(out.vwap, lit!(13.0));
+        assert_eq!(round(out.upper), lit!(16.464));
+        assert_eq!(round(out.lower), lit!(9.536));
+    }
+
+    #[test]
+    fn test_next_widens() {
+        let mut vwap = VwapBands::default();
+
+        let out = vwap.next(&bar(10, 100));
+        let mut width = out.upper - out.lower;
+        assert_eq!(width, lit!(0.0));
+
+        // prices disperse further and further from the vwap of 10
+        for (price, volume) in [(11, 100), (9, 100), (13, 100), (7, 100)] {
+            let out = vwap.next(&bar(price, volume));
+            assert!(out.upper - out.lower > width);
+            width = out.upper - out.lower;
+        }
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut vwap = VwapBands::default();
+
+        let out = vwap.next(&bar(10, 0));
+        assert_eq!(out.vwap, lit!(10.0));
+        assert_eq!(out.upper, lit!(10.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwap = VwapBands::default();
+
+        vwap.next(&bar(10, 100));
+        vwap.next(&bar(20, 100));
+
+        // new session
+        vwap.reset();
+        let out = vwap.next(&bar(30, 100));
+        assert_eq!(out.vwap, lit!(30.0));
+        assert_eq!(out.upper, lit!(30.0));
+        assert_eq!(out.lower, lit!(30.0));
+    }
+
+    #[test]
+    fn test_default() {
+        VwapBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = VwapBands::default();
+        #[cfg(not(feature = "f64-backend"))]
+        assert_eq!(format!("{}", indicator), "VWAPB(2.0)");
+        #[cfg(feature = "f64-backend")]
+        assert_eq!(format!("{}", indicator), "VWAPB(2)");
+    }
+}
@@ -0,0 +1,175 @@
+use core::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RollingSum, SimpleMovingAverage};
+use crate::{lit, Close, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Weighted Moving Average (VWMA).
+///
+/// The mean of the closes over a period, each close weighted by the volume of its bar, so
+/// bars with more trading activity move the average more.
+///
+/// # Formula
+///
+/// VWMA = sum(close * volume, _period_) / sum(volume, _period_)
+///
+/// Where _sum(x, n)_ is the sum of _x_ over the last _n_ periods. If the total volume of the
+/// period is 0, VWMA is the simple moving average of the closes.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedMovingAverage;
+/// use ta::{lit, Candle, Next};
+///
+/// let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+/// let bar = |close, volume| {
+///     Candle::builder()
+///         .open(close)
+///         .high(close)
+///         .low(close)
+///         .close(close)
+///         .volume(volume)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// vwma.next(&bar(lit!(10.0), lit!(100.0)));
+/// assert_eq!(vwma.next(&bar(lit!(20.0), lit!(300.0))), lit!(17.5));
+/// ```
+///
+/// # Links
+///
+/// * [Volume Weighted Moving Average, Investopedia](https://www.investopedia.com/articles/trading/11/trading-with-vwap-mvwap.asp)
+#[doc(alias = "VWMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeWeightedMovingAverage {
+    period: usize,
+    price_volume: RollingSum,
+    volume: RollingSum,
+    sma: SimpleMovingAverage,
+}
+
+impl VolumeWeightedMovingAverage {
+    /// # Errors
+    ///
+    /// Will return `Err` if `period` is 0
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            price_volume: RollingSum::new(period)?,
+            volume: RollingSum::new(period)?,
+            sma: SimpleMovingAverage::new(period)?,
+        })
+    }
+}
+
+impl Period for VolumeWeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for VolumeWeightedMovingAverage {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let volume = input.volume();
+
+        let price_volume = self.price_volume.next(close * volume);
+        let total_volume = self.volume.next(volume);
+        let sma = self.sma.next(close);
+
+        if total_volume == lit!(0.0) {
+            sma
+        } else {
+            price_volume / total_volume
+        }
+    }
+}
+
+impl Reset for VolumeWeightedMovingAverage {
+    fn reset(&mut self) {
+        self.price_volume.reset();
+        self.volume.reset();
+        self.sma.reset();
+    }
+}
+
+impl Default for VolumeWeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeWeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    type Vwma = VolumeWeightedMovingAverage;
+
+    #[test]
+    fn test_new() {
+        assert!(Vwma::new(0).is_err());
+        assert!(Vwma::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwma = Vwma::new(2).unwrap();
+
+        // a single bar is its own average
+        assert_eq!(vwma.next(&Bar::new().close(10).volume(100)), lit!(10.0));
+        // (10 * 100 + 13 * 300) / (100 + 300) = 4900 / 400
+        assert_eq!(vwma.next(&Bar::new().close(13).volume(300)), lit!(12.25));
+        // 10 leaves: (13 * 300 + 8 * 100) / (300 + 100) = 4700 / 400
+        assert_eq!(vwma.next(&Bar::new().close(8).volume(100)), lit!(11.75));
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut vwma = Vwma::new(2).unwrap();
+
+        assert_eq!(vwma.next(&Bar::new().close(10).volume(0)), lit!(10.0));
+        assert_eq!(vwma.next(&Bar::new().close(13).volume(0)), lit!(11.5));
+        // a bar without volume doesn't move the average
+        assert_eq!(vwma.next(&Bar::new().close(20).volume(50)), lit!(20.0));
+        assert_eq!(vwma.next(&Bar::new().close(4).volume(0)), lit!(20.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwma = Vwma::new(2).unwrap();
+        vwma.next(&Bar::new().close(10).volume(100));
+        vwma.next(&Bar::new().close(13).volume(300));
+
+        vwma.reset();
+        assert_eq!(vwma.next(&Bar::new().close(7).volume(10)), lit!(7.0));
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Vwma::default().period(), 20);
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Vwma::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "VWMA(20)");
+    }
+}
@@ -0,0 +1,98 @@
+use core::fmt;
+
+use crate::{lit, Close, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Weighted Close.
+///
+/// The average of the high, the low and the close of the bar, giving the close twice the
+/// weight. It is stateless, so it can be used to turn a stream of bars into a stream of
+/// prices for another indicator.
+///
+/// # Formula
+///
+/// Weighted Close = (high + low + 2 * close) / 4
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WeightedClose {}
+
+impl WeightedClose {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for WeightedClose {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        (input.high() + input.low() + lit!(2.0) * input.close()) / lit!(4.0)
+    }
+}
+
+impl Reset for WeightedClose {
+    fn reset(&mut self) {}
+}
+
+impl Default for WeightedClose {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for WeightedClose {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WEIGHTED_CLOSE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::ExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut wc = WeightedClose::new();
+
+        assert_eq!(wc.next(&Bar::new().high(12).low(8).close(10)), lit!(10.0));
+        assert_eq!(wc.next(&Bar::new().high(12).low(8).close(12)), lit!(11.0));
+        assert_eq!(wc.next(&Bar::new().high(12).low(8).close(8)), lit!(9.0));
+        assert_eq!(wc.next(&Bar::new().high(13).low(8).close(12)), lit!(11.25));
+    }
+
+    #[test]
+    fn test_into_ema() {
+        let mut wc = WeightedClose::new();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+
+        let bar1 = Bar::new().high(12).low(8).close(10);
+        let bar2 = Bar::new().high(12).low(8).close(12);
+        assert_eq!(ema.next(wc.next(&bar1)), lit!(10.0));
+        assert_eq!(ema.next(wc.next(&bar2)), lit!(10.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut wc = WeightedClose::new();
+
+        wc.next(&Bar::new().high(12).low(8).close(12));
+        wc.reset();
+        assert_eq!(wc.next(&Bar::new().high(12).low(8).close(10)), lit!(10.0));
+    }
+
+    #[test]
+    fn test_default() {
+        WeightedClose::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = WeightedClose::new();
+        assert_eq!(format!("{}", indicator), "WEIGHTED_CLOSE");
+    }
+}
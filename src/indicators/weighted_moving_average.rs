@@ -1,8 +1,10 @@
-use std::fmt;
+use alloc::boxed::Box;
+use alloc::vec;
+use core::fmt;
 
 use crate::errors::{Result, TaError};
 use crate::{int, lit};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -28,10 +30,10 @@ pub struct WeightedMovingAverage {
     period: usize,
     index: usize,
     count: usize,
-    weight: rust_decimal::Decimal,
-    sum: rust_decimal::Decimal,
-    sum_flat: rust_decimal::Decimal,
-    deque: Box<[rust_decimal::Decimal]>,
+    weight: crate::Num,
+    sum: crate::Num,
+    sum_flat: crate::Num,
+    deque: Box<[crate::Num]>,
 }
 
 impl WeightedMovingAverage {
@@ -40,7 +42,7 @@ impl WeightedMovingAverage {
     /// Will return `Err` if `period` is 0
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::InvalidPeriod { got: 0 }),
             _ => Ok(Self {
                 period,
                 index: 0,
@@ -60,11 +62,11 @@ impl Period for WeightedMovingAverage {
     }
 }
 
-impl Next<rust_decimal::Decimal> for WeightedMovingAverage {
-    type Output = rust_decimal::Decimal;
+impl Next<crate::Num> for WeightedMovingAverage {
+    type Output = crate::Num;
 
-    fn next(&mut self, input: rust_decimal::Decimal) -> Self::Output {
-        let old_val: rust_decimal::Decimal = self.deque[self.index];
+    fn next(&mut self, input: crate::Num) -> Self::Output {
+        let old_val: crate::Num = self.deque[self.index];
         self.deque[self.index] = input;
 
         self.index = if self.index + 1 < self.period {
@@ -81,18 +83,36 @@ impl Next<rust_decimal::Decimal> for WeightedMovingAverage {
             self.sum = self.sum - self.sum_flat + (input * self.weight);
         }
         self.sum_flat = self.sum_flat - old_val + input;
+        self.average()
+    }
+}
+
+impl WeightedMovingAverage {
+    fn average(&self) -> crate::Num {
         self.sum / (self.weight * (self.weight + lit!(1.0)) / lit!(2.0))
     }
 }
 
 impl<T: Close> Next<&T> for WeightedMovingAverage {
-    type Output = rust_decimal::Decimal;
+    type Output = crate::Num;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
     }
 }
 
+impl Current for WeightedMovingAverage {
+    type Output = crate::Num;
+
+    fn current(&self) -> Option<crate::Num> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.average())
+        }
+    }
+}
+
 impl Reset for WeightedMovingAverage {
     fn reset(&mut self) {
         self.index = 0;
@@ -147,6 +167,21 @@ mod tests {
         assert_eq!(wma.next(&bar2), lit!(4.0));
     }
 
+    #[test]
+    fn test_newest_value_has_highest_weight() {
+        let mut wma = WeightedMovingAverage::new(4).unwrap();
+        for _ in 0..4 {
+            wma.next(lit!(0.0));
+        }
+
+        // a single 1 is weighted 4/10 as the newest value, then 3/10, 2/10, 1/10 as it ages
+        assert_eq!(wma.next(lit!(1.0)), lit!(0.4));
+        assert_eq!(wma.next(lit!(0.0)), lit!(0.3));
+        assert_eq!(wma.next(lit!(0.0)), lit!(0.2));
+        assert_eq!(wma.next(lit!(0.0)), lit!(0.1));
+        assert_eq!(wma.next(lit!(0.0)), lit!(0.0));
+    }
+
     #[test]
     fn test_reset() {
         let mut wma = WeightedMovingAverage::new(5).unwrap();
@@ -0,0 +1,298 @@
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{lit, High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// ZigZag.
+///
+/// ZigZag filters out the price moves smaller than a _threshold_ percentage and connects
+/// the remaining swing highs and lows. A swing high is the highest high reached before the
+/// low falls _threshold_ percent below it, a swing low is the lowest low reached before the
+/// high rises _threshold_ percent above it.
+///
+/// ZigZag is retrospective: a pivot can only be confirmed once the price has moved away
+/// from it by the threshold, which may happen many bars later. The output is `Some` on the
+/// bar that confirms a pivot, with the number of bars since the pivot in
+/// [`bars_ago`](ZigZagPivot::bars_ago), and `None` on every other bar. Until a pivot is
+/// confirmed the trend is unknown and both the highest high and the lowest low are tracked.
+///
+/// # Parameters
+///
+/// * _threshold_ - minimal move in percent (greater than 0 and less than 100). Default is
+///   5.0.
+///
+/// # Links
+///
+/// * [ZigZag, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:zigzag)
+///
+#[doc(alias = "ZIGZAG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZigZag {
+    threshold: crate::Num,
+    index: usize,
+    high: (crate::Num, usize),
+    low: (crate::Num, usize),
+    searching: Option<ZigZagPivotKind>,
+    last_pivot: Option<ZigZagPivot>,
+}
+
+/// Kind of a pivot confirmed by [`ZigZag`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZigZagPivotKind {
+    High,
+    Low,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZigZagPivot {
+    pub kind: ZigZagPivotKind,
+    pub price: crate::Num,
+    /// Number of bars between the pivot and the bar which confirmed it.
+    pub bars_ago: usize,
+}
+
+impl ZigZag {
+    /// # Errors
+    ///
+    /// Will return `Err` if `threshold` is not between 0 and 100
+    pub fn new(threshold: crate::Num) -> Result<Self> {
+        if threshold <= lit!(0.0) || threshold >= lit!(100.0) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            threshold,
+            index: 0,
+            high: (lit!(0.0), 0),
+            low: (lit!(0.0), 0),
+            searching: None,
+            last_pivot: None,
+        })
+    }
+
+    #[must_use]
+    pub fn threshold(&self) -> crate::Num {
+        self.threshold
+    }
+
+    /// The last confirmed pivot, `None` until the first one is confirmed.
+    #[must_use]
+    pub fn last_pivot(&self) -> Option<&ZigZagPivot> {
+        self.last_pivot.as_ref()
+    }
+
+    fn confirm(&mut self, kind: ZigZagPivotKind, index: usize) -> Option<ZigZagPivot> {
+        let (price, pivot_index) = match kind {
+            ZigZagPivotKind::High => self.high,
+            ZigZagPivotKind::Low => self.low,
+        };
+        let pivot = ZigZagPivot {
+            kind,
+            price,
+            bars_ago: index - pivot_index,
+        };
+
+        self.last_pivot = Some(pivot.clone());
+        Some(pivot)
+    }
+}
+
+impl<T: High + Low> Next<&T> for ZigZag {
+    type Output = Option<ZigZagPivot>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        let index = self.index;
+        self.index += 1;
+
+        if index == 0 {
+            self.high = (high, index);
+            self.low = (low, index);
+            return None;
+        }
+
+        let ratio = self.threshold / lit!(100.0);
+        if high > self.high.0 {
+            self.high = (high, index);
+        }
+        if low < self.low.0 {
+            self.low = (low, index);
+        }
+        let reversed_down = low <= self.high.0 * (lit!(1.0) - ratio);
+        let reversed_up = high >= self.low.0 * (lit!(1.0) + ratio);
+
+        match self.searching {
+            Some(ZigZagPivotKind::High) if reversed_down => {
+                self.searching = Some(ZigZagPivotKind::Low);
+                self.low = (low, index);
+                self.confirm(ZigZagPivotKind::High, index)
+            }
+            Some(ZigZagPivotKind::Low) if reversed_up => {
+                self.searching = Some(ZigZagPivotKind::High);
+                self.high = (high, index);
+                self.confirm(ZigZagPivotKind::Low, index)
+            }
+            // the trend is unknown, the older extreme is the first pivot
+            None if reversed_up && (!reversed_down || self.low.1 < self.high.1) => {
+                self.searching = Some(ZigZagPivotKind::High);
+                self.high = (high, index);
+                self.confirm(ZigZagPivotKind::Low, index)
+            }
+            None if reversed_down => {
+                self.searching = Some(ZigZagPivotKind::Low);
+                self.low = (low, index);
+                self.confirm(ZigZagPivotKind::High, index)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Reset for ZigZag {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.high = (lit!(0.0), 0);
+        self.low = (lit!(0.0), 0);
+        self.searching = None;
+        self.last_pivot = None;
+    }
+}
+
+impl Default for ZigZag {
+    fn default() -> Self {
+        Self::new(lit!(5.0)).unwrap()
+    }
+}
+
+impl fmt::Display for ZigZag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZIGZAG({})", self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: i32, low: i32) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ZigZag::new(lit!(0.0)).is_err());
+        assert!(ZigZag::new(lit!(-5.0)).is_err());
+        assert!(ZigZag::new(lit!(100.0)).is_err());
+        assert!(ZigZag::new(lit!(0.5)).is_ok());
+        assert!(ZigZag::new(lit!(5.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zigzag = ZigZag::new(lit!(10.0)).unwrap();
+
+        assert_eq!(zigzag.next(&bar(100, 100)), None);
+        assert_eq!(zigzag.next(&bar(105, 104)), None);
+        assert_eq!(zigzag.last_pivot(), None);
+
+        // 112 is more than 10% above 100
+        let low = ZigZagPivot {
+            kind: ZigZagPivotKind::Low,
+            price: lit!(100.0),
+            bars_ago: 2,
+        };
+        assert_eq!(zigzag.next(&bar(112, 110)), Some(low.clone()));
+        assert_eq!(zigzag.last_pivot(), Some(&low));
+
+        assert_eq!(zigzag.next(&bar(120, 118)), None);
+
+        // 107 is more than 10% below 120
+        let high = ZigZagPivot {
+            kind: ZigZagPivotKind::High,
+            price: lit!(120.0),
+            bars_ago: 1,
+        };
+        assert_eq!(zigzag.next(&bar(110, 107)), Some(high.clone()));
+        assert_eq!(zigzag.last_pivot(), Some(&high));
+
+        assert_eq!(zigzag.next(&bar(109, 105)), None);
+        assert_eq!(zigzag.next(&bar(113, 110)), None);
+
+        let low = ZigZagPivot {
+            kind: ZigZagPivotKind::Low,
+            price: lit!(105.0),
+            bars_ago: 2,
+        };
+        assert_eq!(zigzag.next(&bar(116, 114)), Some(low.clone()));
+        assert_eq!(zigzag.last_pivot(), Some(&low));
+    }
+
+    #[test]
+    fn test_next_first_pivot_high() {
+        let mut zigzag = ZigZag::new(lit!(10.0)).unwrap();
+
+        assert_eq!(zigzag.next(&bar(100, 99)), None);
+        assert_eq!(zigzag.next(&bar(102, 98)), None);
+
+        let high = ZigZagPivot {
+            kind: ZigZagPivotKind::High,
+            price: lit!(102.0),
+            bars_ago: 1,
+        };
+        assert_eq!(zigzag.next(&bar(95, 91)), Some(high));
+    }
+
+    #[test]
+    fn test_next_small_oscillations() {
+        let mut zigzag = ZigZag::new(lit!(5.0)).unwrap();
+
+        // the price swings by 3%, below the threshold
+        for _ in 0..20 {
+            assert_eq!(zigzag.next(&bar(103, 102)), None);
+            assert_eq!(zigzag.next(&bar(101, 100)), None);
+        }
+        assert_eq!(zigzag.last_pivot(), None);
+
+        // once a pivot is confirmed, the swings are filtered out as well
+        assert!(zigzag.next(&bar(110, 108)).is_some());
+        for _ in 0..20 {
+            assert_eq!(zigzag.next(&bar(110, 108)), None);
+            assert_eq!(zigzag.next(&bar(108, 106)), None);
+        }
+        assert_eq!(zigzag.last_pivot().unwrap().kind, ZigZagPivotKind::Low);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zigzag = ZigZag::new(lit!(10.0)).unwrap();
+
+        zigzag.next(&bar(100, 100));
+        zigzag.next(&bar(112, 110));
+        assert!(zigzag.last_pivot().is_some());
+
+        zigzag.reset();
+        assert_eq!(zigzag.last_pivot(), None);
+        assert_eq!(zigzag.next(&bar(200, 200)), None);
+        assert_eq!(zigzag.next(&bar(210, 208)), None);
+    }
+
+    #[test]
+    fn test_default() {
+        ZigZag::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ZigZag::default();
+        #[cfg(not(feature = "f64-backend"))]
+        assert_eq!(format!("{}", indicator), "ZIGZAG(5.0)");
+        #[cfg(feature = "f64-backend")]
+        assert_eq!(format!("{}", indicator), "ZIGZAG(5)");
+    }
+}
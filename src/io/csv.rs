@@ -0,0 +1,143 @@
+//! Reading candles from CSV files.
+//!
+//! Available with the `csv` feature.
+
+use alloc::vec::Vec;
+use std::io::Read;
+
+use ::csv::{ReaderBuilder, StringRecord, Trim};
+
+use crate::data_item::parse_datetime;
+use crate::errors::{Result, TaError};
+use crate::Candle;
+
+/// Reads `date,open,high,low,close,volume` rows into validated candles.
+///
+/// The first row is a header and is skipped, the columns are taken in the above order
+/// whatever their names. The date is an RFC 3339 datetime, e.g. `2017-01-03T14:30:00Z`, or a
+/// `YYYY-MM-DD` date, taken as midnight UTC. Spaces around the fields are ignored.
+///
+/// # Errors
+///
+/// Will return `Err(TaError::InvalidRecord { line })` for the first row that can't be
+/// read, doesn't have 6 fields, has a date or a number that can't be parsed, or doesn't
+/// make a valid candle, see [`CandleBuilder::build`](crate::CandleBuilder::build)
+///
+/// # Example
+///
+/// ```
+/// use ta::io::csv::read_candles;
+/// use ta::{lit, Close};
+///
+/// let data = "date,open,high,low,close,volume
+/// 2017-01-03,757.92,758.76,747.70,753.67,3521100
+/// 2017-01-04,758.39,759.68,754.20,757.18,2510500
+/// ";
+///
+/// let candles = read_candles(data.as_bytes()).unwrap();
+/// assert_eq!(candles.len(), 2);
+/// assert_eq!(candles[1].close(), lit!(757.18));
+/// ```
+pub fn read_candles<R: Read>(reader: R) -> Result<Vec<Candle>> {
+    let mut reader = ReaderBuilder::new().trim(Trim::All).from_reader(reader);
+
+    let mut candles = Vec::new();
+    let mut record = StringRecord::new();
+    loop {
+        match reader.read_record(&mut record) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) => {
+                let line = err.position().map_or(0, |position| position.line());
+                return Err(TaError::InvalidRecord { line });
+            }
+        }
+
+        let line = record.position().map_or(0, |position| position.line());
+        let candle = parse_candle(&record).ok_or(TaError::InvalidRecord { line })?;
+        candles.push(candle);
+    }
+
+    Ok(candles)
+}
+
+fn parse_candle(record: &StringRecord) -> Option<Candle> {
+    if record.len() != 6 {
+        return None;
+    }
+    let number = |i: usize| record[i].parse::<crate::Num>().ok();
+
+    Candle::builder()
+        .time(parse_datetime(&record[0])?)
+        .open(number(1)?)
+        .high(number(2)?)
+        .low(number(3)?)
+        .close(number(4)?)
+        .volume(number(5)?)
+        .build()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lit, Close, Volume};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_read_candles() {
+        let data = "\
+date,open,high,low,close,volume
+2017-01-03,757.92,758.76,747.70,753.67,3521100
+ 2017-01-04T14:30:00Z , 758.39, 759.68, 754.20, 757.18, 2510500
+";
+        let candles = read_candles(data.as_bytes()).unwrap();
+
+        assert_eq!(candles.len(), 2);
+        assert_eq!(
+            candles[0].datetime(),
+            Utc.with_ymd_and_hms(2017, 1, 3, 0, 0, 0).unwrap()
+        );
+        assert_eq!(candles[0].close(), lit!(753.67));
+        assert_eq!(
+            candles[1].datetime(),
+            Utc.with_ymd_and_hms(2017, 1, 4, 14, 30, 0).unwrap()
+        );
+        assert_eq!(candles[1].volume(), lit!(2510500.0));
+    }
+
+    #[test]
+    fn test_read_candles_empty() {
+        assert_eq!(read_candles("".as_bytes()), Ok(vec![]));
+        assert_eq!(
+            read_candles("date,open,high,low,close,volume\n".as_bytes()),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn test_read_candles_invalid() {
+        let header = "date,open,high,low,close,volume\n";
+        let valid = "2017-01-03,757.92,758.76,747.70,753.67,3521100\n";
+        let invalid = [
+            // missing field
+            "2017-01-04,758.39,759.68,754.20,757.18\n",
+            // bad date
+            "04.01.2017,758.39,759.68,754.20,757.18,2510500\n",
+            // bad number
+            "2017-01-04,758.39,x,754.20,757.18,2510500\n",
+            // high below close
+            "2017-01-04,758.39,756.00,754.20,757.18,2510500\n",
+        ];
+
+        for row in invalid {
+            let data = format!("{}{}{}", header, valid, row);
+            assert_eq!(
+                read_candles(data.as_bytes()),
+                Err(TaError::InvalidRecord { line: 3 }),
+                "{}",
+                row
+            );
+        }
+    }
+}
@@ -0,0 +1,3 @@
+//! Reading market data into [`Candle`](crate::Candle)s.
+
+pub mod csv;
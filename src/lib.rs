@@ -10,12 +10,36 @@
 //! Since `Next<T>` is a generic trait, most of the indicators can work with both input types: `f64` and more complex
 //! structures like [`DataItem`](struct.DataItem.html).
 //!
+//! # Numeric type
+//!
+//! Prices and indicator values are of type [`Num`], which is [`rust_decimal::Decimal`] by
+//! default. Enable the `f64-backend` feature to use `f64` instead, trading exactness for
+//! speed. The [`lit!`] and [`int!`] macros build a `Num` for either backend.
+//!
+//! # `no_std`
+//!
+//! The `std` feature is enabled by default. Disable the default features and enable `alloc`
+//! to use the crate without the standard library, e.g. on embedded targets or WASM. The
+//! `f64-backend` feature is not available without `std`.
+//!
 //! # List of indicators
 //!
 //! * Trend
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
 //!   * [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
+//!   * [Donchian Channel (DC)](indicators/struct.DonchianChannel.html)
+//!   * [SuperTrend](indicators/struct.SuperTrend.html)
+//!   * [Vortex Indicator (VI)](indicators/struct.VortexIndicator.html)
+//!   * [Arnaud Legoux Moving Average (ALMA)](indicators/struct.ArnaudLegouxMovingAverage.html)
+//!   * [McGinley Dynamic (MD)](indicators/struct.McGinleyDynamic.html)
+//!   * [T3 Moving Average (T3)](indicators/struct.T3MovingAverage.html)
+//!   * [Variable Index Dynamic Average (VIDYA)](indicators/struct.Vidya.html)
+//!   * [Linear Regression Moving Average (LINREG)](indicators/struct.LinearRegressionMovingAverage.html)
+//!   * [Smoothed Moving Average (SMMA)](indicators/struct.SmoothedMovingAverage.html)
+//!   * [Alligator](indicators/struct.Alligator.html)
+//!   * [Moving Average (MA)](indicators/struct.MovingAverage.html)
+//!   * [Volume Weighted Moving Average (VWMA)](indicators/struct.VolumeWeightedMovingAverage.html)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
@@ -24,6 +48,29 @@
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Directional Movement Index (DMI)](indicators/struct.DirectionalMovementIndex.html)
+//!   * [Momentum (MOM)](indicators/struct.Momentum.html)
+//!   * [Chande Momentum Oscillator (CMO)](indicators/struct.ChandeMomentumOscillator.html)
+//!   * [Triple Exponential Average (TRIX)](indicators/struct.Trix.html)
+//!   * [Ultimate Oscillator (UO)](indicators/struct.UltimateOscillator.html)
+//!   * [Aroon and Aroon Oscillator](indicators/struct.Aroon.html)
+//!   * [Chaikin Oscillator (CHO)](indicators/struct.ChaikinOscillator.html)
+//!   * [Force Index (FI)](indicators/struct.ForceIndex.html)
+//!   * [Ease of Movement (EOM)](indicators/struct.EaseOfMovement.html)
+//!   * [Awesome Oscillator (AO)](indicators/struct.AwesomeOscillator.html)
+//!   * [Detrended Price Oscillator (DPO)](indicators/struct.DetrendedPriceOscillator.html)
+//!   * [Coppock Curve (COPP)](indicators/struct.CoppockCurve.html)
+//!   * [Stochastic RSI (StochRSI)](indicators/struct.StochasticRsi.html)
+//!   * [Balance of Power (BOP)](indicators/struct.BalanceOfPower.html)
+//!   * [Qstick](indicators/struct.Qstick.html)
+//!   * [Connors RSI (CRSI)](indicators/struct.ConnorsRsi.html)
+//!   * [Percentage Volume Oscillator (PVO)](indicators/struct.PercentageVolumeOscillator.html)
+//!   * [Know Sure Thing (KST)](indicators/struct.KnowSureThing.html)
+//!   * [Elder Ray Index (ELDER)](indicators/struct.ElderRay.html)
+//!   * [Disparity Index (DISP)](indicators/struct.DisparityIndex.html)
+//!   * [Schaff Trend Cycle (STC)](indicators/struct.SchaffTrendCycle.html)
+//!   * [Stochastic (STOCH)](indicators/struct.Stochastic.html)
+//!   * [Accelerator Oscillator (AC)](indicators/struct.AcceleratorOscillator.html)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
@@ -37,10 +84,47 @@
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Parabolic SAR (PSAR)](indicators/struct.ParabolicSAR.html)
+//!   * [VWAP Bands](indicators/struct.VwapBands.html)
+//!   * [Close Location Value (CLV)](indicators/struct.CloseLocationValue.html)
+//!   * [Accumulation/Distribution Line (ADL)](indicators/struct.AccumulationDistribution.html)
+//!   * [Chaikin Money Flow (CMF)](indicators/struct.ChaikinMoneyFlow.html)
+//!   * [Ulcer Index (UI)](indicators/struct.UlcerIndex.html)
+//!   * [Price Volume Trend (PVT)](indicators/struct.PriceVolumeTrend.html)
+//!   * [Negative Volume Index (NVI)](indicators/struct.NegativeVolumeIndex.html)
+//!   * [Positive Volume Index (PVI)](indicators/struct.PositiveVolumeIndex.html)
+//!   * [Median Price](indicators/struct.MedianPrice.html)
+//!   * [Weighted Close](indicators/struct.WeightedClose.html)
+//!   * [Standard Error Bands (SEB)](indicators/struct.StandardErrorBands.html)
+//!   * [Mass Index (MASS)](indicators/struct.MassIndex.html)
+//!   * [ZigZag](indicators/struct.ZigZag.html)
+//!   * [Fractal](indicators/struct.Fractal.html)
+//!   * [Pivot Points (PP)](indicators/struct.PivotPoints.html)
+//!   * [High Low Range (HLR)](indicators/struct.HighLowRange.html)
+//!   * [Typical Price (TP)](indicators/struct.TypicalPrice.html)
+//!   * [Average Price (OHLC4)](indicators/struct.AveragePrice.html)
+//!   * [Heikin-Ashi (HA)](indicators/struct.HeikinAshi.html)
+//!   * [Rolling Median (MEDIAN)](indicators/struct.RollingMedian.html)
+//!   * [Rolling Sum (SUM)](indicators/struct.RollingSum.html)
+//!   * [Resampler](indicators/struct.Resampler.html)
 //!
-#[macro_use]
+// the unit tests use the `std` prelude, so they also build without the `std` feature
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("ta requires either the `std` or the `alloc` feature");
+
+// `f64::sqrt` and `f64::exp` are only available with `std`
+#[cfg(all(feature = "f64-backend", not(feature = "std")))]
+compile_error!("the `f64-backend` feature requires the `std` feature");
+
+extern crate alloc;
+
 mod helpers;
 
+mod num;
+pub use crate::num::Num;
+
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
@@ -48,9 +132,20 @@ mod test_helper;
 pub mod errors;
 pub mod indicators;
 
+#[cfg(feature = "csv")]
+pub mod io;
+
 mod traits;
 pub use crate::traits::*;
 
+pub mod parse;
+pub mod patterns;
+pub mod stream;
+
+#[cfg(feature = "serde")]
+pub mod serde_datetime;
+
 mod data_item;
 pub use crate::data_item::Candle;
-pub use crate::data_item::CandleBuilder;
\ No newline at end of file
+pub use crate::data_item::CandleBuilder;
+pub use crate::data_item::Session;
@@ -0,0 +1,213 @@
+//! Numeric type used by the indicators.
+//!
+//! By default it is [`rust_decimal::Decimal`], which represents prices exactly. With the
+//! `f64-backend` feature it is `f64`, which is faster but subject to floating point errors.
+//!
+//! The few operations that have no common method on both types are wrapped into functions
+//! of this module.
+
+use rust_decimal::RoundingStrategy;
+
+/// Numeric type of the inputs, parameters and outputs of the indicators.
+#[cfg(not(feature = "f64-backend"))]
+pub type Num = rust_decimal::Decimal;
+
+/// Numeric type of the inputs, parameters and outputs of the indicators.
+#[cfg(feature = "f64-backend")]
+pub type Num = f64;
+
+/// Returns the square root of `value`.
+///
+/// # Panics
+///
+/// Panics if `value` is negative.
+#[cfg(not(feature = "f64-backend"))]
+pub(crate) fn sqrt(value: Num) -> Num {
+    use rust_decimal::MathematicalOps;
+
+    value
+        .sqrt()
+        .expect("Invalid (probably negative) number sent.")
+}
+
+#[cfg(feature = "f64-backend")]
+pub(crate) fn sqrt(value: Num) -> Num {
+    assert!(value >= 0.0, "Invalid (probably negative) number sent.");
+    value.sqrt()
+}
+
+/// Returns _e_ to the power of `value`, `None` if the result can't be represented.
+#[cfg(not(feature = "f64-backend"))]
+pub(crate) fn exp(value: Num) -> Option<Num> {
+    use rust_decimal::MathematicalOps;
+
+    value.checked_exp()
+}
+
+#[cfg(feature = "f64-backend")]
+pub(crate) fn exp(value: Num) -> Option<Num> {
+    Some(value.exp()).filter(|result| result.is_finite())
+}
+
+/// Converts an `f64` into a `Num`, `None` if it is NaN, infinite or out of range.
+///
+/// The conversion keeps the binary value of the `f64`, so a value like `0.1` that has no
+/// exact binary representation doesn't become the decimal `0.1`.
+#[cfg(not(feature = "f64-backend"))]
+pub(crate) fn from_f64(value: f64) -> Option<Num> {
+    Num::from_f64_retain(value)
+}
+
+#[cfg(feature = "f64-backend")]
+pub(crate) fn from_f64(value: f64) -> Option<Num> {
+    Some(value).filter(|value| value.is_finite())
+}
+
+/// Rounds `value` to `dp` decimal places with the given strategy.
+#[cfg(not(feature = "f64-backend"))]
+pub(crate) fn round_with(value: Num, dp: u32, strategy: RoundingStrategy) -> Num {
+    value.round_dp_with_strategy(dp, strategy)
+}
+
+#[cfg(feature = "f64-backend")]
+#[allow(deprecated)]
+pub(crate) fn round_with(value: Num, dp: u32, strategy: RoundingStrategy) -> Num {
+    let factor = 10f64.powi(dp as i32);
+    let scaled = value * factor;
+    let is_midpoint = (scaled - scaled.trunc()).abs() == 0.5;
+
+    let rounded = match strategy {
+        RoundingStrategy::MidpointNearestEven | RoundingStrategy::BankersRounding => {
+            scaled.round_ties_even()
+        }
+        RoundingStrategy::MidpointAwayFromZero | RoundingStrategy::RoundHalfUp => scaled.round(),
+        RoundingStrategy::MidpointTowardZero | RoundingStrategy::RoundHalfDown => {
+            if is_midpoint {
+                scaled.trunc()
+            } else {
+                scaled.round()
+            }
+        }
+        RoundingStrategy::ToZero | RoundingStrategy::RoundDown => scaled.trunc(),
+        RoundingStrategy::AwayFromZero | RoundingStrategy::RoundUp => {
+            if scaled < 0.0 {
+                scaled.floor()
+            } else {
+                scaled.ceil()
+            }
+        }
+        RoundingStrategy::ToNegativeInfinity => scaled.floor(),
+        RoundingStrategy::ToPositiveInfinity => scaled.ceil(),
+    };
+
+    rounded / factor
+}
+
+/// Rounds `value` to `dp` decimal places, midpoints are rounded away from zero.
+#[cfg(test)]
+pub(crate) fn round(value: Num, dp: u32) -> Num {
+    round_with(value, dp, RoundingStrategy::MidpointAwayFromZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+    use crate::{int, lit, Next};
+
+    // these tests hold for both backends, CI runs them with and without `f64-backend`
+
+    #[test]
+    fn test_macros() {
+        let value: Num = lit!(2.5);
+        assert_eq!(value * int!(2), lit!(5.0));
+        assert_eq!(int!(3usize), lit!(3.0));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(sqrt(lit!(0.0)), lit!(0.0));
+        assert_eq!(sqrt(lit!(6.25)), lit!(2.5));
+        assert_eq!(round(sqrt(lit!(2.0)), 3), lit!(1.414));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_negative() {
+        sqrt(lit!(-1.0));
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(exp(lit!(0.0)), Some(lit!(1.0)));
+        assert_eq!(exp(lit!(2.0)).map(|e| round(e, 3)), Some(lit!(7.389)));
+        assert_eq!(exp(lit!(1000000.0)), None);
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(from_f64(2.5), Some(lit!(2.5)));
+        assert_eq!(from_f64(-0.125), Some(lit!(-0.125)));
+        assert_eq!(from_f64(f64::NAN), None);
+        assert_eq!(from_f64(f64::INFINITY), None);
+        assert_eq!(from_f64(f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(lit!(1.2345), 2), lit!(1.23));
+        assert_eq!(round(lit!(2.5), 0), lit!(3.0));
+        assert_eq!(round(lit!(-2.5), 0), lit!(-3.0));
+    }
+
+    #[test]
+    fn test_round_with() {
+        let cases = [
+            (
+                RoundingStrategy::MidpointNearestEven,
+                [lit!(1.2), lit!(1.2), lit!(-1.2)],
+            ),
+            (
+                RoundingStrategy::MidpointAwayFromZero,
+                [lit!(1.3), lit!(1.2), lit!(-1.3)],
+            ),
+            (
+                RoundingStrategy::MidpointTowardZero,
+                [lit!(1.2), lit!(1.2), lit!(-1.2)],
+            ),
+            (RoundingStrategy::ToZero, [lit!(1.2), lit!(1.2), lit!(-1.2)]),
+            (
+                RoundingStrategy::AwayFromZero,
+                [lit!(1.3), lit!(1.3), lit!(-1.3)],
+            ),
+            (
+                RoundingStrategy::ToNegativeInfinity,
+                [lit!(1.2), lit!(1.2), lit!(-1.3)],
+            ),
+            (
+                RoundingStrategy::ToPositiveInfinity,
+                [lit!(1.3), lit!(1.3), lit!(-1.2)],
+            ),
+        ];
+        for (strategy, expected) in cases {
+            let inputs = [lit!(1.25), lit!(1.21), lit!(-1.25)];
+            for (input, output) in inputs.into_iter().zip(expected) {
+                assert_eq!(round_with(input, 1, strategy), output, "{:?}", strategy);
+            }
+        }
+    }
+
+    #[test]
+    fn test_moving_averages() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+
+        let inputs = [lit!(2.0), lit!(5.0), lit!(1.0), lit!(6.25)];
+        let smas = [lit!(2.0), lit!(3.5), lit!(2.667), lit!(4.083)];
+        let emas = [lit!(2.0), lit!(3.5), lit!(2.25), lit!(4.25)];
+        for ((input, s), e) in inputs.into_iter().zip(smas).zip(emas) {
+            assert_eq!(round(sma.next(input), 3), s);
+            assert_eq!(round(ema.next(input), 3), e);
+        }
+    }
+}
@@ -0,0 +1,693 @@
+//! Parsing of indicator specifications.
+//!
+//! A specification is the name of an indicator and its parameters, written the way the
+//! indicators display themselves, e.g. `"EMA(9)"`, `"BB(20, 2.0)"` or `"MACD(12, 26, 9)"`.
+//! Optional parameters follow in the same order as in the `Display` output, e.g.
+//! `"EMA(9, SMA)"` or `"KC(10, 2, SMA(10))"`. It lets config-driven systems choose their
+//! indicators at runtime.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::{EmaSeed, ExponentialMovingAverage};
+//! use ta::parse::{parse_indicator, IndicatorSpec};
+//!
+//! let spec = parse_indicator("EMA(9)").unwrap();
+//! assert_eq!(
+//!     spec,
+//!     IndicatorSpec::Ema {
+//!         period: 9,
+//!         seed: EmaSeed::FirstValue
+//!     }
+//! );
+//!
+//! let ema = ExponentialMovingAverage::new(9).unwrap();
+//! assert_eq!(format!("{}", ema).parse::<IndicatorSpec>(), Ok(spec));
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{EmaSeed, MovingAverageKind, RsiScale, RsiSmoothing};
+use crate::Num;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Indicator and its parameters, parsed from a string.
+///
+/// The names are matched case-insensitively, spaces around the parameters are ignored.
+/// Periods must be integers greater than 0. Parameters with a default value can be left out.
+/// Indicators without parameters can be written with or without the empty parentheses.
+///
+/// Only the indicators listed here are covered. The other indicators of the crate, e.g. the
+/// channels, oscillators and candle transforms added on top of them, can't be parsed yet.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndicatorSpec {
+    /// `SMA(period)`, [SimpleMovingAverage](crate::indicators::SimpleMovingAverage).
+    Sma(usize),
+    /// `EMA(period[, SMA])`, [ExponentialMovingAverage](crate::indicators::ExponentialMovingAverage).
+    /// `SMA` selects [`EmaSeed::Sma`].
+    Ema { period: usize, seed: EmaSeed },
+    /// `WMA(period)`, [WeightedMovingAverage](crate::indicators::WeightedMovingAverage).
+    Wma(usize),
+    /// `SMMA(period)`, [SmoothedMovingAverage](crate::indicators::SmoothedMovingAverage).
+    Smma(usize),
+    /// `RSI(period[, smoothing[, scale]])`,
+    /// [RelativeStrengthIndex](crate::indicators::RelativeStrengthIndex). The smoothing is
+    /// `SMMA` or `EMA`, the scale `ZERO_HUNDRED`, `CENTERED` or `ZERO_ONE`.
+    Rsi {
+        period: usize,
+        smoothing: RsiSmoothing,
+        scale: RsiScale,
+    },
+    /// `SD(period[, ddof])`, [StandardDeviation](crate::indicators::StandardDeviation).
+    Sd { period: usize, ddof: usize },
+    /// `ATR(period)`, [AverageTrueRange](crate::indicators::AverageTrueRange).
+    Atr(usize),
+    /// `ROC(period)`, [RateOfChange](crate::indicators::RateOfChange).
+    Roc(usize),
+    /// `MOM(period)`, [Momentum](crate::indicators::Momentum).
+    Mom(usize),
+    /// `MAX(period)`, [Maximum](crate::indicators::Maximum).
+    Max(usize),
+    /// `MIN(period)`, [Minimum](crate::indicators::Minimum).
+    Min(usize),
+    /// `CCI(period)`, [CommodityChannelIndex](crate::indicators::CommodityChannelIndex).
+    Cci(usize),
+    /// `ER(period)`, [EfficiencyRatio](crate::indicators::EfficiencyRatio).
+    Er(usize),
+    /// `MAD(period)`, [MeanAbsoluteDeviation](crate::indicators::MeanAbsoluteDeviation).
+    Mad(usize),
+    /// `MFI(period)`, [MoneyFlowIndex](crate::indicators::MoneyFlowIndex).
+    Mfi(usize),
+    /// `FAST_STOCH(period)`, [FastStochastic](crate::indicators::FastStochastic).
+    FastStoch(usize),
+    /// `SLOW_STOCH(stochastic, ema)`, [SlowStochastic](crate::indicators::SlowStochastic).
+    SlowStoch { stochastic: usize, ema: usize },
+    /// `CE(period, multiplier)`, [ChandelierExit](crate::indicators::ChandelierExit).
+    Ce { period: usize, multiplier: Num },
+    /// `TRUE_RANGE()`, [TrueRange](crate::indicators::TrueRange).
+    TrueRange,
+    /// `OBV`, [OnBalanceVolume](crate::indicators::OnBalanceVolume).
+    Obv,
+    /// `BB(period, multiplier[, ddof])`, [BollingerBands](crate::indicators::BollingerBands).
+    Bb {
+        period: usize,
+        multiplier: Num,
+        ddof: usize,
+    },
+    /// `KC(period, multiplier[, average])`, [KeltnerChannel](crate::indicators::KeltnerChannel).
+    /// The average is `SMA(period)`, `EMA(period)`, `WMA(period)` or `SMMA(period)` with the
+    /// period of the channel.
+    Kc {
+        period: usize,
+        multiplier: Num,
+        ma: MovingAverageKind,
+    },
+    /// `MACD(fast, slow, signal)`,
+    /// [MovingAverageConvergenceDivergence](crate::indicators::MovingAverageConvergenceDivergence).
+    Macd {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+    /// `PPO(fast, slow, signal)`,
+    /// [PercentagePriceOscillator](crate::indicators::PercentagePriceOscillator).
+    Ppo {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+}
+
+/// Parses an indicator specification, see [`IndicatorSpec`].
+///
+/// # Errors
+///
+/// Will return `Err(TaError::InvalidPeriod { .. })` if a period is 0, `Err` if the indicator
+/// is unknown, the number of parameters doesn't match or another parameter is invalid
+pub fn parse_indicator(spec: &str) -> Result<IndicatorSpec> {
+    spec.parse()
+}
+
+impl FromStr for IndicatorSpec {
+    type Err = TaError;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        let (name, args) = match spec.split_once('(') {
+            Some((name, args)) => {
+                let args = args.strip_suffix(')').ok_or(TaError::InvalidParameter)?;
+                (name, split_args(args)?)
+            }
+            None => (spec, Vec::new()),
+        };
+        let name = name.trim().to_ascii_uppercase();
+
+        let spec = match (name.as_str(), args.as_slice()) {
+            ("SMA", [period]) => Self::Sma(parse_period(period)?),
+            ("EMA", [period]) => Self::Ema {
+                period: parse_period(period)?,
+                seed: EmaSeed::FirstValue,
+            },
+            ("EMA", [period, seed]) if seed.eq_ignore_ascii_case("SMA") => Self::Ema {
+                period: parse_period(period)?,
+                seed: EmaSeed::Sma,
+            },
+            ("WMA", [period]) => Self::Wma(parse_period(period)?),
+            ("SMMA", [period]) => Self::Smma(parse_period(period)?),
+            ("RSI", [period, options @ ..]) if options.len() <= 2 => Self::Rsi {
+                period: parse_period(period)?,
+                smoothing: match options.first() {
+                    Some(smoothing) => parse_rsi_smoothing(smoothing)?,
+                    None => RsiSmoothing::Wilder,
+                },
+                scale: match options.get(1) {
+                    Some(scale) => parse_rsi_scale(scale)?,
+                    None => RsiScale::ZeroHundred,
+                },
+            },
+            ("SD", [period]) => Self::Sd {
+                period: parse_period(period)?,
+                ddof: 0,
+            },
+            ("SD", [period, ddof]) => Self::Sd {
+                period: parse_period(period)?,
+                ddof: parse_integer(ddof)?,
+            },
+            ("ATR", [period]) => Self::Atr(parse_period(period)?),
+            ("ROC", [period]) => Self::Roc(parse_period(period)?),
+            ("MOM", [period]) => Self::Mom(parse_period(period)?),
+            ("MAX", [period]) => Self::Max(parse_period(period)?),
+            ("MIN", [period]) => Self::Min(parse_period(period)?),
+            ("CCI", [period]) => Self::Cci(parse_period(period)?),
+            ("ER", [period]) => Self::Er(parse_period(period)?),
+            ("MAD", [period]) => Self::Mad(parse_period(period)?),
+            ("MFI", [period]) => Self::Mfi(parse_period(period)?),
+            ("FAST_STOCH", [period]) => Self::FastStoch(parse_period(period)?),
+            ("SLOW_STOCH", [stochastic, ema]) => Self::SlowStoch {
+                stochastic: parse_period(stochastic)?,
+                ema: parse_period(ema)?,
+            },
+            ("CE", [period, multiplier]) => Self::Ce {
+                period: parse_period(period)?,
+                multiplier: parse_number(multiplier)?,
+            },
+            ("TRUE_RANGE", []) => Self::TrueRange,
+            ("OBV", []) => Self::Obv,
+            ("BB", [period, multiplier]) => Self::Bb {
+                period: parse_period(period)?,
+                multiplier: parse_number(multiplier)?,
+                ddof: 0,
+            },
+            ("BB", [period, multiplier, ddof]) => Self::Bb {
+                period: parse_period(period)?,
+                multiplier: parse_number(multiplier)?,
+                ddof: parse_integer(ddof)?,
+            },
+            ("KC", [period, multiplier]) => Self::Kc {
+                period: parse_period(period)?,
+                multiplier: parse_number(multiplier)?,
+                ma: MovingAverageKind::Exponential,
+            },
+            ("KC", [period, multiplier, ma]) => {
+                let period = parse_period(period)?;
+                Self::Kc {
+                    period,
+                    multiplier: parse_number(multiplier)?,
+                    ma: parse_ma_kind(ma, period)?,
+                }
+            }
+            ("MACD", [fast, slow, signal]) => Self::Macd {
+                fast: parse_period(fast)?,
+                slow: parse_period(slow)?,
+                signal: parse_period(signal)?,
+            },
+            ("PPO", [fast, slow, signal]) => Self::Ppo {
+                fast: parse_period(fast)?,
+                slow: parse_period(slow)?,
+                signal: parse_period(signal)?,
+            },
+            _ => return Err(TaError::InvalidParameter),
+        };
+
+        Ok(spec)
+    }
+}
+
+/// Splits the arguments at the commas that are not inside parentheses.
+fn split_args(args: &str) -> Result<Vec<&str>> {
+    let mut parts = Vec::new();
+    if args.trim().is_empty() {
+        return Ok(parts);
+    }
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or(TaError::InvalidParameter)?,
+            ',' if depth == 0 => {
+                parts.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(TaError::InvalidParameter);
+    }
+    parts.push(args[start..].trim());
+    Ok(parts)
+}
+
+fn parse_period(arg: &str) -> Result<usize> {
+    match parse_integer(arg)? {
+        0 => Err(TaError::InvalidPeriod { got: 0 }),
+        period => Ok(period),
+    }
+}
+
+fn parse_integer(arg: &str) -> Result<usize> {
+    arg.parse().map_err(|_| TaError::InvalidParameter)
+}
+
+fn parse_rsi_smoothing(arg: &str) -> Result<RsiSmoothing> {
+    match arg.to_ascii_uppercase().as_str() {
+        "SMMA" => Ok(RsiSmoothing::Wilder),
+        "EMA" => Ok(RsiSmoothing::Exponential),
+        _ => Err(TaError::InvalidParameter),
+    }
+}
+
+fn parse_rsi_scale(arg: &str) -> Result<RsiScale> {
+    match arg.to_ascii_uppercase().as_str() {
+        "ZERO_HUNDRED" => Ok(RsiScale::ZeroHundred),
+        "CENTERED" => Ok(RsiScale::Centered),
+        "ZERO_ONE" => Ok(RsiScale::ZeroOne),
+        _ => Err(TaError::InvalidParameter),
+    }
+}
+
+/// Parses a nested moving average, which must have the period of the outer indicator.
+fn parse_ma_kind(arg: &str, period: usize) -> Result<MovingAverageKind> {
+    let (kind, ma_period) = match arg.parse()? {
+        IndicatorSpec::Sma(p) => (MovingAverageKind::Simple, p),
+        IndicatorSpec::Ema {
+            period: p,
+            seed: EmaSeed::FirstValue,
+        } => (MovingAverageKind::Exponential, p),
+        IndicatorSpec::Wma(p) => (MovingAverageKind::Weighted, p),
+        IndicatorSpec::Smma(p) => (MovingAverageKind::Smoothed, p),
+        _ => return Err(TaError::InvalidParameter),
+    };
+    if ma_period != period {
+        return Err(TaError::InvalidParameter);
+    }
+    Ok(kind)
+}
+
+fn parse_number(arg: &str) -> Result<Num> {
+    arg.parse().map_err(|_| TaError::InvalidParameter)
+}
+
+impl fmt::Display for IndicatorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Sma(period) => write!(f, "SMA({})", period),
+            Self::Ema { period, seed } => match seed {
+                EmaSeed::FirstValue => write!(f, "EMA({})", period),
+                EmaSeed::Sma => write!(f, "EMA({}, SMA)", period),
+            },
+            Self::Wma(period) => write!(f, "WMA({})", period),
+            Self::Smma(period) => write!(f, "SMMA({})", period),
+            Self::Rsi {
+                period,
+                smoothing,
+                scale,
+            } => match (smoothing, scale) {
+                (RsiSmoothing::Wilder, RsiScale::ZeroHundred) => write!(f, "RSI({})", period),
+                (smoothing, RsiScale::ZeroHundred) => write!(f, "RSI({}, {})", period, smoothing),
+                (smoothing, scale) => write!(f, "RSI({}, {}, {})", period, smoothing, scale),
+            },
+            Self::Sd { period, ddof: 0 } => write!(f, "SD({})", period),
+            Self::Sd { period, ddof } => write!(f, "SD({}, {})", period, ddof),
+            Self::Atr(period) => write!(f, "ATR({})", period),
+            Self::Roc(period) => write!(f, "ROC({})", period),
+            Self::Mom(period) => write!(f, "MOM({})", period),
+            Self::Max(period) => write!(f, "MAX({})", period),
+            Self::Min(period) => write!(f, "MIN({})", period),
+            Self::Cci(period) => write!(f, "CCI({})", period),
+            Self::Er(period) => write!(f, "ER({})", period),
+            Self::Mad(period) => write!(f, "MAD({})", period),
+            Self::Mfi(period) => write!(f, "MFI({})", period),
+            Self::FastStoch(period) => write!(f, "FAST_STOCH({})", period),
+            Self::SlowStoch { stochastic, ema } => {
+                write!(f, "SLOW_STOCH({}, {})", stochastic, ema)
+            }
+            Self::Ce { period, multiplier } => write!(f, "CE({}, {})", period, multiplier),
+            Self::TrueRange => write!(f, "TRUE_RANGE()"),
+            Self::Obv => write!(f, "OBV"),
+            Self::Bb {
+                period,
+                multiplier,
+                ddof: 0,
+            } => write!(f, "BB({}, {})", period, multiplier),
+            Self::Bb {
+                period,
+                multiplier,
+                ddof,
+            } => write!(f, "BB({}, {}, {})", period, multiplier, ddof),
+            Self::Kc {
+                period,
+                multiplier,
+                ma,
+            } => {
+                let name = match ma {
+                    MovingAverageKind::Exponential => {
+                        return write!(f, "KC({}, {})", period, multiplier)
+                    }
+                    MovingAverageKind::Simple => "SMA",
+                    MovingAverageKind::Weighted => "WMA",
+                    MovingAverageKind::Smoothed => "SMMA",
+                };
+                write!(f, "KC({}, {}, {}({}))", period, multiplier, name, period)
+            }
+            Self::Macd { fast, slow, signal } => {
+                write!(f, "MACD({}, {}, {})", fast, slow, signal)
+            }
+            Self::Ppo { fast, slow, signal } => write!(f, "PPO({}, {}, {})", fast, slow, signal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::*;
+    use crate::lit;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_indicator("SMA(5)"), Ok(IndicatorSpec::Sma(5)));
+        assert_eq!(
+            parse_indicator(" ema ( 9 ) "),
+            Ok(IndicatorSpec::Ema {
+                period: 9,
+                seed: EmaSeed::FirstValue
+            })
+        );
+        assert_eq!(
+            parse_indicator("BB(10, 3)"),
+            Ok(IndicatorSpec::Bb {
+                period: 10,
+                multiplier: lit!(3.0),
+                ddof: 0
+            })
+        );
+        assert_eq!(
+            parse_indicator("kc(10,2,wma(10))"),
+            Ok(IndicatorSpec::Kc {
+                period: 10,
+                multiplier: lit!(2.0),
+                ma: MovingAverageKind::Weighted
+            })
+        );
+        assert_eq!(
+            parse_indicator("RSI(14, ema)"),
+            Ok(IndicatorSpec::Rsi {
+                period: 14,
+                smoothing: RsiSmoothing::Exponential,
+                scale: RsiScale::ZeroHundred
+            })
+        );
+        assert_eq!(parse_indicator("obv"), Ok(IndicatorSpec::Obv));
+        assert_eq!(parse_indicator("OBV()"), Ok(IndicatorSpec::Obv));
+        assert_eq!(parse_indicator("true_range"), Ok(IndicatorSpec::TrueRange));
+        assert_eq!(
+            parse_indicator("MACD(12,26,9)"),
+            Ok(IndicatorSpec::Macd {
+                fast: 12,
+                slow: 26,
+                signal: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let invalid = [
+            "",
+            "SMA",
+            "SMA(5",
+            "SMA 5)",
+            "SMA()",
+            "SMA(-1)",
+            "SMA(1.5)",
+            "SMA(5, 2)",
+            "SMA 5",
+            "BB(10)",
+            "BB(10, x)",
+            "MACD(12, 26)",
+            "FOO(5)",
+            "EMA(9, WMA)",
+            "SD(20, x)",
+            "RSI(14, WMA)",
+            "RSI(14, EMA, PERCENT)",
+            "RSI(14, EMA, CENTERED, 1)",
+            "KC(10, 2, SMA(5))",
+            "KC(10, 2, EMA(10, SMA))",
+            "KC(10, 2, MACD(12, 26, 9))",
+            "KC(10, 2, SMA(10)",
+            "KC(10, 2, SMA 10))",
+            "SLOW_STOCH(14)",
+            "CE(22)",
+            "TRUE_RANGE(1)",
+            "OBV(5)",
+        ];
+        for spec in invalid {
+            assert_eq!(
+                parse_indicator(spec),
+                Err(TaError::InvalidParameter),
+                "{}",
+                spec
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_zero_period() {
+        for spec in ["SMA(0)", "BB(0, 2)", "MACD(12, 0, 9)"] {
+            assert_eq!(
+                parse_indicator(spec),
+                Err(TaError::InvalidPeriod { got: 0 }),
+                "{}",
+                spec
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_indicators() {
+        let ema = |period, seed| IndicatorSpec::Ema { period, seed };
+        let rsi = |period, smoothing, scale| IndicatorSpec::Rsi {
+            period,
+            smoothing,
+            scale,
+        };
+        let bb = |period, multiplier, ddof| IndicatorSpec::Bb {
+            period,
+            multiplier,
+            ddof,
+        };
+        let kc = |period, multiplier, ma| IndicatorSpec::Kc {
+            period,
+            multiplier,
+            ma,
+        };
+
+        let cases = [
+            (
+                format!("{}", SimpleMovingAverage::new(5).unwrap()),
+                IndicatorSpec::Sma(5),
+            ),
+            (
+                format!("{}", ExponentialMovingAverage::new(9).unwrap()),
+                ema(9, EmaSeed::FirstValue),
+            ),
+            (
+                format!(
+                    "{}",
+                    ExponentialMovingAverage::new_with_seed(9, EmaSeed::Sma).unwrap()
+                ),
+                ema(9, EmaSeed::Sma),
+            ),
+            (
+                format!("{}", WeightedMovingAverage::new(4).unwrap()),
+                IndicatorSpec::Wma(4),
+            ),
+            (
+                format!("{}", SmoothedMovingAverage::new(14).unwrap()),
+                IndicatorSpec::Smma(14),
+            ),
+            (
+                format!("{}", RelativeStrengthIndex::new(14).unwrap()),
+                rsi(14, RsiSmoothing::Wilder, RsiScale::ZeroHundred),
+            ),
+            (
+                format!(
+                    "{}",
+                    RelativeStrengthIndex::new_with_smoothing(14, RsiSmoothing::Exponential)
+                        .unwrap()
+                ),
+                rsi(14, RsiSmoothing::Exponential, RsiScale::ZeroHundred),
+            ),
+            (
+                format!(
+                    "{}",
+                    RelativeStrengthIndex::new_with_scale(14, RsiScale::Centered).unwrap()
+                ),
+                rsi(14, RsiSmoothing::Wilder, RsiScale::Centered),
+            ),
+            (
+                format!(
+                    "{}",
+                    RelativeStrengthIndex::new_with_options(
+                        14,
+                        RsiScale::ZeroOne,
+                        RsiSmoothing::Exponential
+                    )
+                    .unwrap()
+                ),
+                rsi(14, RsiSmoothing::Exponential, RsiScale::ZeroOne),
+            ),
+            (
+                format!("{}", StandardDeviation::new(20).unwrap()),
+                IndicatorSpec::Sd {
+                    period: 20,
+                    ddof: 0,
+                },
+            ),
+            (
+                format!("{}", StandardDeviation::new_with_ddof(20, 1).unwrap()),
+                IndicatorSpec::Sd {
+                    period: 20,
+                    ddof: 1,
+                },
+            ),
+            (
+                format!("{}", AverageTrueRange::new(14).unwrap()),
+                IndicatorSpec::Atr(14),
+            ),
+            (
+                format!("{}", RateOfChange::new(10).unwrap()),
+                IndicatorSpec::Roc(10),
+            ),
+            (
+                format!("{}", Momentum::new(10).unwrap()),
+                IndicatorSpec::Mom(10),
+            ),
+            (
+                format!("{}", Maximum::new(3).unwrap()),
+                IndicatorSpec::Max(3),
+            ),
+            (
+                format!("{}", Minimum::new(3).unwrap()),
+                IndicatorSpec::Min(3),
+            ),
+            (
+                format!("{}", CommodityChannelIndex::new(20).unwrap()),
+                IndicatorSpec::Cci(20),
+            ),
+            (
+                format!("{}", EfficiencyRatio::new(10).unwrap()),
+                IndicatorSpec::Er(10),
+            ),
+            (
+                format!("{}", MeanAbsoluteDeviation::new(9).unwrap()),
+                IndicatorSpec::Mad(9),
+            ),
+            (
+                format!("{}", MoneyFlowIndex::new(14).unwrap()),
+                IndicatorSpec::Mfi(14),
+            ),
+            (
+                format!("{}", FastStochastic::new(14).unwrap()),
+                IndicatorSpec::FastStoch(14),
+            ),
+            (
+                format!("{}", SlowStochastic::new(14, 3).unwrap()),
+                IndicatorSpec::SlowStoch {
+                    stochastic: 14,
+                    ema: 3,
+                },
+            ),
+            (
+                format!("{}", ChandelierExit::new(22, lit!(3.0)).unwrap()),
+                IndicatorSpec::Ce {
+                    period: 22,
+                    multiplier: lit!(3.0),
+                },
+            ),
+            (format!("{}", TrueRange::new()), IndicatorSpec::TrueRange),
+            (format!("{}", OnBalanceVolume::new()), IndicatorSpec::Obv),
+            (
+                format!("{}", BollingerBands::new(20, lit!(2.5)).unwrap()),
+                bb(20, lit!(2.5), 0),
+            ),
+            (
+                format!(
+                    "{}",
+                    BollingerBands::new_with_ddof(20, lit!(2.0), 1).unwrap()
+                ),
+                bb(20, lit!(2.0), 1),
+            ),
+            (
+                format!("{}", KeltnerChannel::new(10, lit!(2.0)).unwrap()),
+                kc(10, lit!(2.0), MovingAverageKind::Exponential),
+            ),
+            (
+                format!(
+                    "{}",
+                    KeltnerChannel::new_with_ma(10, lit!(2.0), MovingAverageKind::Simple).unwrap()
+                ),
+                kc(10, lit!(2.0), MovingAverageKind::Simple),
+            ),
+            (
+                format!(
+                    "{}",
+                    KeltnerChannel::new_with_ma(10, lit!(1.5), MovingAverageKind::Smoothed)
+                        .unwrap()
+                ),
+                kc(10, lit!(1.5), MovingAverageKind::Smoothed),
+            ),
+            (
+                format!(
+                    "{}",
+                    MovingAverageConvergenceDivergence::new(12, 26, 9).unwrap()
+                ),
+                IndicatorSpec::Macd {
+                    fast: 12,
+                    slow: 26,
+                    signal: 9,
+                },
+            ),
+            (
+                format!("{}", PercentagePriceOscillator::new(12, 26, 9).unwrap()),
+                IndicatorSpec::Ppo {
+                    fast: 12,
+                    slow: 26,
+                    signal: 9,
+                },
+            ),
+        ];
+
+        for (display, spec) in cases {
+            assert_eq!(display.parse::<IndicatorSpec>(), Ok(spec.clone()));
+            assert_eq!(format!("{}", spec), display);
+        }
+    }
+}
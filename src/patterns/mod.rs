@@ -0,0 +1,331 @@
+//! Candlestick pattern detection.
+//!
+//! Unlike the [indicators](crate::indicators), which return numbers, the detector classifies
+//! the shape of the latest bars into a [`CandlePattern`].
+
+use core::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{int, lit, Close, High, Low, Next, Open, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Candlestick pattern recognized by a [`PatternDetector`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandlePattern {
+    /// The open and the close are almost equal, the market is undecided.
+    Doji,
+    /// Small body at the top of the range with a long lower shadow.
+    Hammer,
+    /// Small body at the bottom of the range with a long upper shadow.
+    ShootingStar,
+    /// A rising bar whose body covers the body of the previous, falling, bar.
+    BullishEngulfing,
+    /// A falling bar whose body covers the body of the previous, rising, bar.
+    BearishEngulfing,
+}
+
+/// Detects candlestick patterns in a series of bars.
+///
+/// Every call of `next` classifies the latest bar, together with the previous one for the
+/// two-bar patterns, and returns `None` if it matches no pattern. The patterns are checked in
+/// this order and the first match is returned:
+///
+/// * [`BullishEngulfing`](CandlePattern::BullishEngulfing) - the previous bar closed below
+///   its open, the latest one closes above its open, and its body covers the previous body:
+///   _open ≤ previous close_ and _close ≥ previous open_.
+/// * [`BearishEngulfing`](CandlePattern::BearishEngulfing) - the mirror image: a rising bar
+///   followed by a falling one with _open ≥ previous close_ and _close ≤ previous open_.
+/// * [`Doji`](CandlePattern::Doji) - _body ≤ doji_body × range_.
+/// * [`Hammer`](CandlePattern::Hammer) - _lower shadow ≥ shadow_ratio × body_ and
+///   _upper shadow ≤ small_shadow × range_.
+/// * [`ShootingStar`](CandlePattern::ShootingStar) - _upper shadow ≥ shadow_ratio × body_
+///   and _lower shadow ≤ small_shadow × range_.
+///
+/// Where:
+///
+/// * _body_ = |close - open|
+/// * _range_ = high - low
+/// * _upper shadow_ = high - max(open, close)
+/// * _lower shadow_ = min(open, close) - low
+///
+/// Only the shapes of the bars are compared, the detector doesn't check the preceding trend
+/// that e.g. a hammer is expected to reverse.
+///
+/// # Parameters
+///
+/// * _doji_body_ - largest body of a doji, as a fraction of the range (number in 0..1).
+///   Default is 0.1.
+/// * _shadow_ratio_ - smallest long shadow of a hammer or a shooting star, as a multiple of
+///   the body (number greater than 0). Default is 2.
+/// * _small_shadow_ - largest short shadow of a hammer or a shooting star, as a fraction of
+///   the range (number in 0..1). Default is 0.1.
+///
+/// # Example
+///
+/// ```
+/// use ta::patterns::{CandlePattern, PatternDetector};
+/// use ta::{lit, Candle, Next};
+///
+/// let mut detector = PatternDetector::default();
+///
+/// let hammer = Candle::builder()
+///     .open(lit!(9.0))
+///     .high(lit!(10.0))
+///     .low(lit!(4.0))
+///     .close(lit!(10.0))
+///     .volume(lit!(100.0))
+///     .build()
+///     .unwrap();
+/// assert_eq!(detector.next(&hammer), Some(CandlePattern::Hammer));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PatternDetector {
+    doji_body: crate::Num,
+    shadow_ratio: crate::Num,
+    small_shadow: crate::Num,
+    // open and close of the previous bar
+    previous: Option<(crate::Num, crate::Num)>,
+}
+
+impl PatternDetector {
+    /// # Errors
+    ///
+    /// Will return `Err` if `doji_body` or `small_shadow` is not in 0..1 or `shadow_ratio`
+    /// is not positive
+    pub fn new(
+        doji_body: crate::Num,
+        shadow_ratio: crate::Num,
+        small_shadow: crate::Num,
+    ) -> Result<Self> {
+        let fraction = lit!(0.0)..lit!(1.0);
+        if !fraction.contains(&doji_body)
+            || !fraction.contains(&small_shadow)
+            || shadow_ratio <= lit!(0.0)
+        {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            doji_body,
+            shadow_ratio,
+            small_shadow,
+            previous: None,
+        })
+    }
+
+    #[must_use]
+    pub fn doji_body(&self) -> crate::Num {
+        self.doji_body
+    }
+
+    #[must_use]
+    pub fn shadow_ratio(&self) -> crate::Num {
+        self.shadow_ratio
+    }
+
+    #[must_use]
+    pub fn small_shadow(&self) -> crate::Num {
+        self.small_shadow
+    }
+
+    fn engulfing(&self, open: crate::Num, close: crate::Num) -> Option<CandlePattern> {
+        let (prev_open, prev_close) = self.previous?;
+
+        if prev_close < prev_open && close > open && open <= prev_close && close >= prev_open {
+            Some(CandlePattern::BullishEngulfing)
+        } else if prev_close > prev_open && close < open && open >= prev_close && close <= prev_open
+        {
+            Some(CandlePattern::BearishEngulfing)
+        } else {
+            None
+        }
+    }
+
+    fn single(
+        &self,
+        open: crate::Num,
+        high: crate::Num,
+        low: crate::Num,
+        close: crate::Num,
+    ) -> Option<CandlePattern> {
+        let body = (close - open).abs();
+        let range = high - low;
+        let upper = high - open.max(close);
+        let lower = open.min(close) - low;
+
+        if body <= self.doji_body * range {
+            Some(CandlePattern::Doji)
+        } else if lower >= self.shadow_ratio * body && upper <= self.small_shadow * range {
+            Some(CandlePattern::Hammer)
+        } else if upper >= self.shadow_ratio * body && lower <= self.small_shadow * range {
+            Some(CandlePattern::ShootingStar)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for PatternDetector {
+    type Output = Option<CandlePattern>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (open, close) = (input.open(), input.close());
+
+        let pattern = self
+            .engulfing(open, close)
+            .or_else(|| self.single(open, input.high(), input.low(), close));
+
+        self.previous = Some((open, close));
+        pattern
+    }
+}
+
+impl Reset for PatternDetector {
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl Default for PatternDetector {
+    fn default() -> Self {
+        Self::new(lit!(0.1), int!(2), lit!(0.1)).unwrap()
+    }
+}
+
+impl fmt::Display for PatternDetector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PATTERN({}, {}, {})",
+            self.doji_body, self.shadow_ratio, self.small_shadow
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(open: i32, high: i32, low: i32, close: i32) -> Bar {
+        Bar::new().open(open).high(high).low(low).close(close)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(PatternDetector::new(lit!(0.1), lit!(2.0), lit!(0.1)).is_ok());
+        assert!(PatternDetector::new(lit!(-0.1), lit!(2.0), lit!(0.1)).is_err());
+        assert!(PatternDetector::new(lit!(1.0), lit!(2.0), lit!(0.1)).is_err());
+        assert!(PatternDetector::new(lit!(0.1), lit!(0.0), lit!(0.1)).is_err());
+        assert!(PatternDetector::new(lit!(0.1), lit!(2.0), lit!(1.5)).is_err());
+    }
+
+    #[test]
+    fn test_doji() {
+        let mut detector = PatternDetector::default();
+
+        assert_eq!(
+            detector.next(&bar(50, 60, 40, 51)),
+            Some(CandlePattern::Doji)
+        );
+        // a bar without range is a doji too
+        assert_eq!(
+            detector.next(&bar(50, 50, 50, 50)),
+            Some(CandlePattern::Doji)
+        );
+        // body of 15% of the range
+        assert_eq!(detector.next(&bar(50, 60, 40, 53)), None);
+    }
+
+    #[test]
+    fn test_hammer() {
+        let mut detector = PatternDetector::default();
+
+        // body 2, lower shadow 8, no upper shadow
+        assert_eq!(
+            detector.next(&bar(18, 20, 10, 20)),
+            Some(CandlePattern::Hammer)
+        );
+        // a falling body is a hammer too
+        let mut detector = PatternDetector::default();
+        assert_eq!(
+            detector.next(&bar(20, 20, 10, 18)),
+            Some(CandlePattern::Hammer)
+        );
+        // the upper shadow is a sixth of the range
+        assert_eq!(detector.next(&bar(20, 24, 12, 22)), None);
+    }
+
+    #[test]
+    fn test_shooting_star() {
+        let mut detector = PatternDetector::default();
+
+        // body 2, upper shadow 8, no lower shadow
+        assert_eq!(
+            detector.next(&bar(10, 20, 10, 12)),
+            Some(CandlePattern::ShootingStar)
+        );
+        // the upper shadow is shorter than twice the body
+        let mut detector = PatternDetector::default();
+        assert_eq!(detector.next(&bar(10, 20, 10, 14)), None);
+    }
+
+    #[test]
+    fn test_engulfing() {
+        let mut detector = PatternDetector::default();
+
+        assert_eq!(detector.next(&bar(20, 21, 14, 15)), None);
+        assert_eq!(
+            detector.next(&bar(14, 23, 13, 22)),
+            Some(CandlePattern::BullishEngulfing)
+        );
+        assert_eq!(
+            detector.next(&bar(23, 24, 12, 13)),
+            Some(CandlePattern::BearishEngulfing)
+        );
+        // a rising bar that doesn't reach the previous open
+        assert_eq!(detector.next(&bar(12, 24, 11, 22)), None);
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let mut strict = PatternDetector::new(lit!(0.01), int!(3), lit!(0.1)).unwrap();
+        let mut loose = PatternDetector::new(lit!(0.2), int!(2), lit!(0.1)).unwrap();
+
+        let candle = bar(50, 60, 40, 53);
+        assert_eq!(strict.next(&candle), None);
+        assert_eq!(loose.next(&candle), Some(CandlePattern::Doji));
+
+        // lower shadow is 2.5 times the body
+        let candle = bar(16, 20, 6, 20);
+        assert_eq!(strict.next(&candle), None);
+        assert_eq!(loose.next(&candle), Some(CandlePattern::Hammer));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut detector = PatternDetector::default();
+
+        detector.next(&bar(20, 21, 14, 15));
+        detector.reset();
+        // the previous bar is forgotten, no engulfing
+        assert_eq!(detector.next(&bar(14, 23, 13, 22)), None);
+    }
+
+    #[test]
+    fn test_default() {
+        let detector = PatternDetector::default();
+        assert_eq!(detector.doji_body(), lit!(0.1));
+        assert_eq!(detector.shadow_ratio(), lit!(2.0));
+        assert_eq!(detector.small_shadow(), lit!(0.1));
+    }
+
+    #[test]
+    fn test_display() {
+        let detector = PatternDetector::new(lit!(0.05), int!(3), lit!(0.2)).unwrap();
+        assert_eq!(format!("{}", detector), "PATTERN(0.05, 3, 0.2)");
+    }
+}
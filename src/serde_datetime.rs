@@ -0,0 +1,151 @@
+//! Serde formats of the [`Candle`](crate::Candle) datetime.
+//!
+//! `Candle` serializes its datetime as an RFC 3339 string, e.g. `"2017-01-03T00:00:00Z"`.
+//! Enable the `serde-epoch-millis` feature to serialize it as the number of milliseconds
+//! since the Unix epoch instead, as expected by many exchange APIs and databases.
+//!
+//! Both formats are modules for the `#[serde(with = "...")]` attribute, so they can be
+//! used for datetimes of other structs as well:
+//!
+//! ```
+//! use chrono::{DateTime, Utc};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Trade {
+//!     #[serde(with = "ta::serde_datetime::epoch_millis")]
+//!     time: DateTime<Utc>,
+//! }
+//!
+//! let trade: Trade = serde_json::from_str(r#"{"time":1483401600000}"#).unwrap();
+//! assert_eq!(trade.time.to_rfc3339(), "2017-01-03T00:00:00+00:00");
+//! ```
+
+/// RFC 3339 strings, e.g. `"2017-01-03T00:00:00Z"`.
+///
+/// Deserializes any RFC 3339 offset, which is converted to UTC, and also plain dates like
+/// `"2017-01-03"`, taken as midnight UTC, as found in daily OHLC files.
+pub mod rfc3339 {
+    use core::fmt;
+
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        datetime: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        deserializer.deserialize_str(Rfc3339Visitor)
+    }
+
+    struct Rfc3339Visitor;
+
+    impl Visitor<'_> for Rfc3339Visitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "an RFC 3339 datetime or a YYYY-MM-DD date")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            crate::data_item::parse_datetime(value)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+/// Milliseconds since the Unix epoch, e.g. `1483401600000`.
+pub mod epoch_millis {
+    use chrono::{DateTime, Utc};
+    use serde::de::{self, Deserialize};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        datetime: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(datetime.timestamp_millis())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        DateTime::from_timestamp_millis(millis).ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Signed(millis),
+                &"milliseconds within the range of DateTime",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Rfc3339 {
+        #[serde(with = "super::rfc3339")]
+        time: DateTime<Utc>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct EpochMillis {
+        #[serde(with = "super::epoch_millis")]
+        time: DateTime<Utc>,
+    }
+
+    fn time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2017, 1, 3, 14, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_rfc3339() {
+        let value = Rfc3339 { time: time() };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"time":"2017-01-03T14:30:00Z"}"#);
+        assert_eq!(serde_json::from_str::<Rfc3339>(&json).unwrap(), value);
+
+        // offsets are converted to UTC
+        let json = r#"{"time":"2017-01-03T16:30:00+02:00"}"#;
+        assert_eq!(serde_json::from_str::<Rfc3339>(json).unwrap(), value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Rfc3339>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_rfc3339_date() {
+        let value: Rfc3339 = serde_json::from_str(r#"{"time":"2017-01-03"}"#).unwrap();
+        assert_eq!(
+            value.time,
+            Utc.with_ymd_and_hms(2017, 1, 3, 0, 0, 0).unwrap()
+        );
+
+        assert!(serde_json::from_str::<Rfc3339>(r#"{"time":"03.01.2017"}"#).is_err());
+        assert!(serde_json::from_str::<Rfc3339>(r#"{"time":1483401600000}"#).is_err());
+    }
+
+    #[test]
+    fn test_epoch_millis() {
+        let value = EpochMillis { time: time() };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"time":1483453800000}"#);
+        assert_eq!(serde_json::from_str::<EpochMillis>(&json).unwrap(), value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<EpochMillis>(&bytes).unwrap(), value);
+
+        assert!(serde_json::from_str::<EpochMillis>(r#"{"time":"2017-01-03"}"#).is_err());
+        assert!(serde_json::from_str::<EpochMillis>(r#"{"time":9223372036854775807}"#).is_err());
+    }
+}
@@ -0,0 +1,887 @@
+//! Adapters to run indicators over iterators.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+
+use crate::errors::{Result, TaError};
+use crate::{Candle, Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub use rust_decimal::RoundingStrategy;
+
+/// Iterator that feeds every item of `iter` into an indicator and yields its outputs.
+///
+/// Created by [`IndicateExt::indicate`]. The inputs are pulled lazily, one per call of
+/// [`Iterator::next`].
+#[derive(Debug, Clone)]
+pub struct Indicated<I, Ind> {
+    iter: I,
+    indicator: Ind,
+}
+
+impl<I, Ind> Indicated<I, Ind> {
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &Ind {
+        &self.indicator
+    }
+
+    /// Consumes the adapter, returns the wrapped indicator.
+    pub fn into_indicator(self) -> Ind {
+        self.indicator
+    }
+}
+
+impl<I, Ind> Iterator for Indicated<I, Ind>
+where
+    I: Iterator,
+    Ind: Next<I::Item>,
+{
+    type Item = Ind::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|input| self.indicator.next(input))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Runs an indicator over an iterator.
+///
+/// Implemented for every iterator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::lit;
+/// use ta::stream::IndicateExt;
+///
+/// let prices = vec![lit!(1.0), lit!(3.0), lit!(5.0)];
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let averages: Vec<_> = prices.into_iter().indicate(sma).collect();
+/// assert_eq!(averages, vec![lit!(1.0), lit!(2.0), lit!(4.0)]);
+/// ```
+pub trait IndicateExt: Iterator + Sized {
+    /// Wraps the iterator into an [`Indicated`] that yields `indicator.next(item)` for every
+    /// item.
+    fn indicate<Ind>(self, indicator: Ind) -> Indicated<Self, Ind>
+    where
+        Ind: Next<Self::Item>,
+    {
+        Indicated {
+            iter: self,
+            indicator,
+        }
+    }
+}
+
+impl<I: Iterator> IndicateExt for I {}
+
+/// Wraps an indicator and hides its outputs during the warmup window.
+///
+/// Many indicators return values before they have seen a full _period_ of inputs, e.g.
+/// [`SimpleMovingAverage`](crate::indicators::SimpleMovingAverage) averages fewer values.
+/// `Warmup` counts the inputs and returns `None` until the wrapped indicator has seen
+/// [`period`](Period::period) of them, `Some` afterward.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::stream::Warmup;
+/// use ta::{lit, Next};
+///
+/// let mut sma = Warmup::new(SimpleMovingAverage::new(2).unwrap());
+///
+/// assert_eq!(sma.next(lit!(1.0)), None);
+/// assert_eq!(sma.next(lit!(3.0)), Some(lit!(2.0)));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Warmup<I> {
+    indicator: I,
+    count: usize,
+}
+
+impl<I: Period> Warmup<I> {
+    pub fn new(indicator: I) -> Self {
+        Self {
+            indicator,
+            count: 0,
+        }
+    }
+
+    /// Returns `true` once the wrapped indicator has seen `period` inputs.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.count >= self.indicator.period()
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I: Period> Period for Warmup<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T, I: Period + Next<T>> Next<T> for Warmup<I> {
+    type Output = Option<I::Output>;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let output = self.indicator.next(input);
+        if !self.is_ready() {
+            self.count += 1;
+        }
+
+        if self.is_ready() {
+            Some(output)
+        } else {
+            None
+        }
+    }
+}
+
+impl<I: Reset> Reset for Warmup<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.count = 0;
+    }
+}
+
+/// Wraps an indicator and rounds its outputs to a fixed number of decimal places.
+///
+/// Useful to emit values that are ready for display or comparison, instead of rounding
+/// every output by hand. The wrapped indicator keeps computing with full precision, only
+/// the returned values are rounded.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::stream::{Precision, RoundingStrategy};
+/// use ta::{lit, Next};
+///
+/// let sma = SimpleMovingAverage::new(3).unwrap();
+/// let mut sma = Precision::new(sma, 2, RoundingStrategy::MidpointAwayFromZero);
+///
+/// sma.next(lit!(1.0));
+/// sma.next(lit!(2.0));
+/// assert_eq!(sma.next(lit!(2.0)), lit!(1.67));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Precision<I> {
+    indicator: I,
+    dp: u32,
+    strategy: RoundingStrategy,
+}
+
+impl<I> Precision<I> {
+    /// Rounds the outputs of `indicator` to `dp` decimal places with `strategy`.
+    pub fn new(indicator: I, dp: u32, strategy: RoundingStrategy) -> Self {
+        Self {
+            indicator,
+            dp,
+            strategy,
+        }
+    }
+
+    #[must_use]
+    pub fn dp(&self) -> u32 {
+        self.dp
+    }
+
+    #[must_use]
+    pub fn strategy(&self) -> RoundingStrategy {
+        self.strategy
+    }
+
+    /// Returns the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+}
+
+impl<I: Period> Period for Precision<I> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T, I: Next<T, Output = crate::Num>> Next<T> for Precision<I> {
+    type Output = crate::Num;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        crate::num::round_with(self.indicator.next(input), self.dp, self.strategy)
+    }
+}
+
+impl<I: Reset> Reset for Precision<I> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for Precision<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.indicator.fmt(f)
+    }
+}
+
+/// Direction in which the first indicator of a [`Crossover`] crossed the second one.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrossSignal {
+    /// No crossing on this input.
+    #[default]
+    None,
+    /// The first indicator moved above the second one.
+    CrossUp,
+    /// The first indicator moved below the second one.
+    CrossDown,
+}
+
+/// Detects when one indicator crosses another, e.g. a fast moving average crossing a slow
+/// one.
+///
+/// Every input is fed into both indicators. The sign of the difference _first - second_
+/// decides which side the first indicator is on, and a change of the side emits a
+/// [`CrossSignal`]. A difference of 0 keeps the previous side, so touching the second
+/// indicator and turning back is not a crossing, and moving through it over several inputs
+/// is reported once, when the other side is reached.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::stream::{CrossSignal, Crossover};
+/// use ta::{lit, Next};
+///
+/// let fast = SimpleMovingAverage::new(1).unwrap();
+/// let slow = SimpleMovingAverage::new(3).unwrap();
+/// let mut crossover = Crossover::new(fast, slow);
+///
+/// assert_eq!(crossover.next(lit!(3.0)), CrossSignal::None);
+/// assert_eq!(crossover.next(lit!(2.0)), CrossSignal::None);
+/// assert_eq!(crossover.next(lit!(4.0)), CrossSignal::CrossUp);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Crossover<A, B> {
+    first: A,
+    second: B,
+    // whether the first indicator was above the second one on the last nonzero difference
+    above: Option<bool>,
+}
+
+impl<A, B> Crossover<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            above: None,
+        }
+    }
+
+    /// Returns the indicator that crosses.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns the indicator that is crossed.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<T, A, B> Next<T> for Crossover<A, B>
+where
+    T: Copy,
+    A: Next<T, Output = crate::Num>,
+    B: Next<T, Output = crate::Num>,
+{
+    type Output = CrossSignal;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let first = self.first.next(input);
+        let second = self.second.next(input);
+
+        let above = if first > second {
+            true
+        } else if first < second {
+            false
+        } else {
+            return CrossSignal::None;
+        };
+
+        let signal = match (self.above, above) {
+            (Some(false), true) => CrossSignal::CrossUp,
+            (Some(true), false) => CrossSignal::CrossDown,
+            _ => CrossSignal::None,
+        };
+        self.above = Some(above);
+
+        signal
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Crossover<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.above = None;
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Crossover<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CROSS({}, {})", self.first, self.second)
+    }
+}
+
+/// Chains two indicators, feeding the outputs of the first one into the second one.
+///
+/// Usually built with the [`pipe!`](crate::pipe) macro. The output type of the first
+/// indicator must be an input type of the second one, e.g. an indicator returning
+/// [`Num`](crate::Num) can be followed by any indicator implementing `Next<Num>`, while
+/// one returning a struct like
+/// [`BollingerBandsOutput`](crate::indicators::BollingerBandsOutput) can't.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Pipe<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Pipe<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns the indicator that receives the inputs.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns the indicator that receives the outputs of the first one.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<T, A, B> Next<T> for Pipe<A, B>
+where
+    A: Next<T>,
+    B: Next<A::Output>,
+{
+    type Output = B::Output;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.second.next(self.first.next(input))
+    }
+}
+
+impl<A: Reset, B: Reset> Reset for Pipe<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Pipe<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} | {}", self.first, self.second)
+    }
+}
+
+/// Fills gaps in a series of candles with flat candles, so that indicators downstream see
+/// one candle per _interval_.
+///
+/// Every candle falls into the slot of _interval_ that contains its timestamp, slots are
+/// aligned to the Unix epoch like the buckets of [`Resampler`](crate::indicators::Resampler).
+/// A candle is preceded by a flat candle for every empty slot since the slot of the previous
+/// candle: its time is the start of the slot, open, high, low and close are the previous
+/// close and the volume is 0. So a candle a second late doesn't produce a fill, and the
+/// fills stay on the grid even if some candles are not. Candles in the same or an earlier
+/// slot than the previous one don't produce fills.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use ta::stream::GapFiller;
+/// use ta::{lit, Candle, Close, Next, Volume};
+///
+/// let mut filler = GapFiller::new(Duration::minutes(1)).unwrap();
+/// let bar = |minute, close| {
+///     let time = Utc.with_ymd_and_hms(2024, 1, 10, 9, minute, 0).unwrap();
+///     Candle::from_f64(time, close, close, close, close, 100.0).unwrap()
+/// };
+///
+/// assert_eq!(filler.next(&bar(0, 10.0)).len(), 1);
+///
+/// // 09:01 is missing
+/// let candles = filler.next(&bar(2, 12.0));
+/// assert_eq!(candles.len(), 2);
+/// assert_eq!((candles[0].close(), candles[0].volume()), (lit!(10.0), lit!(0.0)));
+/// assert_eq!(candles[1], bar(2, 12.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GapFiller {
+    interval: Duration,
+    previous: Option<Candle>,
+}
+
+impl GapFiller {
+    /// # Errors
+    ///
+    /// Will return `Err` if `interval` is not greater than 0
+    pub fn new(interval: Duration) -> Result<Self> {
+        if interval <= Duration::zero() {
+            return Err(TaError::InvalidParameter);
+        }
+        Ok(Self {
+            interval,
+            previous: None,
+        })
+    }
+
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
+
+impl GapFiller {
+    fn slot(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        // can only fail for timestamps near the limits of DateTime
+        time.duration_trunc(self.interval).unwrap_or(time)
+    }
+}
+
+impl Next<&Candle> for GapFiller {
+    /// The fills, oldest first, followed by the input candle.
+    type Output = Vec<Candle>;
+
+    fn next(&mut self, input: &Candle) -> Self::Output {
+        let mut candles = Vec::new();
+
+        if let Some(previous) = &self.previous {
+            let close = previous.close();
+            let mut time = self.slot(previous.datetime()) + self.interval;
+            let end = self.slot(input.datetime());
+            while time < end {
+                candles.push(
+                    Candle::builder()
+                        .time(time)
+                        .open(close)
+                        .high(close)
+                        .low(close)
+                        .close(close)
+                        .volume(crate::lit!(0.0))
+                        .build_unchecked(),
+                );
+                time += self.interval;
+            }
+        }
+
+        candles.push(input.clone());
+        self.previous = Some(input.clone());
+        candles
+    }
+}
+
+impl Reset for GapFiller {
+    fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+impl fmt::Display for GapFiller {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GAP_FILL({})", self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::RateOfChange;
+    use crate::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+    use crate::test_helper::*;
+    use crate::Current;
+    use crate::{int, lit, pipe};
+
+    #[test]
+    fn test_indicate() {
+        let prices = vec![lit!(2.0), lit!(4.0), lit!(6.0), lit!(8.0)];
+        let sma = SimpleMovingAverage::new(2).unwrap();
+
+        let averages: Vec<_> = prices.into_iter().indicate(sma).collect();
+        assert_eq!(averages, vec![lit!(2.0), lit!(3.0), lit!(5.0), lit!(7.0)]);
+    }
+
+    #[test]
+    fn test_indicate_combinators() {
+        let ema = ExponentialMovingAverage::new(3).unwrap();
+
+        // inputs and outputs can be mapped like with any other iterator
+        let rounded: Vec<_> = (1..=4)
+            .map(|price| lit!(2.0) * int!(price))
+            .indicate(ema)
+            .map(round)
+            .collect();
+        assert_eq!(rounded, vec![lit!(2.0), lit!(3.0), lit!(4.5), lit!(6.25)]);
+    }
+
+    #[test]
+    fn test_indicate_bars() {
+        let bars = [
+            Bar::new().close(2),
+            Bar::new().close(4),
+            Bar::new().close(9),
+        ];
+        let sma = SimpleMovingAverage::new(3).unwrap();
+
+        let mut stream = bars.iter().indicate(sma);
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+        assert_eq!(stream.next(), Some(lit!(2.0)));
+        assert_eq!(stream.nth(1), Some(lit!(5.0)));
+        assert_eq!(stream.next(), None);
+    }
+
+    #[test]
+    fn test_into_indicator() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut stream = vec![lit!(1.0), lit!(3.0)].into_iter().indicate(sma);
+        stream.by_ref().for_each(drop);
+
+        // the indicator keeps its state and can be fed further
+        let mut sma = stream.into_indicator();
+        assert_eq!(sma.next(lit!(5.0)), lit!(4.0));
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut sma = Warmup::new(SimpleMovingAverage::new(3).unwrap());
+
+        assert_eq!(sma.next(lit!(3.0)), None);
+        assert!(!sma.is_ready());
+        assert_eq!(sma.next(lit!(6.0)), None);
+        assert_eq!(sma.next(lit!(9.0)), Some(lit!(6.0)));
+        assert!(sma.is_ready());
+        assert_eq!(sma.next(&Bar::new().close(12)), Some(lit!(9.0)));
+        assert_eq!(sma.next(lit!(0.0)), Some(lit!(7.0)));
+    }
+
+    #[test]
+    fn test_warmup_indicate() {
+        let sma = Warmup::new(SimpleMovingAverage::new(2).unwrap());
+
+        let ready: Vec<_> = vec![lit!(1.0), lit!(3.0), lit!(5.0)]
+            .into_iter()
+            .indicate(sma)
+            .flatten()
+            .collect();
+        assert_eq!(ready, vec![lit!(2.0), lit!(4.0)]);
+    }
+
+    #[test]
+    fn test_warmup_reset() {
+        let mut sma = Warmup::new(SimpleMovingAverage::new(2).unwrap());
+
+        sma.next(lit!(1.0));
+        sma.next(lit!(3.0));
+        assert!(sma.is_ready());
+
+        sma.reset();
+        assert!(!sma.is_ready());
+        assert_eq!(sma.next(lit!(5.0)), None);
+        assert_eq!(sma.next(lit!(7.0)), Some(lit!(6.0)));
+    }
+
+    #[test]
+    fn test_precision() {
+        let ema = ExponentialMovingAverage::new(3).unwrap();
+        let mut ema = Precision::new(ema, 2, RoundingStrategy::MidpointAwayFromZero);
+        assert_eq!(ema.dp(), 2);
+        assert_eq!(ema.strategy(), RoundingStrategy::MidpointAwayFromZero);
+
+        // unrounded: 1.0, 1.505, 1.7525, 2.37625
+        assert_eq!(ema.next(lit!(1.0)), lit!(1.0));
+        assert_eq!(ema.next(lit!(2.01)), lit!(1.51));
+        assert_eq!(ema.next(lit!(2.0)), lit!(1.75));
+        assert_eq!(ema.next(&Bar::new().close(3)), lit!(2.38));
+
+        // the indicator keeps the unrounded value
+        assert_ne!(ema.indicator().current(), Some(lit!(2.38)));
+    }
+
+    #[test]
+    fn test_precision_strategy() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut sma = Precision::new(sma, 1, RoundingStrategy::ToNegativeInfinity);
+
+        assert_eq!(sma.next(lit!(-1.0)), lit!(-1.0));
+        assert_eq!(sma.next(lit!(-1.1)), lit!(-1.1));
+        // -1.15 is rounded down
+        assert_eq!(sma.next(lit!(-1.2)), lit!(-1.2));
+        assert_eq!(sma.period(), 2);
+        assert_eq!(format!("{}", sma), "SMA(2)");
+    }
+
+    #[test]
+    fn test_precision_reset() {
+        let sma = SimpleMovingAverage::new(2).unwrap();
+        let mut sma = Precision::new(sma, 0, RoundingStrategy::MidpointNearestEven);
+
+        sma.next(lit!(3.0));
+        sma.reset();
+        assert_eq!(sma.next(lit!(2.5)), lit!(2.0));
+        assert_eq!(sma.next(lit!(4.5)), lit!(4.0));
+    }
+
+    #[test]
+    fn test_crossover() {
+        let fast = SimpleMovingAverage::new(2).unwrap();
+        let slow = SimpleMovingAverage::new(4).unwrap();
+        let mut crossover = Crossover::new(fast, slow);
+
+        // (input, fast SMA, slow SMA, signal)
+        let steps = [
+            (lit!(10.0), lit!(10.0), lit!(10.0), CrossSignal::None),
+            (lit!(8.0), lit!(9.0), lit!(9.0), CrossSignal::None),
+            (lit!(6.0), lit!(7.0), lit!(8.0), CrossSignal::None),
+            (lit!(6.0), lit!(6.0), lit!(7.5), CrossSignal::None),
+            (lit!(10.0), lit!(8.0), lit!(7.5), CrossSignal::CrossUp),
+            (lit!(12.0), lit!(11.0), lit!(8.5), CrossSignal::None),
+            (lit!(4.0), lit!(8.0), lit!(8.0), CrossSignal::None),
+            (lit!(2.0), lit!(3.0), lit!(7.0), CrossSignal::CrossDown),
+            (lit!(2.0), lit!(2.0), lit!(5.0), CrossSignal::None),
+        ];
+        for (input, fast, slow, signal) in steps {
+            assert_eq!(crossover.next(input), signal);
+            assert_eq!(crossover.first().current(), Some(fast));
+            assert_eq!(crossover.second().current(), Some(slow));
+        }
+    }
+
+    #[test]
+    fn test_crossover_touch() {
+        let mut crossover = Crossover::new(
+            SimpleMovingAverage::new(1).unwrap(),
+            SimpleMovingAverage::new(1).unwrap(),
+        );
+        // both indicators are always equal, there is no side
+        assert_eq!(crossover.next(lit!(1.0)), CrossSignal::None);
+        assert_eq!(crossover.next(lit!(5.0)), CrossSignal::None);
+
+        // touching the slow average and turning back is not a crossing
+        let fast = SimpleMovingAverage::new(1).unwrap();
+        let slow = SimpleMovingAverage::new(3).unwrap();
+        let mut crossover = Crossover::new(fast, slow);
+        let inputs = [lit!(6.0), lit!(3.0), lit!(3.0), lit!(3.0), lit!(2.0)];
+        let signals: Vec<_> = inputs.iter().map(|&x| crossover.next(x)).collect();
+        assert_eq!(signals, vec![CrossSignal::None; 5]);
+    }
+
+    #[test]
+    fn test_crossover_bars() {
+        let fast = ExponentialMovingAverage::new(1).unwrap();
+        let slow = ExponentialMovingAverage::new(3).unwrap();
+        let mut crossover = Crossover::new(fast, slow);
+
+        assert_eq!(crossover.next(&Bar::new().close(5)), CrossSignal::None);
+        assert_eq!(crossover.next(&Bar::new().close(3)), CrossSignal::None);
+        assert_eq!(crossover.next(&Bar::new().close(7)), CrossSignal::CrossUp);
+        assert_eq!(format!("{}", crossover), "CROSS(EMA(1), EMA(3))");
+    }
+
+    #[test]
+    fn test_crossover_reset() {
+        let fast = SimpleMovingAverage::new(1).unwrap();
+        let slow = SimpleMovingAverage::new(2).unwrap();
+        let mut crossover = Crossover::new(fast, slow);
+
+        crossover.next(lit!(4.0));
+        crossover.next(lit!(2.0));
+        crossover.reset();
+
+        // the side before the reset is forgotten
+        assert_eq!(crossover.next(lit!(4.0)), CrossSignal::None);
+        assert_eq!(crossover.next(lit!(5.0)), CrossSignal::None);
+        assert_eq!(crossover.next(lit!(1.0)), CrossSignal::CrossDown);
+    }
+
+    #[test]
+    fn test_pipe() {
+        let mut pipe = pipe!(
+            SimpleMovingAverage::new(2).unwrap(),
+            RateOfChange::new(1).unwrap()
+        );
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let mut roc = RateOfChange::new(1).unwrap();
+
+        for input in [lit!(10.0), lit!(12.0), lit!(14.0), lit!(11.0), lit!(9.0)] {
+            assert_eq!(pipe.next(input), roc.next(sma.next(input)));
+        }
+        assert_eq!(pipe.first().current(), sma.current());
+        assert_eq!(format!("{}", pipe), "SMA(2) | ROC(1)");
+    }
+
+    #[test]
+    fn test_pipe_three_stages() {
+        let mut pipe = pipe!(
+            SimpleMovingAverage::new(2).unwrap(),
+            RateOfChange::new(1).unwrap(),
+            ExponentialMovingAverage::new(3).unwrap(),
+        );
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let mut roc = RateOfChange::new(1).unwrap();
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+
+        for close in [10, 12, 14, 11, 9, 13] {
+            // bars are fed into the first stage only
+            let bar = Bar::new().close(close);
+            assert_eq!(pipe.next(&bar), ema.next(roc.next(sma.next(&bar))));
+        }
+        assert_eq!(format!("{}", pipe), "SMA(2) | ROC(1) | EMA(3)");
+    }
+
+    #[test]
+    fn test_pipe_reset() {
+        let mut pipe = pipe!(
+            SimpleMovingAverage::new(2).unwrap(),
+            RateOfChange::new(1).unwrap()
+        );
+        let first = pipe.next(lit!(10.0));
+        pipe.next(lit!(20.0));
+
+        pipe.reset();
+        assert_eq!(pipe.next(lit!(10.0)), first);
+        assert_eq!(pipe.first().current(), Some(lit!(10.0)));
+    }
+
+    fn minute_candle(minute: u32, close: f64) -> Candle {
+        use chrono::{TimeZone, Utc};
+
+        let time = Utc.with_ymd_and_hms(2024, 1, 10, 9, minute, 0).unwrap();
+        Candle::from_f64(time, close, close + 1.0, close - 1.0, close, 100.0).unwrap()
+    }
+
+    fn flat_candle(minute: u32, close: f64) -> Candle {
+        use chrono::{TimeZone, Utc};
+
+        let time = Utc.with_ymd_and_hms(2024, 1, 10, 9, minute, 0).unwrap();
+        Candle::from_f64(time, close, close, close, close, 0.0).unwrap()
+    }
+
+    #[test]
+    fn test_gap_filler() {
+        assert!(GapFiller::new(Duration::zero()).is_err());
+
+        let mut filler = GapFiller::new(Duration::minutes(1)).unwrap();
+
+        // no gap
+        assert_eq!(
+            filler.next(&minute_candle(0, 10.0)),
+            vec![minute_candle(0, 10.0)]
+        );
+        assert_eq!(
+            filler.next(&minute_candle(1, 11.0)),
+            vec![minute_candle(1, 11.0)]
+        );
+
+        // one missing period
+        assert_eq!(
+            filler.next(&minute_candle(3, 12.0)),
+            vec![flat_candle(2, 11.0), minute_candle(3, 12.0)]
+        );
+
+        // three missing periods
+        assert_eq!(
+            filler.next(&minute_candle(7, 9.0)),
+            vec![
+                flat_candle(4, 12.0),
+                flat_candle(5, 12.0),
+                flat_candle(6, 12.0),
+                minute_candle(7, 9.0)
+            ]
+        );
+        assert_eq!(format!("{}", filler), "GAP_FILL(PT60S)");
+    }
+
+    #[test]
+    fn test_gap_filler_irregular() {
+        use chrono::{TimeZone, Utc};
+
+        let mut filler = GapFiller::new(Duration::minutes(1)).unwrap();
+        filler.next(&minute_candle(0, 10.0));
+
+        // in the next slot, nothing is missing
+        let late = Candle::from_f64(
+            Utc.with_ymd_and_hms(2024, 1, 10, 9, 1, 30).unwrap(),
+            10.0,
+            10.0,
+            10.0,
+            10.0,
+            1.0,
+        )
+        .unwrap();
+        assert_eq!(filler.next(&late).len(), 1);
+
+        // not later than the previous candle
+        assert_eq!(filler.next(&minute_candle(0, 10.0)).len(), 1);
+    }
+
+    #[test]
+    fn test_gap_filler_off_grid() {
+        use chrono::{TimeZone, Utc};
+
+        let mut filler = GapFiller::new(Duration::minutes(1)).unwrap();
+        filler.next(&minute_candle(0, 10.0));
+
+        let off_grid = Candle::from_f64(
+            Utc.with_ymd_and_hms(2024, 1, 10, 9, 2, 45).unwrap(),
+            11.0,
+            11.0,
+            11.0,
+            11.0,
+            1.0,
+        )
+        .unwrap();
+        assert_eq!(
+            filler.next(&off_grid),
+            vec![flat_candle(1, 10.0), off_grid.clone()]
+        );
+
+        // the fills after the off-grid candle are on the grid
+        assert_eq!(
+            filler.next(&minute_candle(5, 12.0)),
+            vec![
+                flat_candle(3, 11.0),
+                flat_candle(4, 11.0),
+                minute_candle(5, 12.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gap_filler_reset() {
+        let mut filler = GapFiller::new(Duration::minutes(1)).unwrap();
+        filler.next(&minute_candle(0, 10.0));
+
+        filler.reset();
+        assert_eq!(filler.next(&minute_candle(5, 10.0)).len(), 1);
+    }
+}
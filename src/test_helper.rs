@@ -2,11 +2,11 @@ use super::{lit, Close, High, Low, Open, Volume};
 
 #[derive(Debug, PartialEq)]
 pub struct Bar {
-    open: rust_decimal::Decimal,
-    high: rust_decimal::Decimal,
-    low: rust_decimal::Decimal,
-    close: rust_decimal::Decimal,
-    volume: rust_decimal::Decimal,
+    open: crate::Num,
+    high: crate::Num,
+    low: crate::Num,
+    close: crate::Num,
+    volume: crate::Num,
 }
 
 impl Bar {
@@ -20,65 +20,76 @@ impl Bar {
         }
     }
 
-    //pub fn open<T: Into<rust_decimal::Decimal>>(mut self, val :T ) -> Self {
-    //    self.open = val.into();
-    //    self
-    //}
+    pub fn open<T: Into<crate::Num>>(mut self, val: T) -> Self {
+        self.open = val.into();
+        self
+    }
 
-    pub fn high<T: Into<rust_decimal::Decimal>>(mut self, val: T) -> Self {
+    pub fn high<T: Into<crate::Num>>(mut self, val: T) -> Self {
         self.high = val.into();
         self
     }
 
-    pub fn low<T: Into<rust_decimal::Decimal>>(mut self, val: T) -> Self {
+    pub fn low<T: Into<crate::Num>>(mut self, val: T) -> Self {
         self.low = val.into();
         self
     }
 
-    pub fn close<T: Into<rust_decimal::Decimal>>(mut self, val: T) -> Self {
+    pub fn close<T: Into<crate::Num>>(mut self, val: T) -> Self {
         self.close = val.into();
         self
     }
 
-    pub fn volume<T: Into<rust_decimal::Decimal>>(mut self, val: T) -> Self {
+    pub fn volume<T: Into<crate::Num>>(mut self, val: T) -> Self {
         self.volume = val.into();
         self
     }
 }
 
 impl Open for Bar {
-    fn open(&self) -> rust_decimal::Decimal {
+    fn open(&self) -> crate::Num {
         self.open
     }
 }
 
 impl Close for Bar {
-    fn close(&self) -> rust_decimal::Decimal {
+    fn close(&self) -> crate::Num {
         self.close
     }
 }
 
 impl Low for Bar {
-    fn low(&self) -> rust_decimal::Decimal {
+    fn low(&self) -> crate::Num {
         self.low
     }
 }
 
 impl High for Bar {
-    fn high(&self) -> rust_decimal::Decimal {
+    fn high(&self) -> crate::Num {
         self.high
     }
 }
 
 impl Volume for Bar {
-    fn volume(&self) -> rust_decimal::Decimal {
+    fn volume(&self) -> crate::Num {
         self.volume
     }
 }
 
-pub fn round(num: rust_decimal::Decimal) -> rust_decimal::Decimal {
-    use rust_decimal::prelude::RoundingStrategy;
-    num.round_dp_with_strategy(3, RoundingStrategy::MidpointAwayFromZero)
+pub fn round(num: crate::Num) -> crate::Num {
+    crate::num::round(num, 3)
+}
+
+/// Rounds off the floating point error of the `f64` backend. The `Decimal` backend is exact,
+/// so the value is left untouched there.
+#[cfg(not(feature = "f64-backend"))]
+pub fn round_float(num: crate::Num) -> crate::Num {
+    num
+}
+
+#[cfg(feature = "f64-backend")]
+pub fn round_float(num: crate::Num) -> crate::Num {
+    crate::num::round(num, 10)
 }
 
 macro_rules! test_indicator {
@@ -91,7 +102,7 @@ macro_rules! test_indicator {
             // ensure Default trait is implemented
             let mut indicator = $i::default();
 
-            // ensure Next<rust_decimal::Decimal> is implemented
+            // ensure Next<crate::Num> is implemented
             let first_output = indicator.next(lit!(12.3));
 
             // ensure next accepts &DataItem as well
@@ -1,9 +1,60 @@
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Resets an indicator to the initial state.
 pub trait Reset {
     fn reset(&mut self);
 }
 
+/// Resets an indicator to a state seeded with a known value, instead of the blank initial
+/// state.
+///
+/// This is useful to carry a running indicator across e.g. a switch of the data source, or to
+/// restart it after a gap in the data. The next output builds on `seed` as if the indicator
+/// had been running, instead of treating the next input as a cold start. `ResetTo` is the
+/// single seeding trait of the crate, there is no separate `reset_with` method.
+///
+/// Implemented by:
+///
+/// * [AccumulationDistribution](indicators/struct.AccumulationDistribution.html) - `seed` becomes the accumulated value
+/// * [ExponentialMovingAverage](indicators/struct.ExponentialMovingAverage.html) - `seed` becomes the current average
+/// * [OnBalanceVolume](indicators/struct.OnBalanceVolume.html) - `seed` becomes the accumulated volume
+/// * [SimpleMovingAverage](indicators/struct.SimpleMovingAverage.html) - the whole window is filled with `seed`
+pub trait ResetTo {
+    fn reset_to(&mut self, seed: crate::Num);
+}
+
+/// Creates a copy of an indicator in the initial state, with the same parameters.
+///
+/// Implemented for every indicator that implements `Clone` and [`Reset`], which is handy
+/// to start e.g. another backtest with an already configured indicator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ExponentialMovingAverage;
+/// use ta::{lit, Fresh, Next};
+///
+/// let mut ema = ExponentialMovingAverage::new(3).unwrap();
+/// ema.next(lit!(2.0));
+/// ema.next(lit!(5.0));
+///
+/// let mut fresh = ema.fresh();
+/// assert_eq!(fresh.next(lit!(4.0)), lit!(4.0));
+/// ```
+pub trait Fresh: Clone + Reset {
+    /// Clones the indicator and resets the clone, `self` keeps its state.
+    #[must_use]
+    fn fresh(&self) -> Self {
+        let mut fresh = self.clone();
+        fresh.reset();
+        fresh
+    }
+}
+
+impl<I: Clone + Reset> Fresh for I {}
+
 /// Return the period used by the indicator.
 pub trait Period {
     fn period(&self) -> usize;
@@ -23,27 +74,286 @@ pub trait Next<T> {
     fn next(&mut self, input: T) -> Self::Output;
 }
 
+/// Feeds the last item of an append-only history into an indicator.
+///
+/// Implemented for every type, so it can be called on any indicator that implements
+/// `Next<&T>`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Candle, NextLatest};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// let candles: Vec<Candle> = Vec::new();
+///
+/// assert_eq!(sma.next_latest(&candles), None);
+/// ```
+pub trait NextLatest {
+    /// Feeds `slice.last()` into the indicator, returns `None` if `slice` is empty.
+    fn next_latest<'a, T>(&mut self, slice: &'a [T]) -> Option<<Self as Next<&'a T>>::Output>
+    where
+        Self: Next<&'a T>,
+    {
+        slice.last().map(|item| self.next(item))
+    }
+}
+
+impl<I: ?Sized> NextLatest for I {}
+
+/// Feeds a whole series into an indicator.
+///
+/// Implemented for every type, so it can be called on any indicator that implements
+/// `Next<T>`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{lit, NextExt};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// let prices = vec![lit!(1.0), lit!(3.0), lit!(5.0)];
+///
+/// assert_eq!(sma.next_iter(prices), vec![lit!(1.0), lit!(2.0), lit!(4.0)]);
+/// ```
+pub trait NextExt {
+    /// Feeds `inputs` into the indicator in order, returns the outputs in the same order.
+    fn next_iter<T, I>(&mut self, inputs: I) -> Vec<<Self as Next<T>>::Output>
+    where
+        Self: Next<T>,
+        I: IntoIterator<Item = T>,
+    {
+        inputs.into_iter().map(|input| self.next(input)).collect()
+    }
+}
+
+impl<I: ?Sized> NextExt for I {}
+
+/// Feeds a slice of numbers into an indicator, reusing the caller's output buffer.
+///
+/// Implemented for every indicator that implements `Next<Num>`. Unlike
+/// [`NextExt::next_iter`] it doesn't allocate a new vector for every call, which helps
+/// backtests that run many indicators over big arrays.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{lit, BulkNext};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut out = Vec::new();
+///
+/// sma.next_slice(&[lit!(1.0), lit!(3.0)], &mut out);
+/// sma.next_slice(&[lit!(5.0)], &mut out);
+/// assert_eq!(out, vec![lit!(1.0), lit!(2.0), lit!(4.0)]);
+/// ```
+pub trait BulkNext: Next<crate::Num> {
+    /// Feeds `input` into the indicator in order and appends the outputs to `out`.
+    ///
+    /// `out` is not cleared, call [`Vec::clear`] first to reuse its allocation for a new
+    /// series.
+    fn next_slice(&mut self, input: &[crate::Num], out: &mut Vec<Self::Output>) {
+        out.reserve(input.len());
+        out.extend(input.iter().map(|&value| self.next(value)));
+    }
+}
+
+impl<I: Next<crate::Num> + ?Sized> BulkNext for I {}
+
+/// Reads the latest value of an indicator without feeding it a new input.
+///
+/// Implemented by:
+///
+/// * [ExponentialMovingAverage](indicators/struct.ExponentialMovingAverage.html)
+/// * [MovingAverage](indicators/struct.MovingAverage.html)
+/// * [RelativeStrengthIndex](indicators/struct.RelativeStrengthIndex.html)
+/// * [SimpleMovingAverage](indicators/struct.SimpleMovingAverage.html)
+/// * [SmoothedMovingAverage](indicators/struct.SmoothedMovingAverage.html)
+/// * [WeightedMovingAverage](indicators/struct.WeightedMovingAverage.html)
+pub trait Current {
+    type Output;
+
+    /// Returns the value returned by the last call of `next`, or `None` before the first
+    /// input.
+    fn current(&self) -> Option<Self::Output>;
+}
+
 /// Open price of a particular period.
 pub trait Open {
-    fn open(&self) -> rust_decimal::Decimal;
+    fn open(&self) -> crate::Num;
 }
 
 /// Close price of a particular period.
 pub trait Close {
-    fn close(&self) -> rust_decimal::Decimal;
+    fn close(&self) -> crate::Num;
 }
 
 /// Lowest price of a particular period.
 pub trait Low {
-    fn low(&self) -> rust_decimal::Decimal;
+    fn low(&self) -> crate::Num;
 }
 
 /// Highest price of a particular period.
 pub trait High {
-    fn high(&self) -> rust_decimal::Decimal;
+    fn high(&self) -> crate::Num;
 }
 
 /// Trading volume of a particular trading period.
 pub trait Volume {
-    fn volume(&self) -> rust_decimal::Decimal;
+    fn volume(&self) -> crate::Num;
+}
+
+/// Typical price of a particular period, `(high + low + close) / 3`.
+///
+/// Implemented for every type that implements [`High`], [`Low`] and [`Close`].
+pub trait TypicalPriceExt: High + Low + Close {
+    fn typical_price(&self) -> crate::Num {
+        (self.close() + self.high() + self.low()) / crate::lit!(3.0)
+    }
+}
+
+impl<T: High + Low + Close + ?Sized> TypicalPriceExt for T {}
+
+/// Output of a band indicator: an average surrounded by an upper and a lower band.
+pub trait Bands {
+    fn upper(&self) -> crate::Num;
+    fn average(&self) -> crate::Num;
+    fn lower(&self) -> crate::Num;
+
+    /// Returns the signed distances of `price` from each band (price minus band level).
+    fn distances(&self, price: crate::Num) -> BandDistances {
+        BandDistances {
+            to_upper: price - self.upper(),
+            to_average: price - self.average(),
+            to_lower: price - self.lower(),
+        }
+    }
+}
+
+/// Signed distances of a price from the levels of [`Bands`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandDistances {
+    pub to_upper: crate::Num,
+    pub to_average: crate::Num,
+    pub to_lower: crate::Num,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{BollingerBands, ExponentialMovingAverage, SimpleMovingAverage};
+    use crate::lit;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_latest() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let bars = vec![Bar::new().close(1), Bar::new().close(3)];
+
+        // only the last bar is fed
+        assert_eq!(sma.next_latest(&bars), Some(lit!(3.0)));
+        assert_eq!(sma.next_latest(&bars[..0]), None);
+        assert_eq!(sma.next(lit!(5.0)), lit!(4.0));
+    }
+
+    #[test]
+    fn test_next_latest_growing() {
+        let closes = [4, 7, 1, 8, 3];
+
+        let mut streaming = SimpleMovingAverage::new(3).unwrap();
+        let mut latest = SimpleMovingAverage::new(3).unwrap();
+        let mut history = Vec::new();
+
+        for close in closes {
+            let bar = Bar::new().close(close);
+            let expected = streaming.next(&bar);
+
+            history.push(bar);
+            assert_eq!(latest.next_latest(&history), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_next_iter() {
+        let prices = [lit!(4.0), lit!(7.0), lit!(1.0), lit!(8.0), lit!(3.0)];
+
+        let mut looped = SimpleMovingAverage::new(3).unwrap();
+        let expected: Vec<_> = prices.iter().map(|&price| looped.next(price)).collect();
+
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(sma.next_iter(prices), expected);
+        // the indicator keeps its state
+        assert_eq!(sma.next(lit!(6.0)), looped.next(lit!(6.0)));
+
+        assert!(sma.next_iter(Vec::<crate::Num>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_next_iter_bars() {
+        let bars: Vec<Bar> = [4, 7, 1].iter().map(|&c| Bar::new().close(c)).collect();
+
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        assert_eq!(sma.next_iter(&bars), vec![lit!(4.0), lit!(5.5), lit!(4.0)]);
+    }
+
+    #[test]
+    fn test_next_slice() {
+        let prices = [lit!(4.0), lit!(7.0), lit!(1.0), lit!(8.0), lit!(3.0)];
+
+        let mut looped = SimpleMovingAverage::new(3).unwrap();
+        let expected: Vec<_> = prices.iter().map(|&price| looped.next(price)).collect();
+
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let mut out = Vec::new();
+        sma.next_slice(&prices, &mut out);
+        assert_eq!(out.len(), prices.len());
+        assert_eq!(out, expected);
+
+        // the buffer is appended to, the indicator keeps its state
+        sma.next_slice(&[lit!(6.0)], &mut out);
+        assert_eq!(out.len(), prices.len() + 1);
+        assert_eq!(out[prices.len()], looped.next(lit!(6.0)));
+
+        out.clear();
+        sma.next_slice(&[], &mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_next_slice_composite_output() {
+        let prices = [lit!(4.0), lit!(7.0), lit!(1.0)];
+
+        let mut looped = BollingerBands::new(2, lit!(2.0)).unwrap();
+        let mut bb = BollingerBands::new(2, lit!(2.0)).unwrap();
+        let mut out = Vec::with_capacity(prices.len());
+        bb.next_slice(&prices, &mut out);
+
+        assert_eq!(out.len(), prices.len());
+        for (output, &price) in out.iter().zip(&prices) {
+            assert_eq!(*output, looped.next(price));
+        }
+    }
+
+    #[test]
+    fn test_fresh() {
+        let inputs = [lit!(2.0), lit!(5.0), lit!(1.0), lit!(6.0)];
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        for &input in &inputs[..2] {
+            ema.next(input);
+        }
+
+        let mut fresh = ema.fresh();
+        let mut new = ExponentialMovingAverage::new(3).unwrap();
+        for &input in &inputs {
+            assert_eq!(fresh.next(input), new.next(input));
+        }
+
+        // the original keeps its state
+        let expected = ExponentialMovingAverage::new(3).unwrap().next_iter(inputs);
+        assert_eq!(ema.next(inputs[2]), expected[2]);
+    }
 }
@@ -8,7 +8,7 @@ mod test {
     #[cfg(feature = "serde")]
     mod serde {
         use ta::indicators::SimpleMovingAverage;
-        use ta::Next;
+        use ta::{lit, Next};
 
         // Simple smoke test that serde works (not sure if this is really necessary)
         #[test]
@@ -17,7 +17,7 @@ mod test {
             let bytes = bincode::serialize(&macd).unwrap();
             let mut deserialized: SimpleMovingAverage = bincode::deserialize(&bytes).unwrap();
 
-            assert_eq!(deserialized.next(2.0), macd.next(2.0));
+            assert_eq!(deserialized.next(lit!(2.0)), macd.next(lit!(2.0)));
         }
     }
 }